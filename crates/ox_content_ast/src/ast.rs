@@ -38,6 +38,14 @@ pub enum Node<'a> {
     Html(Html<'a>),
     /// Table (GFM extension).
     Table(Table<'a>),
+    /// Definition list (PHP Markdown Extra extension).
+    DescriptionList(DescriptionList<'a>),
+    /// Term inside a definition list.
+    DescriptionTerm(DescriptionTerm<'a>),
+    /// Definition inside a definition list.
+    DescriptionDetails(DescriptionDetails<'a>),
+    /// Custom container (`:::kind title` ... `:::` extension).
+    Container(Container<'a>),
 
     // Inline nodes
     /// Plain text.
@@ -56,6 +64,12 @@ pub enum Node<'a> {
     Image(Image<'a>),
     /// Strikethrough (GFM extension).
     Delete(Delete<'a>),
+    /// Highlighted text (`==mark==` extension).
+    Mark(Mark<'a>),
+    /// Subscript (`~sub~` extension).
+    Subscript(Subscript<'a>),
+    /// Superscript (`^sup^` extension).
+    Superscript(Superscript<'a>),
     /// Footnote reference (GFM extension).
     FootnoteReference(FootnoteReference<'a>),
 
@@ -104,6 +118,19 @@ pub struct BlockQuote<'a> {
     pub span: Span,
 }
 
+/// Custom container (`:::kind title` ... `:::` extension).
+#[derive(Debug)]
+pub struct Container<'a> {
+    /// Container kind, such as `tip`, `warning` or `details`.
+    pub kind: &'a str,
+    /// Optional title following the kind.
+    pub title: Option<&'a str>,
+    /// Block children.
+    pub children: Vec<'a, Node<'a>>,
+    /// Source span.
+    pub span: Span,
+}
+
 /// List (ordered or unordered).
 #[derive(Debug)]
 pub struct List<'a> {
@@ -111,6 +138,8 @@ pub struct List<'a> {
     pub ordered: bool,
     /// Starting number for ordered lists.
     pub start: Option<u32>,
+    /// Marker delimiter for ordered lists (`1.` vs `1)`).
+    pub delimiter: Option<ListDelimiter>,
     /// Whether the list is spread (items separated by blank lines).
     pub spread: bool,
     /// List item children.
@@ -119,6 +148,15 @@ pub struct List<'a> {
     pub span: Span,
 }
 
+/// Delimiter following the number of an ordered list marker.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ListDelimiter {
+    /// Period delimiter (`1.`).
+    Period,
+    /// Closing parenthesis delimiter (`1)`).
+    Paren,
+}
+
 /// List item.
 #[derive(Debug)]
 pub struct ListItem<'a> {
@@ -145,7 +183,7 @@ pub struct CodeBlock<'a> {
     pub span: Span,
 }
 
-/// HTML block.
+/// Raw HTML: an HTML block, or an inline HTML comment.
 #[derive(Debug)]
 pub struct Html<'a> {
     /// Raw HTML content.
@@ -183,6 +221,33 @@ pub struct TableCell<'a> {
     pub span: Span,
 }
 
+/// Definition list (PHP Markdown Extra extension).
+#[derive(Debug)]
+pub struct DescriptionList<'a> {
+    /// Term and details children, in source order.
+    pub children: Vec<'a, Node<'a>>,
+    /// Source span.
+    pub span: Span,
+}
+
+/// Term inside a definition list.
+#[derive(Debug)]
+pub struct DescriptionTerm<'a> {
+    /// Inline children.
+    pub children: Vec<'a, Node<'a>>,
+    /// Source span.
+    pub span: Span,
+}
+
+/// Definition inside a definition list.
+#[derive(Debug)]
+pub struct DescriptionDetails<'a> {
+    /// Inline children.
+    pub children: Vec<'a, Node<'a>>,
+    /// Source span.
+    pub span: Span,
+}
+
 /// Column alignment in tables.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum AlignKind {
@@ -237,6 +302,9 @@ pub struct InlineCode<'a> {
 /// Line break.
 #[derive(Debug)]
 pub struct Break {
+    /// Whether this is a hard break (trailing backslash or two or more
+    /// trailing spaces) rather than a soft line ending within a paragraph.
+    pub hard: bool,
     /// Source span.
     pub span: Span,
 }
@@ -276,6 +344,33 @@ pub struct Delete<'a> {
     pub span: Span,
 }
 
+/// Highlighted text (`==mark==` extension).
+#[derive(Debug)]
+pub struct Mark<'a> {
+    /// Inline children.
+    pub children: Vec<'a, Node<'a>>,
+    /// Source span.
+    pub span: Span,
+}
+
+/// Subscript (`~sub~` extension).
+#[derive(Debug)]
+pub struct Subscript<'a> {
+    /// Inline children.
+    pub children: Vec<'a, Node<'a>>,
+    /// Source span.
+    pub span: Span,
+}
+
+/// Superscript (`^sup^` extension).
+#[derive(Debug)]
+pub struct Superscript<'a> {
+    /// Inline children.
+    pub children: Vec<'a, Node<'a>>,
+    /// Source span.
+    pub span: Span,
+}
+
 /// Footnote reference (GFM extension).
 #[derive(Debug)]
 pub struct FootnoteReference<'a> {
@@ -331,6 +426,10 @@ impl<'a> Node<'a> {
             Self::CodeBlock(n) => n.span,
             Self::Html(n) => n.span,
             Self::Table(n) => n.span,
+            Self::DescriptionList(n) => n.span,
+            Self::DescriptionTerm(n) => n.span,
+            Self::DescriptionDetails(n) => n.span,
+            Self::Container(n) => n.span,
             Self::Text(n) => n.span,
             Self::Emphasis(n) => n.span,
             Self::Strong(n) => n.span,
@@ -339,6 +438,9 @@ impl<'a> Node<'a> {
             Self::Link(n) => n.span,
             Self::Image(n) => n.span,
             Self::Delete(n) => n.span,
+            Self::Mark(n) => n.span,
+            Self::Subscript(n) => n.span,
+            Self::Superscript(n) => n.span,
             Self::FootnoteReference(n) => n.span,
             Self::Definition(n) => n.span,
             Self::FootnoteDefinition(n) => n.span,
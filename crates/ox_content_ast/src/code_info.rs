@@ -0,0 +1,178 @@
+//! Structured parsing of fenced code block info strings.
+
+use crate::ast::CodeBlock;
+
+/// Structured view of a fenced code block info string.
+///
+/// For ` ```ts title="foo.ts" {1,3-4} `, `lang` is `ts`, `title` is `foo.ts`
+/// and `highlights` is `[(1, 1), (3, 4)]`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CodeInfo<'a> {
+    /// Language identifier without inline attributes (`ts{1}` yields `ts`).
+    pub lang: Option<&'a str>,
+    /// Title from `title="..."` or a `[...]` token.
+    pub title: Option<&'a str>,
+    /// Inclusive 1-based line ranges from `{...}` tokens.
+    pub highlights: Vec<(u32, u32)>,
+    /// `key=value` attributes and bare flags, in source order.
+    pub attributes: Vec<(&'a str, Option<&'a str>)>,
+}
+
+impl<'a> CodeInfo<'a> {
+    /// Parses a language token and meta string into structured info.
+    #[must_use]
+    pub fn parse(lang: Option<&'a str>, meta: Option<&'a str>) -> Self {
+        let mut info = Self::default();
+
+        if let Some(raw_lang) = lang.map(str::trim).filter(|value| !value.is_empty()) {
+            let split = raw_lang.find(['{', '[']).unwrap_or(raw_lang.len());
+            let name = &raw_lang[..split];
+            if !name.is_empty() {
+                info.lang = Some(name);
+            }
+            info.parse_meta(&raw_lang[split..]);
+        }
+
+        if let Some(meta) = meta {
+            info.parse_meta(meta);
+        }
+
+        info
+    }
+
+    /// Returns true if the given 1-based line is highlighted.
+    #[must_use]
+    pub fn is_highlighted(&self, line: u32) -> bool {
+        self.highlights.iter().any(|&(start, end)| (start..=end).contains(&line))
+    }
+
+    /// Returns the value of an attribute, if present.
+    #[must_use]
+    pub fn attribute(&self, key: &str) -> Option<&'a str> {
+        self.attributes.iter().find(|(name, _)| *name == key).and_then(|(_, value)| *value)
+    }
+
+    fn parse_meta(&mut self, meta: &'a str) {
+        let bytes = meta.as_bytes();
+        let mut index = 0;
+
+        while index < bytes.len() {
+            if bytes[index].is_ascii_whitespace() {
+                index += 1;
+                continue;
+            }
+
+            match bytes[index] {
+                b'{' | b'[' => {
+                    let close = if bytes[index] == b'{' { b'}' } else { b']' };
+                    let start = index + 1;
+                    let end = bytes[start..]
+                        .iter()
+                        .position(|&byte| byte == close)
+                        .map_or(bytes.len(), |offset| start + offset);
+                    let value = &meta[start..end];
+                    if close == b'}' {
+                        self.highlights.extend(parse_ranges(value));
+                    } else if self.title.is_none() && !value.trim().is_empty() {
+                        self.title = Some(value.trim());
+                    }
+                    index = (end + 1).min(bytes.len());
+                }
+                _ => {
+                    let key_start = index;
+                    while index < bytes.len()
+                        && !bytes[index].is_ascii_whitespace()
+                        && !matches!(bytes[index], b'=' | b'{' | b'[')
+                    {
+                        index += 1;
+                    }
+                    let key = &meta[key_start..index];
+
+                    if index >= bytes.len() || bytes[index] != b'=' {
+                        self.attributes.push((key, None));
+                        continue;
+                    }
+
+                    index += 1;
+                    let value = if index < bytes.len() && matches!(bytes[index], b'"' | b'\'') {
+                        let quote = bytes[index];
+                        let start = index + 1;
+                        let end = bytes[start..]
+                            .iter()
+                            .position(|&byte| byte == quote)
+                            .map_or(bytes.len(), |offset| start + offset);
+                        index = (end + 1).min(bytes.len());
+                        &meta[start..end]
+                    } else {
+                        let start = index;
+                        while index < bytes.len() && !bytes[index].is_ascii_whitespace() {
+                            index += 1;
+                        }
+                        &meta[start..index]
+                    };
+
+                    if key.is_empty() {
+                        continue;
+                    }
+                    if key == "title" && self.title.is_none() {
+                        self.title = Some(value);
+                    }
+                    self.attributes.push((key, Some(value)));
+                }
+            }
+        }
+    }
+}
+
+impl<'a> CodeBlock<'a> {
+    /// Parses the info string of this code block.
+    #[must_use]
+    pub fn info(&self) -> CodeInfo<'a> {
+        CodeInfo::parse(self.lang, self.meta)
+    }
+}
+
+/// Parses a comma separated list of line numbers and ranges (`1,3-5`).
+fn parse_ranges(value: &str) -> impl Iterator<Item = (u32, u32)> + '_ {
+    value.split(',').map(str::trim).filter_map(|part| {
+        let (start, end) = part.split_once('-').unwrap_or((part, part));
+        let start = start.trim().parse::<u32>().ok().filter(|start| *start > 0)?;
+        let end = end.trim().parse::<u32>().ok().filter(|end| *end >= start)?;
+        Some((start, end))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_title_attribute() {
+        let info = CodeInfo::parse(Some("ts"), Some("title=\"foo.ts\" showLineNumbers"));
+        assert_eq!(info.lang, Some("ts"));
+        assert_eq!(info.title, Some("foo.ts"));
+        assert_eq!(info.attribute("title"), Some("foo.ts"));
+        assert_eq!(info.attributes[1], ("showLineNumbers", None));
+    }
+
+    #[test]
+    fn test_parse_brace_ranges() {
+        let info = CodeInfo::parse(Some("rust"), Some("{1,3-4}"));
+        assert_eq!(info.highlights, vec![(1, 1), (3, 4)]);
+        assert!(info.is_highlighted(4));
+        assert!(!info.is_highlighted(2));
+    }
+
+    #[test]
+    fn test_parse_inline_lang_attributes() {
+        let info = CodeInfo::parse(Some("ts{2}[app.ts]"), None);
+        assert_eq!(info.lang, Some("ts"));
+        assert_eq!(info.highlights, vec![(2, 2)]);
+        assert_eq!(info.title, Some("app.ts"));
+    }
+
+    #[test]
+    fn test_parse_empty_info() {
+        assert_eq!(CodeInfo::parse(None, None), CodeInfo::default());
+    }
+}
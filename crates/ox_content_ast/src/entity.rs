@@ -0,0 +1,129 @@
+//! HTML entity decoding for inline text.
+
+/// Named entities recognized in text, sorted by name.
+const NAMED_ENTITIES: &[(&str, char)] = &[
+    ("AElig", 'Æ'),
+    ("Aacute", 'Á'),
+    ("Eacute", 'É'),
+    ("Ntilde", 'Ñ'),
+    ("Ouml", 'Ö'),
+    ("Uuml", 'Ü'),
+    ("aacute", 'á'),
+    ("acute", '´'),
+    ("aelig", 'æ'),
+    ("agrave", 'à'),
+    ("amp", '&'),
+    ("apos", '\''),
+    ("auml", 'ä'),
+    ("bull", '•'),
+    ("ccedil", 'ç'),
+    ("cent", '¢'),
+    ("copy", '©'),
+    ("darr", '↓'),
+    ("deg", '°'),
+    ("divide", '÷'),
+    ("eacute", 'é'),
+    ("egrave", 'è'),
+    ("euro", '€'),
+    ("frac12", '½'),
+    ("frac14", '¼'),
+    ("frac34", '¾'),
+    ("ge", '≥'),
+    ("gt", '>'),
+    ("harr", '↔'),
+    ("hellip", '…'),
+    ("iacute", 'í'),
+    ("iexcl", '¡'),
+    ("infin", '∞'),
+    ("iquest", '¿'),
+    ("laquo", '«'),
+    ("larr", '←'),
+    ("ldquo", '“'),
+    ("le", '≤'),
+    ("lsquo", '‘'),
+    ("lt", '<'),
+    ("mdash", '—'),
+    ("micro", 'µ'),
+    ("middot", '·'),
+    ("nbsp", '\u{a0}'),
+    ("ndash", '–'),
+    ("ne", '≠'),
+    ("ntilde", 'ñ'),
+    ("oacute", 'ó'),
+    ("ouml", 'ö'),
+    ("para", '¶'),
+    ("plusmn", '±'),
+    ("pound", '£'),
+    ("quot", '"'),
+    ("raquo", '»'),
+    ("rarr", '→'),
+    ("rdquo", '”'),
+    ("reg", '®'),
+    ("rsquo", '’'),
+    ("sect", '§'),
+    ("shy", '\u{ad}'),
+    ("szlig", 'ß'),
+    ("times", '×'),
+    ("trade", '™'),
+    ("uacute", 'ú'),
+    ("uarr", '↑'),
+    ("uuml", 'ü'),
+    ("yen", '¥'),
+];
+
+/// Longest reference body scanned for the closing `;`, so a stray `&` in a
+/// long paragraph doesn't search the rest of the text.
+const MAX_ENTITY_LEN: usize = 32;
+
+/// Decodes an entity reference (`&name;`, `&#123;` or `&#x1F;`) at the start of `text`.
+///
+/// Returns the decoded character and the byte length of the reference.
+/// Numeric references to NUL, surrogates or out-of-range code points decode
+/// to U+FFFD, as in CommonMark.
+pub fn decode_entity(text: &str) -> Option<(char, usize)> {
+    let body = text.strip_prefix('&')?;
+    let end = body.bytes().take(MAX_ENTITY_LEN).position(|b| b == b';')?;
+    let name = &body[..end];
+    let len = end + 2;
+
+    if let Some(number) = name.strip_prefix('#') {
+        let (digits, radix, max_len) = match number.strip_prefix(['x', 'X']) {
+            Some(hex) => (hex, 16, 6),
+            None => (number, 10, 7),
+        };
+        if digits.is_empty() || digits.len() > max_len || !digits.chars().all(|c| c.is_digit(radix))
+        {
+            return None;
+        }
+        let code = u32::from_str_radix(digits, radix).ok()?;
+        let ch = if code == 0 { None } else { char::from_u32(code) };
+        return Some((ch.unwrap_or('\u{fffd}'), len));
+    }
+
+    NAMED_ENTITIES
+        .binary_search_by(|(entity, _)| entity.cmp(&name))
+        .ok()
+        .map(|index| (NAMED_ENTITIES[index].1, len))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn named_entities_sorted() {
+        assert!(NAMED_ENTITIES.windows(2).all(|pair| pair[0].0 < pair[1].0));
+    }
+
+    #[test]
+    fn decode_named_and_numeric() {
+        assert_eq!(decode_entity("&amp; rest"), Some(('&', 5)));
+        assert_eq!(decode_entity("&#x2014;"), Some(('—', 8)));
+        assert_eq!(decode_entity("&#35;"), Some(('#', 5)));
+        assert_eq!(decode_entity("&#0;"), Some(('\u{fffd}', 4)));
+        assert_eq!(decode_entity("&unknown;"), None);
+        assert_eq!(decode_entity("&amp"), None);
+        assert_eq!(decode_entity("&#xZZ;"), None);
+        assert_eq!(decode_entity(&format!("&{};", "a".repeat(40))), None);
+    }
+}
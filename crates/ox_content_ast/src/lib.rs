@@ -5,9 +5,13 @@
 //! providing efficient arena-based allocation.
 
 mod ast;
+mod code_info;
+mod entity;
 mod span;
 mod visit;
 
 pub use ast::*;
+pub use code_info::*;
+pub use entity::*;
 pub use span::*;
 pub use visit::*;
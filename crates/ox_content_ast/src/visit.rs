@@ -63,6 +63,26 @@ pub trait Visit<'a> {
         walk_table_cell(self, table_cell);
     }
 
+    /// Visits a definition list.
+    fn visit_description_list(&mut self, description_list: &DescriptionList<'a>) {
+        walk_description_list(self, description_list);
+    }
+
+    /// Visits a definition list term.
+    fn visit_description_term(&mut self, description_term: &DescriptionTerm<'a>) {
+        walk_description_term(self, description_term);
+    }
+
+    /// Visits a definition list details entry.
+    fn visit_description_details(&mut self, description_details: &DescriptionDetails<'a>) {
+        walk_description_details(self, description_details);
+    }
+
+    /// Visits a custom container.
+    fn visit_container(&mut self, container: &Container<'a>) {
+        walk_container(self, container);
+    }
+
     /// Visits text.
     fn visit_text(&mut self, _text: &Text<'a>) {}
 
@@ -95,6 +115,21 @@ pub trait Visit<'a> {
         walk_delete(self, delete);
     }
 
+    /// Visits highlighted text.
+    fn visit_mark(&mut self, mark: &Mark<'a>) {
+        walk_mark(self, mark);
+    }
+
+    /// Visits subscript.
+    fn visit_subscript(&mut self, subscript: &Subscript<'a>) {
+        walk_subscript(self, subscript);
+    }
+
+    /// Visits superscript.
+    fn visit_superscript(&mut self, superscript: &Superscript<'a>) {
+        walk_superscript(self, superscript);
+    }
+
     /// Visits a footnote reference.
     fn visit_footnote_reference(&mut self, _footnote_ref: &FootnoteReference<'a>) {}
 
@@ -126,6 +161,10 @@ pub fn walk_node<'a, V: Visit<'a> + ?Sized>(visitor: &mut V, node: &Node<'a>) {
         Node::CodeBlock(n) => visitor.visit_code_block(n),
         Node::Html(n) => visitor.visit_html(n),
         Node::Table(n) => visitor.visit_table(n),
+        Node::DescriptionList(n) => visitor.visit_description_list(n),
+        Node::DescriptionTerm(n) => visitor.visit_description_term(n),
+        Node::DescriptionDetails(n) => visitor.visit_description_details(n),
+        Node::Container(n) => visitor.visit_container(n),
         Node::Text(n) => visitor.visit_text(n),
         Node::Emphasis(n) => visitor.visit_emphasis(n),
         Node::Strong(n) => visitor.visit_strong(n),
@@ -134,6 +173,9 @@ pub fn walk_node<'a, V: Visit<'a> + ?Sized>(visitor: &mut V, node: &Node<'a>) {
         Node::Link(n) => visitor.visit_link(n),
         Node::Image(n) => visitor.visit_image(n),
         Node::Delete(n) => visitor.visit_delete(n),
+        Node::Mark(n) => visitor.visit_mark(n),
+        Node::Subscript(n) => visitor.visit_subscript(n),
+        Node::Superscript(n) => visitor.visit_superscript(n),
         Node::FootnoteReference(n) => visitor.visit_footnote_reference(n),
         Node::Definition(n) => visitor.visit_definition(n),
         Node::FootnoteDefinition(n) => visitor.visit_footnote_definition(n),
@@ -196,6 +238,43 @@ pub fn walk_table_cell<'a, V: Visit<'a> + ?Sized>(visitor: &mut V, table_cell: &
     }
 }
 
+/// Walks through a definition list's children.
+pub fn walk_description_list<'a, V: Visit<'a> + ?Sized>(
+    visitor: &mut V,
+    description_list: &DescriptionList<'a>,
+) {
+    for child in &description_list.children {
+        visitor.visit_node(child);
+    }
+}
+
+/// Walks through a definition list term's children.
+pub fn walk_description_term<'a, V: Visit<'a> + ?Sized>(
+    visitor: &mut V,
+    description_term: &DescriptionTerm<'a>,
+) {
+    for child in &description_term.children {
+        visitor.visit_node(child);
+    }
+}
+
+/// Walks through a definition list details entry's children.
+pub fn walk_description_details<'a, V: Visit<'a> + ?Sized>(
+    visitor: &mut V,
+    description_details: &DescriptionDetails<'a>,
+) {
+    for child in &description_details.children {
+        visitor.visit_node(child);
+    }
+}
+
+/// Walks through a custom container's children.
+pub fn walk_container<'a, V: Visit<'a> + ?Sized>(visitor: &mut V, container: &Container<'a>) {
+    for child in &container.children {
+        visitor.visit_node(child);
+    }
+}
+
 /// Walks through emphasis children.
 pub fn walk_emphasis<'a, V: Visit<'a> + ?Sized>(visitor: &mut V, emphasis: &Emphasis<'a>) {
     for child in &emphasis.children {
@@ -224,6 +303,27 @@ pub fn walk_delete<'a, V: Visit<'a> + ?Sized>(visitor: &mut V, delete: &Delete<'
     }
 }
 
+/// Walks through highlighted text children.
+pub fn walk_mark<'a, V: Visit<'a> + ?Sized>(visitor: &mut V, mark: &Mark<'a>) {
+    for child in &mark.children {
+        visitor.visit_node(child);
+    }
+}
+
+/// Walks through subscript children.
+pub fn walk_subscript<'a, V: Visit<'a> + ?Sized>(visitor: &mut V, subscript: &Subscript<'a>) {
+    for child in &subscript.children {
+        visitor.visit_node(child);
+    }
+}
+
+/// Walks through superscript children.
+pub fn walk_superscript<'a, V: Visit<'a> + ?Sized>(visitor: &mut V, superscript: &Superscript<'a>) {
+    for child in &superscript.children {
+        visitor.visit_node(child);
+    }
+}
+
 /// Walks through a footnote definition's children.
 pub fn walk_footnote_definition<'a, V: Visit<'a> + ?Sized>(
     visitor: &mut V,
@@ -1,5 +1,7 @@
 //! Configuration for documentation generation.
 
+use std::collections::BTreeMap;
+
 use serde::{Deserialize, Serialize};
 
 /// Configuration for documentation generation.
@@ -25,6 +27,12 @@ pub struct DocsConfig {
 
     /// Theme for the generated docs.
     pub theme: Option<String>,
+
+    /// Maps source path prefixes to module names for navigation grouping.
+    ///
+    /// Items whose path matches no prefix are grouped by their source file.
+    #[serde(default)]
+    pub modules: BTreeMap<String, String>,
 }
 
 impl Default for DocsConfig {
@@ -47,6 +55,7 @@ impl Default for DocsConfig {
             json: false,
             document_private: false,
             theme: None,
+            modules: BTreeMap::new(),
         }
     }
 }
@@ -57,8 +57,15 @@ pub struct DocItem {
     pub signature: Option<String>,
     /// Parameters (for functions/methods).
     pub params: Vec<ParamDoc>,
+    /// Generic type parameters (for functions, methods, classes, interfaces and type aliases).
+    #[serde(default)]
+    pub type_params: Vec<TypeParamDoc>,
     /// Return type (for functions/methods).
     pub return_type: Option<String>,
+    /// Every signature of an overloaded function, in declaration order
+    /// (empty unless the function has more than one signature).
+    #[serde(default)]
+    pub overloads: Vec<String>,
     /// Child items (for classes, modules, etc.).
     pub children: Vec<DocItem>,
     /// JSDoc tags.
@@ -80,6 +87,19 @@ pub struct ParamDoc {
     pub description: Option<String>,
 }
 
+/// Generic type parameter documentation.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TypeParamDoc {
+    /// Type parameter name.
+    pub name: String,
+    /// `extends` constraint (if any).
+    pub constraint: Option<String>,
+    /// Default type (if any).
+    pub default: Option<String>,
+    /// Description from JSDoc @typeParam (or @template) tag.
+    pub description: Option<String>,
+}
+
 /// JSDoc tag.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct DocTag {
@@ -90,7 +110,7 @@ pub struct DocTag {
 }
 
 /// Kind of documentation item.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
 #[serde(rename_all = "lowercase")]
 pub enum DocItemKind {
     /// Module or namespace.
@@ -205,6 +225,9 @@ struct DocVisitor<'a> {
     items: Vec<DocItem>,
     /// Track default export
     has_default_export: bool,
+    /// `items.len()` right after a function overload signature was recorded;
+    /// a following declaration of the same function is merged into that item.
+    open_overload: Option<usize>,
 }
 
 impl<'a> DocVisitor<'a> {
@@ -230,6 +253,7 @@ impl<'a> DocVisitor<'a> {
             line_starts,
             items: Vec::new(),
             has_default_export: false,
+            open_overload: None,
         }
     }
 
@@ -653,6 +677,42 @@ impl<'a> DocVisitor<'a> {
             .collect()
     }
 
+    /// Extracts type parameters with their constraints, defaults and descriptions.
+    fn extract_type_params(
+        &self,
+        type_params: Option<&oxc_allocator::Box<'a, oxc_ast::ast::TSTypeParameterDeclaration<'a>>>,
+        tags: &[DocTag],
+    ) -> Vec<TypeParamDoc> {
+        let Some(type_params) = type_params else {
+            return Vec::new();
+        };
+
+        type_params
+            .params
+            .iter()
+            .map(|param| {
+                let name = param.name.name.to_string();
+                let description = tags
+                    .iter()
+                    .filter(|t| t.tag == "typeParam" || t.tag == "template")
+                    .find_map(|t| type_param_description(&t.value, &name));
+
+                TypeParamDoc {
+                    constraint: param
+                        .constraint
+                        .as_ref()
+                        .map(|c| self.slice(c.span().start, c.span().end)),
+                    default: param
+                        .default
+                        .as_ref()
+                        .map(|d| self.slice(d.span().start, d.span().end)),
+                    name,
+                    description,
+                }
+            })
+            .collect()
+    }
+
     /// Extract parameters from a function.
     fn extract_params(&self, func: &Function, tags: &[DocTag]) -> Vec<ParamDoc> {
         self.extract_params_from_formals(&func.params, tags)
@@ -703,7 +763,9 @@ impl<'a> DocVisitor<'a> {
                 exported,
             )),
             params: self.extract_params(func, &tags),
+            type_params: self.extract_type_params(func.type_parameters.as_ref(), &tags),
             return_type: self.extract_return_type(func, &tags),
+            overloads: Vec::new(),
             children: Vec::new(),
             tags,
         })
@@ -770,7 +832,12 @@ impl<'a> DocVisitor<'a> {
                             method.value.return_type.as_ref(),
                         )),
                         params: self.extract_params(&method.value, &method_tags),
+                        type_params: self.extract_type_params(
+                            method.value.type_parameters.as_ref(),
+                            &method_tags,
+                        ),
                         return_type: self.extract_return_type(&method.value, &method_tags),
+                        overloads: Vec::new(),
                         children: Vec::new(),
                         tags: method_tags,
                     });
@@ -809,7 +876,9 @@ impl<'a> DocVisitor<'a> {
                         exported: false,
                         signature: type_annotation,
                         params: Vec::new(),
+                        type_params: Vec::new(),
                         return_type: None,
+                        overloads: Vec::new(),
                         children: Vec::new(),
                         tags: prop_tags,
                     });
@@ -830,7 +899,9 @@ impl<'a> DocVisitor<'a> {
             exported,
             signature: Some(self.format_class_signature(class, name, exported)),
             params: Vec::new(),
+            type_params: self.extract_type_params(class.type_parameters.as_ref(), &tags),
             return_type: None,
+            overloads: Vec::new(),
             children,
             tags,
         })
@@ -881,6 +952,37 @@ impl<'a> Visit<'a> for DocVisitor<'a> {
 }
 
 impl<'a> DocVisitor<'a> {
+    /// Merges a declaration that directly follows an overload signature of the
+    /// same function into the existing item. Further overload signatures are
+    /// added to `overloads`; the implementation signature is not documented.
+    fn merge_overload(&mut self, func: &Function<'a>, exported: bool) -> bool {
+        let Some(name) = func.id.as_ref().map(|id| id.name.as_str()) else {
+            return false;
+        };
+        if self.open_overload != Some(self.items.len()) {
+            return false;
+        }
+        let signature = self.format_function_signature(func, name, exported);
+        let (_, end_line) = self.span_lines(func.span.start, func.span.end);
+        let Some(item) = self.items.last_mut() else {
+            return false;
+        };
+        if item.kind != DocItemKind::Function || item.name != name {
+            return false;
+        }
+
+        if func.body.is_none() {
+            if item.overloads.is_empty() {
+                item.overloads.extend(item.signature.clone());
+            }
+            item.overloads.push(signature);
+        } else {
+            self.open_overload = None;
+        }
+        item.end_line = end_line;
+        true
+    }
+
     fn visit_declaration_as_exported(&mut self, decl: &Declaration<'a>, attached_to: u32) {
         self.visit_declaration_internal(decl, true, attached_to);
     }
@@ -893,8 +995,15 @@ impl<'a> DocVisitor<'a> {
     ) {
         match decl {
             Declaration::FunctionDeclaration(func) => {
+                if self.merge_overload(func, exported) {
+                    return;
+                }
+                self.open_overload = None;
                 if let Some(item) = self.create_function_item(func, exported, attached_to) {
                     self.items.push(item);
+                    if func.body.is_none() {
+                        self.open_overload = Some(self.items.len());
+                    }
                 }
             }
             Declaration::ClassDeclaration(class) => {
@@ -943,10 +1052,13 @@ impl<'a> DocVisitor<'a> {
                                         arrow.return_type.as_ref(),
                                     )),
                                     params: self.extract_params_from_formals(&arrow.params, &tags),
+                                    type_params: self
+                                        .extract_type_params(arrow.type_parameters.as_ref(), &tags),
                                     return_type: self.extract_return_type_from_annotation(
                                         arrow.return_type.as_ref(),
                                         &tags,
                                     ),
+                                    overloads: Vec::new(),
                                     children: Vec::new(),
                                     tags: tags.clone(),
                                 });
@@ -970,7 +1082,12 @@ impl<'a> DocVisitor<'a> {
                                         func_expr.return_type.as_ref(),
                                     )),
                                     params: self.extract_params(func_expr, &tags),
+                                    type_params: self.extract_type_params(
+                                        func_expr.type_parameters.as_ref(),
+                                        &tags,
+                                    ),
                                     return_type: self.extract_return_type(func_expr, &tags),
+                                    overloads: Vec::new(),
                                     children: Vec::new(),
                                     tags: tags.clone(),
                                 });
@@ -1001,7 +1118,10 @@ impl<'a> DocVisitor<'a> {
                     exported,
                     signature: Some(self.format_type_alias_signature(type_alias, exported)),
                     params: Vec::new(),
+                    type_params: self
+                        .extract_type_params(type_alias.type_parameters.as_ref(), &tags),
                     return_type: None,
+                    overloads: Vec::new(),
                     children: Vec::new(),
                     tags,
                 });
@@ -1056,7 +1176,9 @@ impl<'a> DocVisitor<'a> {
                                 exported: false,
                                 signature: type_annotation,
                                 params: Vec::new(),
+                                type_params: Vec::new(),
                                 return_type: None,
+                                overloads: Vec::new(),
                                 children: Vec::new(),
                                 tags: prop_tags,
                             });
@@ -1099,10 +1221,15 @@ impl<'a> DocVisitor<'a> {
                                 )),
                                 params: self
                                     .extract_params_from_formals(&method.params, &method_tags),
+                                type_params: self.extract_type_params(
+                                    method.type_parameters.as_ref(),
+                                    &method_tags,
+                                ),
                                 return_type: self.extract_return_type_from_annotation(
                                     method.return_type.as_ref(),
                                     &method_tags,
                                 ),
+                                overloads: Vec::new(),
                                 children: Vec::new(),
                                 tags: method_tags,
                             });
@@ -1123,7 +1250,10 @@ impl<'a> DocVisitor<'a> {
                     exported,
                     signature: Some(self.format_interface_signature(interface, exported)),
                     params: Vec::new(),
+                    type_params: self
+                        .extract_type_params(interface.type_parameters.as_ref(), &tags),
                     return_type: None,
+                    overloads: Vec::new(),
                     children,
                     tags,
                 });
@@ -1159,7 +1289,9 @@ impl<'a> DocVisitor<'a> {
                             exported: false,
                             signature: None,
                             params: Vec::new(),
+                            type_params: Vec::new(),
                             return_type: None,
+                            overloads: Vec::new(),
                             children: Vec::new(),
                             tags: Vec::new(),
                         }
@@ -1178,7 +1310,9 @@ impl<'a> DocVisitor<'a> {
                     exported,
                     signature: None,
                     params: Vec::new(),
+                    type_params: Vec::new(),
                     return_type: None,
+                    overloads: Vec::new(),
                     children,
                     tags,
                 });
@@ -1188,6 +1322,22 @@ impl<'a> DocVisitor<'a> {
     }
 }
 
+/// Returns the description from a `@typeParam`/`@template` tag value if it
+/// documents `name`: `T - description`, `T description` or `{Bound} T description`.
+fn type_param_description(value: &str, name: &str) -> Option<String> {
+    let value = value.trim_start();
+    let value = match value.strip_prefix('{') {
+        Some(rest) => rest.split_once('}').map_or(value, |(_, rest)| rest.trim_start()),
+        None => value,
+    };
+    let end = value.find(char::is_whitespace).unwrap_or(value.len());
+    if &value[..end] != name {
+        return None;
+    }
+    let description = value[end..].trim_start().trim_start_matches('-').trim();
+    (!description.is_empty()).then(|| description.to_string())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1217,6 +1367,88 @@ export function add(a: number, b: number): number {
         assert_eq!(items[0].params.len(), 2);
     }
 
+    #[test]
+    fn test_extract_function_overloads() {
+        let source = r"
+/**
+ * Parses a document.
+ */
+export declare function parse(source: string): Document;
+export declare function parse(source: string, options: ParseOptions): Document;
+
+/** Formats a document. */
+export function format(doc: Document): string;
+export function format(doc: Document, indent: number): string;
+export function format(doc: Document, indent?: number): string {
+    return String(doc) + String(indent);
+}
+";
+
+        let extractor = DocExtractor::new();
+        let items = extractor.extract_source(source, "test.d.ts", SourceType::d_ts()).unwrap();
+
+        assert_eq!(items.len(), 1);
+        assert_eq!(items[0].name, "parse");
+        assert_eq!(
+            items[0].overloads,
+            vec![
+                "export declare function parse(source: string): Document",
+                "export declare function parse(source: string, options: ParseOptions): Document",
+            ]
+        );
+        assert_eq!(items[0].signature.as_deref(), Some(items[0].overloads[0].as_str()));
+        assert_eq!(items[0].end_line, 6);
+
+        let items = extractor
+            .extract_source(&source[source.find("/** F").unwrap()..], "test.ts", SourceType::ts())
+            .unwrap();
+        assert_eq!(items.len(), 1);
+        assert_eq!(items[0].overloads.len(), 2);
+        assert!(items[0].overloads.iter().all(|sig| !sig.contains("indent?")));
+    }
+
+    #[test]
+    fn test_extract_generic_function() {
+        let source = r"
+/**
+ * Picks a key from an object.
+ * @typeParam T - The object type
+ * @typeParam K - Keys of `T` to pick
+ */
+export function pick<T extends object, K extends keyof T = keyof T>(obj: T, key: K): T[K] {
+    return obj[key];
+}
+";
+
+        let extractor = DocExtractor::new();
+        let items = extractor.extract_source(source, "test.ts", SourceType::ts()).unwrap();
+
+        assert_eq!(
+            items[0].signature.as_deref(),
+            Some(
+                "export function pick<T extends object, K extends keyof T = keyof T>(obj: T, key: K): T[K]"
+            )
+        );
+        let type_params = &items[0].type_params;
+        assert_eq!(type_params.len(), 2);
+        assert_eq!(type_params[0].name, "T");
+        assert_eq!(type_params[0].constraint.as_deref(), Some("object"));
+        assert_eq!(type_params[0].default, None);
+        assert_eq!(type_params[0].description.as_deref(), Some("The object type"));
+        assert_eq!(type_params[1].name, "K");
+        assert_eq!(type_params[1].constraint.as_deref(), Some("keyof T"));
+        assert_eq!(type_params[1].default.as_deref(), Some("keyof T"));
+        assert_eq!(type_params[1].description.as_deref(), Some("Keys of `T` to pick"));
+    }
+
+    #[test]
+    fn test_type_param_description() {
+        assert_eq!(type_param_description("T - The type", "T").as_deref(), Some("The type"));
+        assert_eq!(type_param_description("{string} T The type", "T").as_deref(), Some("The type"));
+        assert_eq!(type_param_description("TValue - The value", "T"), None);
+        assert_eq!(type_param_description("T", "T"), None);
+    }
+
     #[test]
     fn test_extract_interface() {
         let source = r"
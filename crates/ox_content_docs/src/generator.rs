@@ -1,10 +1,12 @@
 //! Documentation site generator.
 
+use std::collections::{BTreeMap, HashMap};
 use std::path::Path;
 
 use crate::config::DocsConfig;
 use crate::extractor::{DocExtractor, DocItem, ExtractResult};
 
+use serde::{Deserialize, Serialize};
 use thiserror::Error;
 
 /// Result type for generation operations.
@@ -26,6 +28,15 @@ pub enum GenerateError {
     Template(String),
 }
 
+/// A navigation group: a module and the items documented in it.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DocModule {
+    /// Module name (configured name or source path).
+    pub name: String,
+    /// Items in the module, sorted by kind then name.
+    pub items: Vec<DocItem>,
+}
+
 /// Documentation generator.
 pub struct DocsGenerator {
     config: DocsConfig,
@@ -48,7 +59,8 @@ impl DocsGenerator {
 
     /// Generates documentation for all source files.
     pub fn generate(&self) -> GenerateResult<()> {
-        let items = self.extract_all()?;
+        let mut items = self.extract_all()?;
+        Self::resolve_links(&mut items);
         self.render(&items)?;
         Ok(())
     }
@@ -89,6 +101,46 @@ impl DocsGenerator {
         Ok(items)
     }
 
+    /// Resolves `{@link Name}` references in descriptions and tag values.
+    ///
+    /// References to documented items become Markdown links to the item's
+    /// anchor; unknown names are left as plain text.
+    pub fn resolve_links(items: &mut [DocItem]) {
+        let mut anchors = HashMap::new();
+        collect_anchors(items, None, &mut anchors);
+        resolve_item_links(items, &anchors);
+    }
+
+    /// Groups items by module into a navigation tree.
+    ///
+    /// Modules are sorted by name; items within a module by kind, then name.
+    #[must_use]
+    pub fn group_by_module(&self, items: &[DocItem]) -> Vec<DocModule> {
+        let mut groups: BTreeMap<&str, Vec<DocItem>> = BTreeMap::new();
+        for item in items {
+            groups.entry(self.module_name(&item.source_path)).or_default().push(item.clone());
+        }
+
+        groups
+            .into_iter()
+            .map(|(name, mut items)| {
+                items.sort_by(|a, b| a.kind.cmp(&b.kind).then_with(|| a.name.cmp(&b.name)));
+                DocModule { name: name.to_string(), items }
+            })
+            .collect()
+    }
+
+    /// Resolves the module name for a source path using the longest
+    /// configured prefix, falling back to the path itself.
+    fn module_name<'a>(&'a self, source_path: &'a str) -> &'a str {
+        self.config
+            .modules
+            .iter()
+            .filter(|(prefix, _)| source_path.starts_with(prefix.as_str()))
+            .max_by_key(|(prefix, _)| prefix.len())
+            .map_or(source_path, |(_, name)| name.as_str())
+    }
+
     /// Checks if a file should be included.
     fn should_include(&self, path: &Path) -> bool {
         let path_str = path.to_string_lossy();
@@ -119,6 +171,10 @@ impl DocsGenerator {
             let json = serde_json::to_string_pretty(items)
                 .map_err(|e| GenerateError::Template(e.to_string()))?;
             std::fs::write(out_dir.join("docs.json"), json)?;
+
+            let nav = serde_json::to_string_pretty(&self.group_by_module(items))
+                .map_err(|e| GenerateError::Template(e.to_string()))?;
+            std::fs::write(out_dir.join("nav.json"), nav)?;
         }
 
         // TODO: Generate HTML pages
@@ -128,6 +184,63 @@ impl DocsGenerator {
     }
 }
 
+/// Returns the anchor id for a (possibly qualified) item name.
+fn item_anchor(qualified_name: &str) -> String {
+    qualified_name.to_lowercase().replace('.', "-")
+}
+
+/// Builds the name→anchor index. Children are indexed as `Parent.child`.
+fn collect_anchors(items: &[DocItem], parent: Option<&str>, anchors: &mut HashMap<String, String>) {
+    for item in items {
+        let qualified_name = match parent {
+            Some(parent) => format!("{parent}.{}", item.name),
+            None => item.name.clone(),
+        };
+        collect_anchors(&item.children, Some(&qualified_name), anchors);
+        anchors.entry(qualified_name.clone()).or_insert_with(|| item_anchor(&qualified_name));
+    }
+}
+
+fn resolve_item_links(items: &mut [DocItem], anchors: &HashMap<String, String>) {
+    for item in items {
+        if let Some(doc) = &item.doc {
+            item.doc = Some(resolve_link_text(doc, anchors));
+        }
+        for tag in &mut item.tags {
+            tag.value = resolve_link_text(&tag.value, anchors);
+        }
+        resolve_item_links(&mut item.children, anchors);
+    }
+}
+
+/// Rewrites `{@link Name}`, `{@link Name label}` and `{@link Name|label}`.
+fn resolve_link_text(text: &str, anchors: &HashMap<String, String>) -> String {
+    const OPEN: &str = "{@link ";
+
+    let mut out = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(start) = rest.find(OPEN) {
+        let Some(len) = rest[start..].find('}') else {
+            break;
+        };
+        out.push_str(&rest[..start]);
+
+        let inner = rest[start + OPEN.len()..start + len].trim();
+        let (target, label) = match inner.split_once(['|', ' ']) {
+            Some((target, label)) => (target.trim(), label.trim()),
+            None => (inner, inner),
+        };
+        match anchors.get(target) {
+            Some(anchor) => out.push_str(&format!("[{label}](#{anchor})")),
+            None => out.push_str(label),
+        }
+
+        rest = &rest[start + len + 1..];
+    }
+    out.push_str(rest);
+    out
+}
+
 /// Simple glob matching (** and * patterns).
 fn glob_match(pattern: &str, path: &str) -> bool {
     // Very simplified glob matching
@@ -166,6 +279,7 @@ fn glob_match(pattern: &str, path: &str) -> bool {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use oxc_span::SourceType;
 
     #[test]
     fn test_glob_match() {
@@ -182,4 +296,55 @@ mod tests {
         assert!(glob_match("foo.ts", "foo.ts"));
         assert!(!glob_match("foo.ts", "bar.ts"));
     }
+
+    #[test]
+    fn test_group_by_module() {
+        let extractor = DocExtractor::new();
+        let mut items = extractor
+            .extract_source(
+                "export class Parser {}\nexport function parse() {}\nexport function format() {}",
+                "src/parser.ts",
+                SourceType::ts(),
+            )
+            .unwrap();
+        items.extend(
+            extractor
+                .extract_source("export interface Options {}", "src/options.ts", SourceType::ts())
+                .unwrap(),
+        );
+
+        let generator = DocsGenerator::new(DocsConfig::default());
+        let modules = generator.group_by_module(&items);
+
+        assert_eq!(modules.len(), 2);
+        assert_eq!(modules[0].name, "src/options.ts");
+        assert_eq!(modules[0].items.len(), 1);
+        assert_eq!(modules[1].name, "src/parser.ts");
+        let names: Vec<&str> = modules[1].items.iter().map(|item| item.name.as_str()).collect();
+        assert_eq!(names, vec!["format", "parse", "Parser"]);
+
+        let mut config = DocsConfig::default();
+        config.modules.insert("src/".to_string(), "core".to_string());
+        let modules = DocsGenerator::new(config).group_by_module(&items);
+        assert_eq!(modules.len(), 1);
+        assert_eq!(modules[0].name, "core");
+        assert_eq!(modules[0].items.len(), 4);
+    }
+
+    #[test]
+    fn test_resolve_links() {
+        let source = r"
+/** Parses input using {@link Options} and {@link Missing}. */
+export function parse() {}
+
+/** Parser options. */
+export interface Options {}
+";
+        let mut items =
+            DocExtractor::new().extract_source(source, "src/parser.ts", SourceType::ts()).unwrap();
+        DocsGenerator::resolve_links(&mut items);
+
+        let doc = items.iter().find(|item| item.name == "parse").unwrap().doc.as_deref().unwrap();
+        assert_eq!(doc, "Parses input using [Options](#options) and Missing.");
+    }
 }
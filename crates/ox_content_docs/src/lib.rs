@@ -10,6 +10,6 @@ mod generator;
 
 pub use config::DocsConfig;
 pub use extractor::{
-    DocExtractor, DocItem, DocItemKind, DocTag, ExtractError, ExtractResult, ParamDoc,
+    DocExtractor, DocItem, DocItemKind, DocTag, ExtractError, ExtractResult, ParamDoc, TypeParamDoc,
 };
-pub use generator::{DocsGenerator, GenerateError, GenerateResult};
+pub use generator::{DocModule, DocsGenerator, GenerateError, GenerateResult};
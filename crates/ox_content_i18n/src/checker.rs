@@ -1,24 +1,66 @@
-use std::collections::HashSet;
+use std::borrow::Cow;
+use std::collections::{BTreeMap, HashSet};
 use std::hash::BuildHasher;
+use std::path::Path;
 
 use crate::dictionary::DictionarySet;
+use crate::locale::Locale;
 use crate::mf2;
+use crate::mf2::ast::{ComplexBody, Declaration, Message, VariantKey};
+use crate::mf2::registry::FunctionRegistry;
 
 /// Diagnostic severity level.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+///
+/// Ordered from most to least severe.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
 pub enum Severity {
     Error,
     Warning,
     Info,
 }
 
+/// Placeholder syntax a project's dictionary values are written in.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Interpolation {
+    /// ICU MessageFormat 2 placeholders: `{$name}`.
+    #[default]
+    Mf2,
+    /// i18next placeholders: `{{name}}`.
+    I18next,
+}
+
+impl Interpolation {
+    /// Parses a dialect name: `"mf2"` or `"i18next"`.
+    #[must_use]
+    pub fn parse(name: &str) -> Option<Self> {
+        match name {
+            "mf2" => Some(Self::Mf2),
+            "i18next" => Some(Self::I18next),
+            _ => None,
+        }
+    }
+}
+
 /// A single diagnostic produced by static analysis.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Diagnostic {
     pub severity: Severity,
     pub message: String,
     pub key: Option<String>,
     pub locale: Option<String>,
+    /// Source position the diagnostic points at, filled in by callers that know it.
+    pub location: Option<Location>,
+}
+
+/// A position in a source or dictionary file.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Location {
+    /// File path.
+    pub file: String,
+    /// 1-based line.
+    pub line: u32,
+    /// 1-based column.
+    pub column: u32,
 }
 
 impl std::fmt::Display for Diagnostic {
@@ -50,12 +92,13 @@ pub fn check_missing_keys<S: BuildHasher>(
     for locale in dict_set.locales() {
         if let Some(dict) = dict_set.get(locale) {
             for key in used_keys {
-                if dict.get(key).is_none() {
+                if dict.get(&dict_set.normalize_key(key)).is_none() {
                     diagnostics.push(Diagnostic {
                         severity: Severity::Error,
                         message: format!("missing translation for key '{key}'"),
                         key: Some(key.clone()),
                         locale: Some(locale.to_string()),
+                        location: None,
                     });
                 }
             }
@@ -72,6 +115,8 @@ pub fn check_unused_keys<S: BuildHasher>(
     dict_set: &DictionarySet,
 ) -> Vec<Diagnostic> {
     let mut diagnostics = Vec::new();
+    let used_keys: HashSet<Cow<'_, str>> =
+        used_keys.iter().map(|key| dict_set.normalize_key(key)).collect();
 
     for locale in dict_set.locales() {
         if let Some(dict) = dict_set.get(locale) {
@@ -82,6 +127,7 @@ pub fn check_unused_keys<S: BuildHasher>(
                         message: format!("unused translation key '{key}'"),
                         key: Some(key.to_string()),
                         locale: Some(locale.to_string()),
+                        location: None,
                     });
                 }
             }
@@ -91,6 +137,84 @@ pub fn check_unused_keys<S: BuildHasher>(
     diagnostics
 }
 
+/// Checks for dictionary files none of whose keys are used.
+///
+/// An entirely unused namespace file is usually dead and can be removed, so
+/// it is reported once per file rather than as many unused-key warnings.
+/// Entries without a recorded source file are ignored.
+#[must_use]
+pub fn check_orphaned_namespaces<S: BuildHasher>(
+    used_keys: &HashSet<String, S>,
+    dict_set: &DictionarySet,
+) -> Vec<Diagnostic> {
+    let mut diagnostics = Vec::new();
+    let used_keys: HashSet<Cow<'_, str>> =
+        used_keys.iter().map(|key| dict_set.normalize_key(key)).collect();
+
+    for locale in dict_set.locales() {
+        let Some(dict) = dict_set.get(locale) else {
+            continue;
+        };
+        // File path -> (key count, any key used)
+        let mut files: BTreeMap<&Path, (usize, bool)> = BTreeMap::new();
+        for key in dict.keys() {
+            if let Some(source) = dict.source(key) {
+                let file = files.entry(&source.path).or_default();
+                file.0 += 1;
+                file.1 |= used_keys.contains(key);
+            }
+        }
+
+        for (path, (count, used)) in files {
+            if used {
+                continue;
+            }
+            diagnostics.push(Diagnostic {
+                severity: Severity::Warning,
+                message: format!(
+                    "none of the {count} key(s) in namespace file '{}' are used",
+                    path.display()
+                ),
+                key: None,
+                locale: Some(locale.to_string()),
+                location: Some(Location {
+                    file: path.to_string_lossy().to_string(),
+                    line: 1,
+                    column: 1,
+                }),
+            });
+        }
+    }
+
+    diagnostics
+}
+
+/// Drops unused-key diagnostics for keys in a file that `orphaned` (from
+/// [`check_orphaned_namespaces`]) already reports, so a dead namespace file
+/// gets one warning instead of one per key.
+pub fn suppress_orphaned_unused_keys(
+    unused: &mut Vec<Diagnostic>,
+    orphaned: &[Diagnostic],
+    dict_set: &DictionarySet,
+) {
+    let orphaned_files: HashSet<(&str, &str)> = orphaned
+        .iter()
+        .filter_map(|d| Some((d.locale.as_deref()?, d.location.as_ref()?.file.as_str())))
+        .collect();
+    if orphaned_files.is_empty() {
+        return;
+    }
+    unused.retain(|d| {
+        let (Some(locale), Some(key)) = (d.locale.as_deref(), d.key.as_deref()) else {
+            return true;
+        };
+        let Some(source) = dict_set.get(locale).and_then(|dict| dict.source(key)) else {
+            return true;
+        };
+        !orphaned_files.contains(&(locale, source.path.to_string_lossy().as_ref()))
+    });
+}
+
 /// Checks that placeholder variables match across all locales for each key.
 #[must_use]
 pub fn check_type_mismatch(dict_set: &DictionarySet) -> Vec<Diagnostic> {
@@ -137,6 +261,7 @@ pub fn check_type_mismatch(dict_set: &DictionarySet) -> Vec<Diagnostic> {
                         ),
                         key: Some(key.clone()),
                         locale: Some(other_locale.clone()),
+                        location: None,
                     });
                 }
                 if !extra.is_empty() {
@@ -148,6 +273,7 @@ pub fn check_type_mismatch(dict_set: &DictionarySet) -> Vec<Diagnostic> {
                         ),
                         key: Some(key.clone()),
                         locale: Some(other_locale.clone()),
+                        location: None,
                     });
                 }
             }
@@ -160,29 +286,42 @@ pub fn check_type_mismatch(dict_set: &DictionarySet) -> Vec<Diagnostic> {
 /// Checks all dictionary values for MF2 syntax errors.
 #[must_use]
 pub fn check_syntax_errors(dict_set: &DictionarySet) -> Vec<Diagnostic> {
+    check_syntax_errors_with_registry(dict_set, &FunctionRegistry::default())
+}
+
+/// Checks all dictionary values for MF2 syntax errors, validating functions
+/// and options against a custom registry.
+#[must_use]
+pub fn check_syntax_errors_with_registry(
+    dict_set: &DictionarySet,
+    registry: &FunctionRegistry,
+) -> Vec<Diagnostic> {
     let mut diagnostics = Vec::new();
 
     for locale in dict_set.locales() {
         if let Some(dict) = dict_set.get(locale) {
             for (key, value) in dict.iter() {
-                if let Err(e) = mf2::parse(value) {
+                let (message, errors) = mf2::parse_all(value);
+                for e in errors {
                     diagnostics.push(Diagnostic {
                         severity: Severity::Error,
                         message: format!("MF2 syntax error: {e}"),
                         key: Some(key.to_string()),
                         locale: Some(locale.to_string()),
+                        location: None,
                     });
                 }
 
                 // Also run semantic validation
-                if let Ok(msg) = mf2::parse(value) {
-                    let errors = mf2::validator::validate(&msg);
+                if let Some(msg) = message {
+                    let errors = mf2::validator::validate_with_registry(&msg, registry);
                     for err in errors {
                         diagnostics.push(Diagnostic {
                             severity: Severity::Warning,
                             message: format!("MF2 validation: {err}"),
                             key: Some(key.to_string()),
                             locale: Some(locale.to_string()),
+                            location: None,
                         });
                     }
                 }
@@ -193,7 +332,461 @@ pub fn check_syntax_errors(dict_set: &DictionarySet) -> Vec<Diagnostic> {
     diagnostics
 }
 
-/// Runs all checks and returns combined diagnostics.
+/// Checks that placeholders use the project's interpolation syntax.
+///
+/// In an MF2 project, i18next `{{name}}` placeholders are flagged; complex
+/// messages (starting with `.`) are skipped since `{{...}}` quotes their
+/// patterns. In an i18next project, MF2 `{$name}` placeholders are flagged.
+#[must_use]
+pub fn check_interpolation_syntax(
+    dict_set: &DictionarySet,
+    interpolation: Interpolation,
+) -> Vec<Diagnostic> {
+    let mut diagnostics = Vec::new();
+
+    for locale in dict_set.locales() {
+        let Some(dict) = dict_set.get(locale) else {
+            continue;
+        };
+        for (key, value) in dict.iter() {
+            let messages: Vec<String> = match interpolation {
+                Interpolation::Mf2 if value.trim_start().starts_with('.') => Vec::new(),
+                Interpolation::Mf2 => i18next_placeholders(value)
+                    .into_iter()
+                    .map(|name| {
+                        format!("i18next placeholder '{{{{{name}}}}}' in an MF2 project; use '{{${name}}}'")
+                    })
+                    .collect(),
+                Interpolation::I18next => mf2_placeholders(value)
+                    .into_iter()
+                    .map(|name| {
+                        format!("MF2 placeholder '{{${name}}}' in an i18next project; use '{{{{{name}}}}}'")
+                    })
+                    .collect(),
+            };
+            for message in messages {
+                diagnostics.push(Diagnostic {
+                    severity: Severity::Error,
+                    message,
+                    key: Some(key.to_string()),
+                    locale: Some(locale.to_string()),
+                    location: None,
+                });
+            }
+        }
+    }
+
+    diagnostics
+}
+
+/// Returns the names of i18next `{{name}}` placeholders (a `, format` suffix is ignored).
+fn i18next_placeholders(value: &str) -> Vec<&str> {
+    let mut names = Vec::new();
+    let mut rest = value;
+    while let Some(start) = rest.find("{{") {
+        rest = &rest[start + 2..];
+        let Some(end) = rest.find("}}") else {
+            break;
+        };
+        let name = rest[..end].split(',').next().unwrap_or_default().trim();
+        if is_placeholder_name(name) {
+            names.push(name);
+            rest = &rest[end + 2..];
+        }
+    }
+    names
+}
+
+/// Returns the names of MF2 `{$name}` placeholders (`{ $name :fn }` included).
+fn mf2_placeholders(value: &str) -> Vec<&str> {
+    let mut names = Vec::new();
+    let mut rest = value;
+    while let Some(start) = rest.find('{') {
+        rest = &rest[start + 1..];
+        let Some(variable) = rest.trim_start().strip_prefix('$') else {
+            continue;
+        };
+        let len = variable
+            .find(|c: char| !(c.is_alphanumeric() || c == '_' || c == '-'))
+            .unwrap_or(variable.len());
+        if len > 0 {
+            names.push(&variable[..len]);
+        }
+    }
+    names
+}
+
+/// Returns true if `name` looks like an interpolation variable name.
+fn is_placeholder_name(name: &str) -> bool {
+    !name.is_empty() && name.chars().all(|c| c.is_alphanumeric() || c == '_' || c == '.')
+}
+
+/// Checks that plural matchers cover the CLDR plural categories each locale
+/// uses for integer counts.
+///
+/// Applies to keys whose default-locale value is a `.match` over a `:number` or
+/// `:integer` selector. A wildcard `*` variant only counts as `other`.
+#[must_use]
+pub fn check_plural_categories(dict_set: &DictionarySet) -> Vec<Diagnostic> {
+    let mut diagnostics = Vec::new();
+
+    let Some(default_locale) = dict_set.default_locale() else {
+        return diagnostics;
+    };
+    let Some(default_dict) = dict_set.get(default_locale.as_str()) else {
+        return diagnostics;
+    };
+
+    for (key, value) in default_dict.iter() {
+        let Ok(default_msg) = mf2::parse(value) else {
+            continue;
+        };
+        let selectors = plural_selectors(&default_msg);
+        if selectors.is_empty() {
+            continue;
+        }
+
+        for locale in dict_set.locales() {
+            if locale == default_locale.as_str() {
+                continue;
+            }
+            let Some(required) = Locale::new(locale).ok().and_then(|l| l.plural_categories())
+            else {
+                continue;
+            };
+            let Some(Ok(msg)) = dict_set.get(locale).and_then(|d| d.get(key)).map(mf2::parse)
+            else {
+                continue;
+            };
+
+            for selector in &selectors {
+                let covered = covered_categories(&msg, selector);
+                let missing: Vec<_> =
+                    required.iter().filter(|category| !covered.contains(**category)).collect();
+                if !missing.is_empty() {
+                    diagnostics.push(Diagnostic {
+                        severity: Severity::Warning,
+                        message: format!(
+                            "locale '{locale}' is missing plural categories {missing:?} \
+                             for selector '${selector}'"
+                        ),
+                        key: Some(key.to_string()),
+                        locale: Some(locale.to_string()),
+                        location: None,
+                    });
+                }
+            }
+        }
+    }
+
+    diagnostics
+}
+
+/// Checks for translations that are identical to the default locale.
+///
+/// Messages are compared with [`mf2::semantically_equal`], so whitespace-only
+/// differences still count as identical. Such values are usually untranslated.
+/// Locales sharing the default locale's language (`en-GB` for `en`) are
+/// skipped, since most of their values are expected to match. Short words
+/// such as "OK" are often legitimately shared too, so this rule is not part
+/// of [`check_all`].
+#[must_use]
+pub fn check_identical_translations(dict_set: &DictionarySet) -> Vec<Diagnostic> {
+    let mut diagnostics = Vec::new();
+
+    let Some(default_locale) = dict_set.default_locale() else {
+        return diagnostics;
+    };
+    let Some(default_dict) = dict_set.get(default_locale.as_str()) else {
+        return diagnostics;
+    };
+
+    for (key, value) in default_dict.iter() {
+        let Ok(default_msg) = mf2::parse(value) else {
+            continue;
+        };
+
+        for locale in dict_set.locales() {
+            if Locale::new(locale)
+                .is_ok_and(|l| l.language().eq_ignore_ascii_case(default_locale.language()))
+            {
+                continue;
+            }
+            let Some(Ok(msg)) = dict_set.get(locale).and_then(|d| d.get(key)).map(mf2::parse)
+            else {
+                continue;
+            };
+
+            if mf2::semantically_equal(&default_msg, &msg) {
+                diagnostics.push(Diagnostic {
+                    severity: Severity::Warning,
+                    message: format!("translation identical to default locale '{default_locale}'"),
+                    key: Some(key.to_string()),
+                    locale: Some(locale.to_string()),
+                    location: None,
+                });
+            }
+        }
+    }
+
+    diagnostics
+}
+
+/// Checks for values shared by `threshold` or more distinct keys within a locale.
+///
+/// Values are compared after trimming whitespace. Repeated text is often a
+/// candidate for a shared key, and sometimes a copy-paste mistake. Each
+/// diagnostic is attached to the first of the keys, which are all listed.
+#[must_use]
+pub fn check_duplicate_values(dict_set: &DictionarySet, threshold: usize) -> Vec<Diagnostic> {
+    let mut diagnostics = Vec::new();
+
+    for locale in dict_set.locales() {
+        let Some(dict) = dict_set.get(locale) else {
+            continue;
+        };
+        let mut keys_by_value: BTreeMap<&str, Vec<String>> = BTreeMap::new();
+        for (key, value) in dict.iter() {
+            let value = value.trim();
+            if !value.is_empty() {
+                keys_by_value.entry(value).or_default().push(key.to_string());
+            }
+        }
+
+        for (value, mut keys) in keys_by_value {
+            if keys.len() < threshold.max(2) {
+                continue;
+            }
+            keys.sort_unstable();
+            diagnostics.push(Diagnostic {
+                severity: Severity::Warning,
+                message: format!(
+                    "value \"{value}\" is shared by {} keys: {}",
+                    keys.len(),
+                    keys.join(", ")
+                ),
+                key: Some(keys[0].clone()),
+                locale: Some(locale.to_string()),
+                location: None,
+            });
+        }
+    }
+
+    diagnostics
+}
+
+/// Checks that each locale's dictionary files mirror the default locale's layout.
+///
+/// A key stored in a different namespace file than in the default locale is
+/// reported on its own. Keys that share a file but are listed in a different
+/// order are reported once per file, at the first key out of place. Files are
+/// compared relative to each locale's directory and without their extension,
+/// so `en/common.json` matches `ja/common.yaml`. Entries without a recorded
+/// source file are ignored. Reordering is often harmless, so callers opt in.
+#[must_use]
+pub fn check_structure_drift(dict_set: &DictionarySet) -> Vec<Diagnostic> {
+    let mut diagnostics = Vec::new();
+
+    let Some(default_locale) = dict_set.default_locale() else {
+        return diagnostics;
+    };
+    let Some(default_dict) = dict_set.get(default_locale.as_str()) else {
+        return diagnostics;
+    };
+
+    for locale in dict_set.locales() {
+        if locale == default_locale.as_str() {
+            continue;
+        }
+        let Some(dict) = dict_set.get(locale) else {
+            continue;
+        };
+        // Namespace file -> (line, default locale line, key) for each shared key
+        let mut files: BTreeMap<String, Vec<(usize, usize, &str)>> = BTreeMap::new();
+        for key in dict.keys() {
+            let (Some(source), Some(default_source)) = (dict.source(key), default_dict.source(key))
+            else {
+                continue;
+            };
+            let file = namespace_file(&source.path, locale);
+            let default_file = namespace_file(&default_source.path, default_locale.as_str());
+            if file == default_file {
+                files.entry(file).or_default().push((source.line, default_source.line, key));
+                continue;
+            }
+            diagnostics.push(Diagnostic {
+                severity: Severity::Info,
+                message: format!(
+                    "key is in namespace file '{file}' but in '{default_file}' for default locale '{default_locale}'"
+                ),
+                key: Some(key.to_string()),
+                locale: Some(locale.to_string()),
+                location: Some(Location {
+                    file: source.path.to_string_lossy().to_string(),
+                    line: u32::try_from(source.line).unwrap_or(u32::MAX),
+                    column: 1,
+                }),
+            });
+        }
+
+        for (file, mut keys) in files {
+            keys.sort_unstable();
+            let Some(pair) = keys.windows(2).find(|pair| pair[1].1 < pair[0].1) else {
+                continue;
+            };
+            let (line, _, key) = pair[1];
+            diagnostics.push(Diagnostic {
+                severity: Severity::Info,
+                message: format!(
+                    "keys in namespace file '{file}' are ordered differently than in default locale '{default_locale}' ('{key}' follows '{}')",
+                    pair[0].2
+                ),
+                key: Some(key.to_string()),
+                locale: Some(locale.to_string()),
+                location: dict.source(key).map(|source| Location {
+                    file: source.path.to_string_lossy().to_string(),
+                    line: u32::try_from(line).unwrap_or(u32::MAX),
+                    column: 1,
+                }),
+            });
+        }
+    }
+
+    diagnostics
+}
+
+/// Returns a dictionary file's path below its locale directory, without extension.
+///
+/// `i18n/ja/admin/users.json` becomes `admin/users` for locale `ja`.
+fn namespace_file(path: &Path, locale: &str) -> String {
+    let path = path.with_extension("");
+    let components: Vec<_> =
+        path.components().map(|component| component.as_os_str().to_string_lossy()).collect();
+    let dirs = &components[..components.len().saturating_sub(1)];
+    let start = dirs.iter().rposition(|dir| dir == locale).map_or(0, |index| index + 1);
+    components[start..].join("/")
+}
+
+/// Checks that translation calls pass the variables their messages need.
+///
+/// Each call pairs a key with the argument names passed at the call site
+/// (`t('key', { name, count })`). A variable the message references but the
+/// call omits is an error; an argument the message never uses is a warning.
+/// Messages are read from the default locale, or from every locale if none is set.
+#[must_use]
+pub fn check_call_arguments(
+    calls: &[(String, Vec<String>)],
+    dict_set: &DictionarySet,
+) -> Vec<Diagnostic> {
+    let mut diagnostics = Vec::new();
+
+    let locales: Vec<&str> = match dict_set.default_locale() {
+        Some(default) => vec![default.as_str()],
+        None => dict_set.locales().collect(),
+    };
+
+    for (key, args) in calls {
+        for &locale in &locales {
+            let Some(Ok(msg)) = dict_set
+                .get(locale)
+                .and_then(|d| d.get(&dict_set.normalize_key(key)))
+                .map(mf2::parse)
+            else {
+                continue;
+            };
+            let required = mf2::required_variables(&msg);
+
+            for var in required.iter().filter(|var| !args.contains(var)) {
+                diagnostics.push(Diagnostic {
+                    severity: Severity::Error,
+                    message: format!("call to '{key}' is missing argument '${var}'"),
+                    key: Some(key.clone()),
+                    locale: Some(locale.to_string()),
+                    location: None,
+                });
+            }
+
+            for arg in args.iter().filter(|arg| !required.contains(*arg)) {
+                diagnostics.push(Diagnostic {
+                    severity: Severity::Warning,
+                    message: format!("call to '{key}' passes unused argument '{arg}'"),
+                    key: Some(key.clone()),
+                    locale: Some(locale.to_string()),
+                    location: None,
+                });
+            }
+        }
+    }
+
+    diagnostics
+}
+
+/// Returns the `.match` selectors declared with a `:number` or `:integer` annotation.
+fn plural_selectors(msg: &Message) -> Vec<&str> {
+    let Message::Complex(complex) = msg else {
+        return Vec::new();
+    };
+    let ComplexBody::Matcher(matcher) = &complex.body else {
+        return Vec::new();
+    };
+
+    matcher
+        .selectors
+        .iter()
+        .filter(|selector| {
+            complex.declarations.iter().any(|declaration| {
+                let (variable, annotation) = match declaration {
+                    Declaration::Input(input) => (&input.variable, input.annotation.as_ref()),
+                    Declaration::Local(local) => {
+                        (&local.variable, local.expression.annotation.as_ref())
+                    }
+                };
+                variable == *selector
+                    && annotation
+                        .is_some_and(|a| matches!(a.function.as_str(), "number" | "integer"))
+            })
+        })
+        .map(String::as_str)
+        .collect()
+}
+
+/// Returns the plural categories a message supplies for `selector`.
+///
+/// A message without a matcher on `selector` only covers `other`.
+fn covered_categories<'a>(msg: &'a Message, selector: &str) -> HashSet<&'a str> {
+    let mut covered = HashSet::from(["other"]);
+
+    if let Message::Complex(complex) = msg {
+        if let ComplexBody::Matcher(matcher) = &complex.body {
+            if let Some(index) = matcher.selectors.iter().position(|s| s == selector) {
+                for variant in &matcher.variants {
+                    if let Some(VariantKey::Literal(key)) = variant.keys.get(index) {
+                        covered.insert(key.as_str());
+                    }
+                }
+            }
+        }
+    }
+
+    covered
+}
+
+/// Sorts diagnostics by severity, then key, then locale, and removes exact duplicates.
+///
+/// Keeps output stable regardless of `HashMap` iteration order.
+pub fn sort_and_dedup(diagnostics: &mut Vec<Diagnostic>) {
+    diagnostics.sort_by(|a, b| {
+        a.severity
+            .cmp(&b.severity)
+            .then_with(|| a.key.cmp(&b.key))
+            .then_with(|| a.locale.cmp(&b.locale))
+            .then_with(|| a.message.cmp(&b.message))
+            .then_with(|| a.location.cmp(&b.location))
+    });
+    diagnostics.dedup();
+}
+
+/// Runs all checks and returns combined diagnostics, sorted and deduplicated.
 #[must_use]
 pub fn check_all<S: BuildHasher>(
     used_keys: &HashSet<String, S>,
@@ -204,15 +797,18 @@ pub fn check_all<S: BuildHasher>(
     all.extend(check_unused_keys(used_keys, dict_set));
     all.extend(check_type_mismatch(dict_set));
     all.extend(check_syntax_errors(dict_set));
+    all.extend(check_plural_categories(dict_set));
+    sort_and_dedup(&mut all);
     all
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::dictionary::Dictionary;
+    use crate::dictionary::{Dictionary, KeySource};
     use crate::key::KeyPath;
     use crate::locale::Locale;
+    use std::path::PathBuf;
 
     fn make_dict_set() -> DictionarySet {
         let mut set = DictionarySet::new();
@@ -243,6 +839,22 @@ mod tests {
         assert!(diags.iter().any(|d| d.message.contains("common.unknown")));
     }
 
+    #[test]
+    fn key_style_matches_used_keys_across_styles() {
+        let mut dict_set = DictionarySet::new();
+        let mut en = Dictionary::new();
+        en.insert(KeyPath::new("user_menu.sign_out"), "Sign out".to_string());
+        dict_set.insert(Locale::new("en").unwrap(), en);
+        let used: HashSet<String> = HashSet::from(["userMenu.signOut".to_string()]);
+
+        assert_eq!(check_missing_keys(&used, &dict_set).len(), 1);
+        assert_eq!(check_unused_keys(&used, &dict_set).len(), 1);
+
+        dict_set.set_key_style(crate::key::KeyStyle::SnakeCase);
+        assert!(check_missing_keys(&used, &dict_set).is_empty());
+        assert!(check_unused_keys(&used, &dict_set).is_empty());
+    }
+
     #[test]
     fn unused_keys() {
         let dict_set = make_dict_set();
@@ -273,4 +885,354 @@ mod tests {
             .any(|d| d.message.contains("missing variables")
                 || d.message.contains("extra variables")));
     }
+
+    #[test]
+    fn orphaned_namespace_file_reported() {
+        let mut en = Dictionary::new();
+        for (key, file) in [
+            ("common.ok", "en/common.json"),
+            ("legacy.a", "en/legacy.json"),
+            ("legacy.b", "en/legacy.json"),
+        ] {
+            en.insert(KeyPath::new(key), "text".to_string());
+            en.set_source(key, KeySource { path: PathBuf::from(file), line: 2 });
+        }
+        let mut set = DictionarySet::new();
+        set.insert(Locale::new("en").unwrap(), en);
+        let used = HashSet::from(["common.ok".to_string()]);
+
+        let diags = check_orphaned_namespaces(&used, &set);
+        assert_eq!(diags.len(), 1, "{diags:?}");
+        assert_eq!(
+            diags[0].message,
+            "none of the 2 key(s) in namespace file 'en/legacy.json' are used"
+        );
+        assert_eq!(diags[0].locale.as_deref(), Some("en"));
+        assert_eq!(diags[0].location.as_ref().map(|l| l.file.as_str()), Some("en/legacy.json"));
+
+        let used = HashSet::from(["common.ok".to_string(), "legacy.b".to_string()]);
+        assert!(check_orphaned_namespaces(&used, &set).is_empty());
+    }
+
+    #[test]
+    fn unused_keys_in_orphaned_files_suppressed() {
+        let mut en = Dictionary::new();
+        for (key, file) in [
+            ("common.ok", "en/common.json"),
+            ("common.unused", "en/common.json"),
+            ("legacy.a", "en/legacy.json"),
+            ("legacy.b", "en/legacy.json"),
+        ] {
+            en.insert(KeyPath::new(key), "text".to_string());
+            en.set_source(key, KeySource { path: PathBuf::from(file), line: 2 });
+        }
+        let mut set = DictionarySet::new();
+        set.insert(Locale::new("en").unwrap(), en);
+        let used = HashSet::from(["common.ok".to_string()]);
+
+        let mut unused = check_unused_keys(&used, &set);
+        assert_eq!(unused.len(), 3);
+        let orphaned = check_orphaned_namespaces(&used, &set);
+        suppress_orphaned_unused_keys(&mut unused, &orphaned, &set);
+        let keys: Vec<_> = unused.iter().filter_map(|d| d.key.as_deref()).collect();
+        assert_eq!(keys, ["common.unused"]);
+    }
+
+    #[test]
+    fn structure_drift_reported() {
+        let mut set = DictionarySet::new();
+        set.set_default_locale(Locale::new("en").unwrap());
+        for (locale, entries) in [
+            (
+                "en",
+                [
+                    ("common.nav.home", "i18n/en/common.json", 3),
+                    ("common.nav.about", "i18n/en/common.json", 4),
+                    ("common.title", "i18n/en/common.json", 6),
+                    ("common.footer", "i18n/en/common.json", 7),
+                ],
+            ),
+            (
+                "ja",
+                [
+                    ("common.nav.home", "i18n/ja/common/nav.yaml", 1),
+                    ("common.nav.about", "i18n/ja/common/nav.yaml", 2),
+                    ("common.title", "i18n/ja/common.yaml", 2),
+                    ("common.footer", "i18n/ja/common.yaml", 1),
+                ],
+            ),
+        ] {
+            let mut dict = Dictionary::new();
+            for (key, file, line) in entries {
+                dict.insert(KeyPath::new(key), "text".to_string());
+                dict.set_source(key, KeySource { path: PathBuf::from(file), line });
+            }
+            set.insert(Locale::new(locale).unwrap(), dict);
+        }
+
+        let mut diags = check_structure_drift(&set);
+        sort_and_dedup(&mut diags);
+        let summary: Vec<_> = diags
+            .iter()
+            .map(|d| (d.key.as_deref().unwrap(), d.locale.as_deref().unwrap(), d.message.as_str()))
+            .collect();
+        assert_eq!(
+            summary,
+            [
+                (
+                    "common.nav.about",
+                    "ja",
+                    "key is in namespace file 'common/nav' but in 'common' for default locale 'en'"
+                ),
+                (
+                    "common.nav.home",
+                    "ja",
+                    "key is in namespace file 'common/nav' but in 'common' for default locale 'en'"
+                ),
+                (
+                    "common.title",
+                    "ja",
+                    "keys in namespace file 'common' are ordered differently than in default \
+                     locale 'en' ('common.title' follows 'common.footer')"
+                ),
+            ]
+        );
+        assert_eq!(
+            diags[1].location.as_ref().map(|l| l.file.as_str()),
+            Some("i18n/ja/common/nav.yaml")
+        );
+        assert!(diags.iter().all(|d| d.severity == Severity::Info));
+    }
+
+    #[test]
+    fn sort_and_dedup_diagnostics() {
+        let diag = |severity, key: &str, locale: &str| Diagnostic {
+            severity,
+            message: format!("problem with '{key}'"),
+            key: Some(key.to_string()),
+            locale: Some(locale.to_string()),
+            location: None,
+        };
+        let mut diags = vec![
+            diag(Severity::Warning, "b", "en"),
+            diag(Severity::Error, "b", "ja"),
+            diag(Severity::Error, "a", "ja"),
+            diag(Severity::Error, "b", "ja"),
+            diag(Severity::Error, "a", "en"),
+            diag(Severity::Warning, "b", "en"),
+        ];
+
+        sort_and_dedup(&mut diags);
+
+        assert_eq!(
+            diags,
+            vec![
+                diag(Severity::Error, "a", "en"),
+                diag(Severity::Error, "a", "ja"),
+                diag(Severity::Error, "b", "ja"),
+                diag(Severity::Warning, "b", "en"),
+            ]
+        );
+    }
+
+    #[test]
+    fn check_all_is_sorted() {
+        let dict_set = make_dict_set();
+        let mut used = HashSet::new();
+        used.insert("common.zeta".to_string());
+        used.insert("common.alpha".to_string());
+
+        let diags = check_all(&used, &dict_set);
+        let mut sorted = diags.clone();
+        sort_and_dedup(&mut sorted);
+        assert_eq!(diags, sorted);
+        assert_eq!(diags[0].key.as_deref(), Some("common.alpha"));
+        assert_eq!(diags[0].locale.as_deref(), Some("en"));
+    }
+
+    #[test]
+    fn syntax_errors_reports_each_error() {
+        let mut set = DictionarySet::new();
+        let mut en = Dictionary::new();
+        en.insert(
+            KeyPath::new("msg"),
+            ".input {|x| :number}\n.match $a $b\none {{x}}\n* * {{y}}".to_string(),
+        );
+        set.insert(Locale::new("en").unwrap(), en);
+
+        let diags = check_syntax_errors(&set);
+        assert_eq!(diags.len(), 2, "{diags:?}");
+        assert!(diags.iter().all(|d| d.message.starts_with("MF2 syntax error")));
+    }
+
+    fn interpolation_dict_set(value: &str) -> DictionarySet {
+        let mut set = DictionarySet::new();
+        let mut en = Dictionary::new();
+        en.insert(KeyPath::new("msg"), value.to_string());
+        set.insert(Locale::new("en").unwrap(), en);
+        set
+    }
+
+    #[test]
+    fn interpolation_i18next_in_mf2_project() {
+        let set = interpolation_dict_set("Hello {{name}}, you have {{count, number}} items");
+        let diags = check_interpolation_syntax(&set, Interpolation::Mf2);
+        assert_eq!(diags.len(), 2, "{diags:?}");
+        assert!(diags[0].message.contains("'{{name}}'"));
+        assert!(diags[0].message.contains("'{$name}'"));
+        assert!(check_interpolation_syntax(&set, Interpolation::I18next).is_empty());
+
+        // Quoted patterns of complex messages are not placeholders.
+        let set = interpolation_dict_set(".input {$n :number}\n{{You have {$n} items}}");
+        assert!(check_interpolation_syntax(&set, Interpolation::Mf2).is_empty());
+    }
+
+    #[test]
+    fn interpolation_mf2_in_i18next_project() {
+        let set = interpolation_dict_set("Hello {$name}, { $count :number } items");
+        let diags = check_interpolation_syntax(&set, Interpolation::I18next);
+        assert_eq!(diags.len(), 2, "{diags:?}");
+        assert!(diags[0].message.contains("'{$name}'"));
+        assert!(diags[1].message.contains("'{{count}}'"));
+        assert!(check_interpolation_syntax(&set, Interpolation::Mf2).is_empty());
+        assert_eq!(Interpolation::parse("i18next"), Some(Interpolation::I18next));
+        assert_eq!(Interpolation::parse("icu"), None);
+    }
+
+    fn plural_dict_set(ru: &str) -> DictionarySet {
+        plural_dict_set_for("ru", ru)
+    }
+
+    fn plural_dict_set_for(locale: &str, value: &str) -> DictionarySet {
+        let mut set = DictionarySet::new();
+        set.set_default_locale(Locale::new("en").unwrap());
+
+        let mut en = Dictionary::new();
+        en.insert(
+            KeyPath::new("files"),
+            ".input {$count :number}\n.match $count\none {{{$count} file}}\n* {{{$count} files}}"
+                .to_string(),
+        );
+        set.insert(Locale::new("en").unwrap(), en);
+
+        let mut dict = Dictionary::new();
+        dict.insert(KeyPath::new("files"), value.to_string());
+        set.insert(Locale::new(locale).unwrap(), dict);
+
+        let mut ja = Dictionary::new();
+        ja.insert(KeyPath::new("files"), "{$count} 個のファイル".to_string());
+        set.insert(Locale::new("ja").unwrap(), ja);
+
+        set
+    }
+
+    #[test]
+    fn plural_categories_missing_few() {
+        let set = plural_dict_set(
+            ".input {$count :number}\n.match $count\none {{{$count} файл}}\nmany {{{$count} файлов}}\n* {{{$count} файла}}",
+        );
+
+        let diags = check_plural_categories(&set);
+        assert_eq!(diags.len(), 1, "{diags:?}");
+        assert_eq!(diags[0].locale.as_deref(), Some("ru"));
+        assert_eq!(diags[0].key.as_deref(), Some("files"));
+        assert!(diags[0].message.contains("[\"few\"]"), "{}", diags[0].message);
+    }
+
+    #[test]
+    fn plural_categories_complete() {
+        let set = plural_dict_set(
+            ".input {$count :number}\n.match $count\none {{{$count} файл}}\nfew {{{$count} файла}}\nmany {{{$count} файлов}}\n* {{{$count} файла}}",
+        );
+
+        assert!(check_plural_categories(&set).is_empty());
+    }
+
+    #[test]
+    fn plural_categories_skip_non_integer_categories() {
+        let set = plural_dict_set_for(
+            "fr",
+            ".input {$count :number}\n.match $count\none {{{$count} fichier}}\n* {{{$count} fichiers}}",
+        );
+        assert!(check_plural_categories(&set).is_empty());
+
+        let set = plural_dict_set_for(
+            "cs",
+            ".input {$count :number}\n.match $count\none {{{$count} soubor}}\nfew {{{$count} soubory}}\n* {{{$count} souborů}}",
+        );
+        assert!(check_plural_categories(&set).is_empty());
+    }
+
+    #[test]
+    fn identical_translations() {
+        let mut set = make_dict_set();
+        let mut fr = Dictionary::new();
+        fr.insert(KeyPath::new("common.greeting"), "Bonjour {$name}".to_string());
+        fr.insert(KeyPath::new("common.farewell"), "  Goodbye ".to_string());
+        set.insert(Locale::new("fr").unwrap(), fr);
+
+        let diags = check_identical_translations(&set);
+        assert_eq!(diags.len(), 1, "{diags:?}");
+        assert_eq!(diags[0].key.as_deref(), Some("common.farewell"));
+        assert_eq!(diags[0].locale.as_deref(), Some("fr"));
+    }
+
+    #[test]
+    fn identical_translations_skip_regional_variants() {
+        let mut set = make_dict_set();
+        let mut en_gb = Dictionary::new();
+        en_gb.insert(KeyPath::new("common.greeting"), "Hello {$name}".to_string());
+        en_gb.insert(KeyPath::new("common.farewell"), "Goodbye".to_string());
+        set.insert(Locale::new("en-GB").unwrap(), en_gb);
+
+        assert!(check_identical_translations(&set).is_empty());
+    }
+
+    #[test]
+    fn duplicate_values_reported_at_threshold() {
+        let mut set = DictionarySet::new();
+        let mut en = Dictionary::new();
+        en.insert(KeyPath::new("form.submit"), "Submit".to_string());
+        en.insert(KeyPath::new("dialog.ok"), "Submit".to_string());
+        en.insert(KeyPath::new("wizard.finish"), " Submit ".to_string());
+        en.insert(KeyPath::new("form.cancel"), "Cancel".to_string());
+        set.insert(Locale::new("en").unwrap(), en);
+
+        let diags = check_duplicate_values(&set, 3);
+        assert_eq!(diags.len(), 1, "{diags:?}");
+        assert_eq!(
+            diags[0].message,
+            "value \"Submit\" is shared by 3 keys: dialog.ok, form.submit, wizard.finish"
+        );
+        assert_eq!(diags[0].key.as_deref(), Some("dialog.ok"));
+        assert_eq!(diags[0].locale.as_deref(), Some("en"));
+        assert!(check_duplicate_values(&set, 4).is_empty());
+    }
+
+    #[test]
+    fn call_arguments_missing_and_extra() {
+        let mut set = make_dict_set();
+        let mut en = Dictionary::new();
+        en.insert(KeyPath::new("common.greeting"), "Hello {$name}".to_string());
+        en.insert(
+            KeyPath::new("cart.items"),
+            ".input {$count :number}\n.local $label = {|items|}\n.match $count\n\
+             one {{{$count} item}}\n* {{{$count} {$label}}}"
+                .to_string(),
+        );
+        set.insert(Locale::new("en").unwrap(), en);
+
+        let calls = vec![
+            ("common.greeting".to_string(), vec!["name".to_string()]),
+            ("cart.items".to_string(), vec![]),
+            ("common.greeting".to_string(), vec!["name".to_string(), "extra".to_string()]),
+        ];
+        let diags = check_call_arguments(&calls, &set);
+
+        assert_eq!(diags.len(), 2, "{diags:?}");
+        assert_eq!(diags[0].severity, Severity::Error);
+        assert_eq!(diags[0].message, "call to 'cart.items' is missing argument '$count'");
+        assert_eq!(diags[1].severity, Severity::Warning);
+        assert_eq!(diags[1].message, "call to 'common.greeting' passes unused argument 'extra'");
+    }
 }
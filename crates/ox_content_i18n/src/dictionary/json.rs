@@ -1,7 +1,10 @@
+use std::collections::HashMap;
+use std::path::Path;
+
 use crate::error::I18nResult;
 use crate::key::KeyPath;
 
-use super::Dictionary;
+use super::{Dictionary, KeySource};
 
 /// Loads a JSON string into a `Dictionary`, prefixing each key with `namespace.`.
 ///
@@ -24,22 +27,125 @@ use super::Dictionary;
 /// - `common.greeting`
 /// - `common.nav.home`
 /// - `common.nav.about`
-pub fn load_into(json_str: &str, namespace: &str, dict: &mut Dictionary) -> I18nResult<()> {
+///
+/// When `source` is given, the file path and line of each key are recorded
+/// on the dictionary (see [`Dictionary::source`]).
+pub fn load_into(
+    json_str: &str,
+    namespace: &str,
+    source: Option<&Path>,
+    dict: &mut Dictionary,
+) -> I18nResult<()> {
     let value: serde_json::Value = serde_json::from_str(json_str)?;
     if let serde_json::Value::Object(map) = value {
         flatten_object(&map, namespace, dict);
     }
+    if let Some(path) = source {
+        for (key, line) in key_lines(json_str) {
+            let full_key = format!("{namespace}.{key}");
+            if dict.get(&full_key).is_some() {
+                dict.set_source(&full_key, KeySource { path: path.to_path_buf(), line });
+            }
+        }
+    }
     Ok(())
 }
 
 /// Parses a JSON string into a standalone `Dictionary` with the given namespace.
 pub fn load(json_str: &str, namespace: &str) -> I18nResult<Dictionary> {
     let mut dict = Dictionary::new();
-    load_into(json_str, namespace, &mut dict)?;
+    load_into(json_str, namespace, None, &mut dict)?;
+    Ok(dict)
+}
+
+/// Loads a JSONC/JSON5-style string into a `Dictionary`, like [`load_into`].
+///
+/// Line (`//`) and block (`/* */`) comments and trailing commas are accepted;
+/// the rest must be valid JSON. Keys flatten exactly as with strict JSON, and
+/// recorded lines refer to the original source.
+pub fn load_jsonc_into(
+    jsonc_str: &str,
+    namespace: &str,
+    source: Option<&Path>,
+    dict: &mut Dictionary,
+) -> I18nResult<()> {
+    load_into(&strip_jsonc(jsonc_str), namespace, source, dict)
+}
+
+/// Parses a JSONC/JSON5-style string into a standalone `Dictionary` with the given namespace.
+pub fn load_jsonc(jsonc_str: &str, namespace: &str) -> I18nResult<Dictionary> {
+    let mut dict = Dictionary::new();
+    load_jsonc_into(jsonc_str, namespace, None, &mut dict)?;
     Ok(dict)
 }
 
-fn flatten_object(
+/// Turns JSONC into strict JSON by blanking out comments and trailing commas.
+///
+/// Removed bytes are replaced with spaces (newlines are kept), so line numbers
+/// and offsets still match the original source.
+fn strip_jsonc(jsonc_str: &str) -> String {
+    let mut bytes = jsonc_str.as_bytes().to_vec();
+    let mut index = 0;
+
+    while index < bytes.len() {
+        match bytes[index] {
+            b'"' => {
+                index += 1;
+                while index < bytes.len() && bytes[index] != b'"' {
+                    if bytes[index] == b'\\' {
+                        index += 1;
+                    }
+                    index += 1;
+                }
+            }
+            b'/' if bytes.get(index + 1) == Some(&b'/') => {
+                while index < bytes.len() && bytes[index] != b'\n' {
+                    bytes[index] = b' ';
+                    index += 1;
+                }
+                continue;
+            }
+            b'/' if bytes.get(index + 1) == Some(&b'*') => {
+                let end = bytes[index + 2..]
+                    .windows(2)
+                    .position(|pair| pair == b"*/")
+                    .map_or(bytes.len(), |pos| index + 2 + pos + 2);
+                for byte in &mut bytes[index..end] {
+                    if *byte != b'\n' {
+                        *byte = b' ';
+                    }
+                }
+                index = end;
+                continue;
+            }
+            _ => {}
+        }
+        index += 1;
+    }
+
+    // Comments are gone, so a trailing comma is one followed only by whitespace.
+    let mut in_string = false;
+    let mut index = 0;
+    while index < bytes.len() {
+        match bytes[index] {
+            b'\\' if in_string => index += 1,
+            b'"' => in_string = !in_string,
+            b',' if !in_string => {
+                let next = bytes[index + 1..].iter().find(|byte| !byte.is_ascii_whitespace());
+                if matches!(next, Some(b'}' | b']')) {
+                    bytes[index] = b' ';
+                }
+            }
+            _ => {}
+        }
+        index += 1;
+    }
+
+    // Only ASCII bytes outside strings were replaced, so the text is still UTF-8.
+    String::from_utf8(bytes).unwrap_or_else(|_| jsonc_str.to_string())
+}
+
+pub(super) fn flatten_object(
     map: &serde_json::Map<String, serde_json::Value>,
     prefix: &str,
     dict: &mut Dictionary,
@@ -61,6 +167,63 @@ fn flatten_object(
     }
 }
 
+/// Scans JSON source for object keys, returning each flattened key with its 1-based line.
+///
+/// Keys inside arrays are skipped since arrays are stored as single values.
+fn key_lines(json_str: &str) -> HashMap<String, usize> {
+    let bytes = json_str.as_bytes();
+    let mut lines = HashMap::new();
+    // (is_object, key that opened this container)
+    let mut stack: Vec<(bool, Option<String>)> = Vec::new();
+    let mut pending_key: Option<String> = None;
+    let mut line = 1;
+    let mut index = 0;
+
+    while index < bytes.len() {
+        match bytes[index] {
+            b'\n' => line += 1,
+            b'{' | b'[' => stack.push((bytes[index] == b'{', pending_key.take())),
+            b'}' | b']' => {
+                stack.pop();
+                pending_key = None;
+            }
+            b',' => pending_key = None,
+            b'"' => {
+                let start = index;
+                index += 1;
+                while index < bytes.len() && bytes[index] != b'"' {
+                    if bytes[index] == b'\\' {
+                        index += 1;
+                    }
+                    index += 1;
+                }
+                let end = index.min(bytes.len() - 1);
+
+                let mut next = end + 1;
+                while next < bytes.len() && bytes[next].is_ascii_whitespace() {
+                    next += 1;
+                }
+                let in_object = stack.last().is_some_and(|(is_object, _)| *is_object);
+                if in_object && bytes.get(next) == Some(&b':') {
+                    if let Ok(key) = serde_json::from_str::<String>(&json_str[start..=end]) {
+                        if stack.iter().all(|(is_object, _)| *is_object) {
+                            let mut path: Vec<&str> =
+                                stack.iter().filter_map(|(_, key)| key.as_deref()).collect();
+                            path.push(&key);
+                            lines.insert(path.join("."), line);
+                        }
+                        pending_key = Some(key);
+                    }
+                }
+            }
+            _ => {}
+        }
+        index += 1;
+    }
+
+    lines
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -95,6 +258,51 @@ mod tests {
         assert!(dict.is_empty());
     }
 
+    #[test]
+    fn records_key_lines() {
+        let json = "{\n  \"greeting\": \"Hi\",\n  \"nav\": {\n    \"home\": \"Home\"\n  }\n}";
+        let mut dict = Dictionary::new();
+        load_into(json, "common", Some(Path::new("en/common.json")), &mut dict).unwrap();
+
+        let home = dict.source("common.nav.home").unwrap();
+        assert_eq!(home.path, Path::new("en/common.json"));
+        assert_eq!(home.line, 4);
+        assert_eq!(dict.source("common.greeting").unwrap().line, 2);
+        assert!(dict.source("common.nav").is_none());
+    }
+
+    #[test]
+    fn jsonc_comments_and_trailing_commas() {
+        let jsonc = r#"{
+  // Shown on the landing page
+  "greeting": "Hello // not a comment",
+  /* Navigation
+     labels */
+  "nav": {
+    "home": "Home", // trailing comment
+    "about": "About /* kept */",
+  },
+  "tags": ["a", "b",],
+}
+"#;
+        let mut dict = Dictionary::new();
+        load_jsonc_into(jsonc, "common", Some(Path::new("en/common.jsonc")), &mut dict).unwrap();
+
+        assert_eq!(dict.len(), 4);
+        assert_eq!(dict.get("common.greeting"), Some("Hello // not a comment"));
+        assert_eq!(dict.get("common.nav.home"), Some("Home"));
+        assert_eq!(dict.get("common.nav.about"), Some("About /* kept */"));
+        assert_eq!(dict.get("common.tags"), Some(r#"["a","b"]"#));
+        assert_eq!(dict.source("common.nav.home").unwrap().line, 7);
+    }
+
+    #[test]
+    fn strict_json_rejects_comments() {
+        let source = "{ // comment\n  \"a\": \"b\" }";
+        assert!(load(source, "ns").is_err());
+        assert_eq!(load_jsonc(source, "ns").unwrap().get("ns.a"), Some("b"));
+    }
+
     #[test]
     fn invalid_json() {
         let result = load("not json", "ns");
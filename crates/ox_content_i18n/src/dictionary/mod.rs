@@ -1,17 +1,39 @@
 pub mod json;
 pub mod yaml;
 
+use std::borrow::Cow;
 use std::collections::HashMap;
-use std::path::Path;
+use std::hash::BuildHasher;
+use std::path::{Path, PathBuf};
 
 use crate::error::{I18nError, I18nResult};
-use crate::key::KeyPath;
+use crate::key::{KeyPath, KeyStyle};
 use crate::locale::Locale;
 
+/// The file and line a dictionary entry was loaded from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KeySource {
+    /// Path of the dictionary file.
+    pub path: PathBuf,
+    /// 1-based line of the key in the file.
+    pub line: usize,
+}
+
+/// A `{{name}}` placeholder left in a value because no variable matched it.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct UnresolvedPlaceholder {
+    pub locale: String,
+    pub key: String,
+    /// Placeholder name, without braces.
+    pub name: String,
+}
+
 /// A flat map of translation keys to their MF2 message strings for one locale.
 #[derive(Debug, Clone, Default)]
 pub struct Dictionary {
     entries: HashMap<String, String>,
+    /// Source locations for entries loaded from files.
+    sources: HashMap<String, KeySource>,
 }
 
 impl Dictionary {
@@ -26,6 +48,17 @@ impl Dictionary {
         self.entries.insert(key.as_str().to_string(), value);
     }
 
+    /// Records where an entry was defined.
+    pub fn set_source(&mut self, key: &str, source: KeySource) {
+        self.sources.insert(key.to_string(), source);
+    }
+
+    /// Returns the file and line an entry was loaded from, if known.
+    #[must_use]
+    pub fn source(&self, key: &str) -> Option<&KeySource> {
+        self.sources.get(key)
+    }
+
     /// Looks up a translation by key.
     #[must_use]
     pub fn get(&self, key: &str) -> Option<&str> {
@@ -53,6 +86,23 @@ impl Dictionary {
     pub fn iter(&self) -> impl Iterator<Item = (&str, &str)> {
         self.entries.iter().map(|(k, v)| (k.as_str(), v.as_str()))
     }
+
+    /// Rewrites every key (and its source location) in the given style.
+    fn normalize_keys(&mut self, style: KeyStyle) {
+        if style == KeyStyle::AsIs {
+            return;
+        }
+        self.entries = self
+            .entries
+            .drain()
+            .map(|(key, value)| (style.apply(&key).into_owned(), value))
+            .collect();
+        self.sources = self
+            .sources
+            .drain()
+            .map(|(key, source)| (style.apply(&key).into_owned(), source))
+            .collect();
+    }
 }
 
 /// A collection of dictionaries, one per locale.
@@ -60,6 +110,7 @@ impl Dictionary {
 pub struct DictionarySet {
     dictionaries: HashMap<String, Dictionary>,
     default_locale: Option<Locale>,
+    key_style: KeyStyle,
 }
 
 impl DictionarySet {
@@ -80,8 +131,34 @@ impl DictionarySet {
         self.default_locale.as_ref()
     }
 
+    /// Normalizes dictionary keys and lookups to `style`.
+    ///
+    /// Existing and later inserted dictionaries are re-keyed, and keys passed
+    /// to [`translate`](Self::translate) are converted before lookup, so keys
+    /// match regardless of naming style. If two keys of one locale normalize
+    /// to the same key, one of them wins.
+    pub fn set_key_style(&mut self, style: KeyStyle) {
+        self.key_style = style;
+        for dict in self.dictionaries.values_mut() {
+            dict.normalize_keys(style);
+        }
+    }
+
+    /// Returns the key style lookups are normalized to.
+    #[must_use]
+    pub fn key_style(&self) -> KeyStyle {
+        self.key_style
+    }
+
+    /// Converts a key to the form stored in this set's dictionaries.
+    #[must_use]
+    pub fn normalize_key<'k>(&self, key: &'k str) -> Cow<'k, str> {
+        self.key_style.apply(key)
+    }
+
     /// Inserts a dictionary for a given locale.
-    pub fn insert(&mut self, locale: Locale, dict: Dictionary) {
+    pub fn insert(&mut self, locale: Locale, mut dict: Dictionary) {
+        dict.normalize_keys(self.key_style);
         self.dictionaries.insert(locale.as_str().to_string(), dict);
     }
 
@@ -102,9 +179,71 @@ impl DictionarySet {
         self.dictionaries.len()
     }
 
+    /// Serializes all locales as nested JSON for a client-side i18n runtime.
+    ///
+    /// The result has the shape
+    /// `{"defaultLocale": "en", "locales": {"en": {"common": {"hello": "Hello"}}}}`,
+    /// with keys split on `.`. When a key is also a prefix of a longer key
+    /// (`a` and `a.b`), the longer key stays flat in the nearest object.
+    #[must_use]
+    pub fn to_json(&self) -> serde_json::Value {
+        let mut locales = serde_json::Map::new();
+        for (locale, dict) in &self.dictionaries {
+            let mut entries: Vec<_> = dict.iter().collect();
+            entries.sort_unstable();
+            let mut root = serde_json::Map::new();
+            for (key, value) in entries {
+                insert_nested(&mut root, key, value);
+            }
+            locales.insert(locale.clone(), serde_json::Value::Object(root));
+        }
+
+        serde_json::json!({
+            "defaultLocale": self.default_locale.as_ref().map(Locale::as_str),
+            "locales": locales,
+        })
+    }
+
+    /// Reads a set back from the nested JSON written by [`to_json`](Self::to_json).
+    ///
+    /// Nested objects are flattened with dot separators, as when loading
+    /// dictionary files. Fails if the default locale is invalid or a locale's
+    /// value is not an object.
+    pub fn from_json(value: &serde_json::Value) -> I18nResult<Self> {
+        let mut set = Self::new();
+        if let Some(locale) = value.get("defaultLocale").and_then(serde_json::Value::as_str) {
+            set.set_default_locale(Locale::new(locale)?);
+        }
+
+        let locales = value.get("locales").and_then(serde_json::Value::as_object);
+        for (locale, entries) in locales.into_iter().flatten() {
+            let serde_json::Value::Object(entries) = entries else {
+                return Err(I18nError::DictionaryLoad {
+                    locale: locale.clone(),
+                    message: "expected an object of translations".to_string(),
+                });
+            };
+            let mut dict = Dictionary::new();
+            for (key, value) in entries {
+                match value {
+                    serde_json::Value::String(s) => dict.insert(KeyPath::new(key), s.clone()),
+                    serde_json::Value::Object(nested) => {
+                        json::flatten_object(nested, key, &mut dict);
+                    }
+                    other => dict.insert(KeyPath::new(key), other.to_string()),
+                }
+            }
+            set.insert(Locale::new(locale)?, dict);
+        }
+        Ok(set)
+    }
+
     /// Translates a key for the given locale, falling back to the default locale.
     #[must_use]
     pub fn translate(&self, locale: &str, key: &str) -> Option<&str> {
+        let key = self.normalize_key(key);
+        let key = key.as_ref();
+
         // Try the requested locale
         if let Some(dict) = self.dictionaries.get(locale) {
             if let Some(value) = dict.get(key) {
@@ -123,6 +262,95 @@ impl DictionarySet {
 
         None
     }
+
+    /// Replaces `{{name}}` placeholders in every value with build-time constants.
+    ///
+    /// Unknown placeholders are left intact and returned, sorted and
+    /// deduplicated. Only `{{...}}` bodies made of identifier characters are
+    /// treated as placeholders, and MF2 complex messages (starting with `.`)
+    /// are left alone since `{{...}}` quotes their patterns.
+    /// Run this before checking, so the `interpolation-syntax` rule only sees
+    /// placeholders that no constant filled in.
+    pub fn interpolate<S: BuildHasher>(
+        &mut self,
+        vars: &HashMap<String, String, S>,
+    ) -> Vec<UnresolvedPlaceholder> {
+        let mut unresolved = Vec::new();
+
+        for (locale, dict) in &mut self.dictionaries {
+            for (key, value) in &mut dict.entries {
+                if value.trim_start().starts_with('.') {
+                    continue;
+                }
+                let mut names = Vec::new();
+                if let Some(replaced) = interpolate_value(value, vars, &mut names) {
+                    *value = replaced;
+                }
+                unresolved.extend(names.into_iter().map(|name| UnresolvedPlaceholder {
+                    locale: locale.clone(),
+                    key: key.clone(),
+                    name,
+                }));
+            }
+        }
+
+        unresolved.sort();
+        unresolved.dedup();
+        unresolved
+    }
+}
+
+/// Inserts `value` at the dotted `key` path, creating objects as needed.
+fn insert_nested(map: &mut serde_json::Map<String, serde_json::Value>, key: &str, value: &str) {
+    if let Some((head, rest)) = key.split_once('.') {
+        let child = map
+            .entry(head.to_string())
+            .or_insert_with(|| serde_json::Value::Object(serde_json::Map::new()));
+        if let serde_json::Value::Object(child) = child {
+            insert_nested(child, rest, value);
+            return;
+        }
+    }
+    map.insert(key.to_string(), serde_json::Value::String(value.to_string()));
+}
+
+/// Replaces `{{name}}` placeholders in a single value.
+///
+/// Returns `None` when nothing was replaced. Names without a matching
+/// variable are pushed to `unresolved`.
+fn interpolate_value<S: BuildHasher>(
+    value: &str,
+    vars: &HashMap<String, String, S>,
+    unresolved: &mut Vec<String>,
+) -> Option<String> {
+    let mut out = String::with_capacity(value.len());
+    let mut rest = value;
+    let mut replaced = false;
+
+    while let Some(start) = rest.find("{{") {
+        let after = &rest[start + 2..];
+        let Some(end) = after.find("}}") else {
+            break;
+        };
+        let name = after[..end].trim();
+        let is_placeholder = !name.is_empty()
+            && name.chars().all(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '-' | '.'));
+
+        out.push_str(&rest[..start]);
+        if let Some(replacement) = vars.get(name).filter(|_| is_placeholder) {
+            out.push_str(replacement);
+            replaced = true;
+        } else {
+            if is_placeholder {
+                unresolved.push(name.to_string());
+            }
+            out.push_str(&rest[start..start + 2 + end + 2]);
+        }
+        rest = &after[end + 2..];
+    }
+
+    out.push_str(rest);
+    replaced.then_some(out)
 }
 
 /// Loads dictionaries from a directory structure.
@@ -133,6 +361,8 @@ impl DictionarySet {
 ///   en/
 ///     common.json
 ///     navigation.json
+///     admin/
+///       users.json      # keys prefixed with `admin.users.`
 ///   ja/
 ///     common.json
 ///     navigation.json
@@ -178,7 +408,21 @@ pub fn load_from_dir(dir: &Path) -> I18nResult<DictionarySet> {
 /// Loads all dictionary files from a single locale directory.
 fn load_locale_dir(dir: &Path, locale: &str) -> I18nResult<Dictionary> {
     let mut dict = Dictionary::new();
+    load_namespace_dir(dir, locale, "", &mut dict)?;
+    Ok(dict)
+}
 
+/// Loads dictionary files from `dir` into `dict`, recursing into subdirectories.
+///
+/// Each file stem is a namespace, and each enclosing subdirectory adds a
+/// prefix: `en/admin/users.json` provides `admin.users.*` keys. Subdirectories
+/// starting with `_` or `.` are skipped.
+fn load_namespace_dir(
+    dir: &Path,
+    locale: &str,
+    prefix: &str,
+    dict: &mut Dictionary,
+) -> I18nResult<()> {
     let entries = std::fs::read_dir(dir).map_err(|e| I18nError::DictionaryLoad {
         locale: locale.to_string(),
         message: e.to_string(),
@@ -191,18 +435,40 @@ fn load_locale_dir(dir: &Path, locale: &str) -> I18nResult<Dictionary> {
         })?;
 
         let path = entry.path();
+        let is_dir = path.is_dir();
+        // Directory names are namespaces as-is (`admin.v2/`); files drop their extension.
+        let name = if is_dir { path.file_name() } else { path.file_stem() };
+        let Some(name) = name.and_then(|n| n.to_str()) else {
+            continue;
+        };
+        let namespace =
+            if prefix.is_empty() { name.to_string() } else { format!("{prefix}.{name}") };
+
+        if is_dir {
+            if !name.starts_with(['_', '.']) {
+                load_namespace_dir(&path, locale, &namespace, dict)?;
+            }
+            continue;
+        }
         if !path.is_file() {
             continue;
         }
 
         let ext = path.extension().and_then(|e| e.to_str()).unwrap_or("");
 
-        let namespace = path.file_stem().and_then(|n| n.to_str()).unwrap_or("");
-
         match ext {
             "json" => {
                 let content = std::fs::read_to_string(&path)?;
-                json::load_into(&content, namespace, &mut dict).map_err(|e| {
+                json::load_into(&content, &namespace, Some(&path), dict).map_err(|e| {
+                    I18nError::DictionaryLoad {
+                        locale: locale.to_string(),
+                        message: format!("{}: {e}", path.display()),
+                    }
+                })?;
+            }
+            "jsonc" | "json5" => {
+                let content = std::fs::read_to_string(&path)?;
+                json::load_jsonc_into(&content, &namespace, Some(&path), dict).map_err(|e| {
                     I18nError::DictionaryLoad {
                         locale: locale.to_string(),
                         message: format!("{}: {e}", path.display()),
@@ -211,7 +477,7 @@ fn load_locale_dir(dir: &Path, locale: &str) -> I18nResult<Dictionary> {
             }
             "yaml" | "yml" => {
                 let content = std::fs::read_to_string(&path)?;
-                yaml::load_into(&content, namespace, &mut dict).map_err(|e| {
+                yaml::load_into(&content, &namespace, Some(&path), dict).map_err(|e| {
                     I18nError::DictionaryLoad {
                         locale: locale.to_string(),
                         message: format!("{}: {e}", path.display()),
@@ -224,7 +490,7 @@ fn load_locale_dir(dir: &Path, locale: &str) -> I18nResult<Dictionary> {
         }
     }
 
-    Ok(dict)
+    Ok(())
 }
 
 #[cfg(test)]
@@ -242,6 +508,53 @@ mod tests {
         assert_eq!(dict.get("missing"), None);
     }
 
+    #[test]
+    fn dictionary_set_to_json_nests_keys() {
+        let mut set = DictionarySet::new();
+        set.set_default_locale(Locale::new("en").unwrap());
+
+        let mut en = Dictionary::new();
+        en.insert(KeyPath::new("common.hello"), "Hello".to_string());
+        en.insert(KeyPath::new("common.nav.home"), "Home".to_string());
+        en.insert(KeyPath::new("title"), "Docs".to_string());
+        en.insert(KeyPath::new("title.short"), "D".to_string());
+        set.insert(Locale::new("en").unwrap(), en);
+
+        let mut ja = Dictionary::new();
+        ja.insert(KeyPath::new("common.hello"), "こんにちは".to_string());
+        set.insert(Locale::new("ja").unwrap(), ja);
+
+        let json: serde_json::Value = serde_json::from_str(&set.to_json().to_string()).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({
+                "defaultLocale": "en",
+                "locales": {
+                    "en": {
+                        "common": { "hello": "Hello", "nav": { "home": "Home" } },
+                        "title": "Docs",
+                        "title.short": "D",
+                    },
+                    "ja": { "common": { "hello": "こんにちは" } },
+                },
+            })
+        );
+
+        let restored = DictionarySet::from_json(&json).unwrap();
+        assert_eq!(restored.default_locale().map(Locale::as_str), Some("en"));
+        assert_eq!(restored.locale_count(), 2);
+        for locale in ["en", "ja"] {
+            let mut original: Vec<_> = set.get(locale).unwrap().iter().collect();
+            let mut entries: Vec<_> = restored.get(locale).unwrap().iter().collect();
+            original.sort_unstable();
+            entries.sort_unstable();
+            assert_eq!(entries, original, "{locale}");
+        }
+
+        let invalid = serde_json::json!({ "locales": { "en": "Hello" } });
+        assert!(DictionarySet::from_json(&invalid).is_err());
+    }
+
     #[test]
     fn dictionary_set_translate() {
         let mut set = DictionarySet::new();
@@ -266,6 +579,129 @@ mod tests {
         assert_eq!(set.translate("ja", "nonexistent"), None);
     }
 
+    #[test]
+    fn dictionary_set_translate_across_key_styles() {
+        let mut set = DictionarySet::new();
+        let mut en = Dictionary::new();
+        en.insert(KeyPath::new("user_menu.sign_out"), "Sign out".to_string());
+        set.insert(Locale::new("en").unwrap(), en);
+
+        assert_eq!(set.translate("en", "userMenu.signOut"), None);
+
+        set.set_key_style(KeyStyle::SnakeCase);
+        assert_eq!(set.translate("en", "userMenu.signOut"), Some("Sign out"));
+        assert_eq!(set.translate("en", "user-menu.sign-out"), Some("Sign out"));
+        assert_eq!(set.translate("en", "user_menu.sign_out"), Some("Sign out"));
+
+        // Dictionaries inserted later are normalized too.
+        let mut ja = Dictionary::new();
+        ja.insert(KeyPath::new("userMenu.signOut"), "ログアウト".to_string());
+        set.insert(Locale::new("ja").unwrap(), ja);
+        assert_eq!(set.translate("ja", "user_menu.sign_out"), Some("ログアウト"));
+    }
+
+    #[test]
+    fn load_from_dir_records_key_sources() {
+        let dir = std::env::temp_dir().join(format!("ox_i18n_sources_{}", std::process::id()));
+        let en = dir.join("en");
+        std::fs::create_dir_all(&en).unwrap();
+        std::fs::write(en.join("common.json"), "{\n  \"nav\": {\n    \"home\": \"Home\"\n  }\n}\n")
+            .unwrap();
+        std::fs::write(en.join("pages.yaml"), "title: Pages\nabout:\n  heading: About\n").unwrap();
+        std::fs::write(
+            en.join("footer.jsonc"),
+            "{\n  // Legal links\n  \"terms\": \"Terms\",\n}\n",
+        )
+        .unwrap();
+
+        let set = load_from_dir(&dir).unwrap();
+        let dict = set.get("en").unwrap();
+
+        let home = dict.source("common.nav.home").unwrap();
+        assert_eq!(home.path, en.join("common.json"));
+        assert_eq!(home.line, 3);
+
+        let heading = dict.source("pages.about.heading").unwrap();
+        assert_eq!(heading.path, en.join("pages.yaml"));
+        assert_eq!(heading.line, 3);
+
+        assert_eq!(dict.get("footer.terms"), Some("Terms"));
+        assert_eq!(dict.source("footer.terms").unwrap().line, 3);
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn load_from_dir_prefixes_nested_directories() {
+        let dir = std::env::temp_dir().join(format!("ox_i18n_nested_{}", std::process::id()));
+        let en = dir.join("en");
+        std::fs::create_dir_all(en.join("admin/settings")).unwrap();
+        std::fs::create_dir_all(en.join("_drafts")).unwrap();
+        std::fs::create_dir_all(en.join(".cache")).unwrap();
+        std::fs::create_dir_all(en.join("api.v2")).unwrap();
+        std::fs::write(en.join("users.json"), r#"{"title": "Users"}"#).unwrap();
+        std::fs::write(en.join("admin/users.json"), r#"{"title": "Manage users"}"#).unwrap();
+        std::fs::write(en.join("admin/settings/mail.yaml"), "title: Mail\n").unwrap();
+        std::fs::write(en.join("_drafts/wip.json"), r#"{"title": "WIP"}"#).unwrap();
+        std::fs::write(en.join(".cache/stale.json"), r#"{"title": "Stale"}"#).unwrap();
+        std::fs::write(en.join("api.v2/routes.json"), r#"{"title": "Routes"}"#).unwrap();
+
+        let set = load_from_dir(&dir).unwrap();
+        std::fs::remove_dir_all(&dir).unwrap();
+        let dict = set.get("en").unwrap();
+
+        assert_eq!(dict.get("users.title"), Some("Users"));
+        assert_eq!(dict.get("admin.users.title"), Some("Manage users"));
+        assert_eq!(dict.get("admin.settings.mail.title"), Some("Mail"));
+        assert_eq!(dict.get("api.v2.routes.title"), Some("Routes"));
+        assert_eq!(dict.len(), 4);
+    }
+
+    #[test]
+    fn interpolate_replaces_known_placeholders() {
+        let mut set = DictionarySet::new();
+        let mut en = Dictionary::new();
+        en.insert(KeyPath::new("visit"), "Visit {{siteUrl}} or {{ siteUrl }}".to_string());
+        en.insert(KeyPath::new("plain"), "No placeholders".to_string());
+        set.insert(Locale::new("en").unwrap(), en);
+
+        let vars = HashMap::from([("siteUrl".to_string(), "https://example.com".to_string())]);
+        let unresolved = set.interpolate(&vars);
+
+        assert!(unresolved.is_empty());
+        assert_eq!(
+            set.translate("en", "visit"),
+            Some("Visit https://example.com or https://example.com")
+        );
+        assert_eq!(set.translate("en", "plain"), Some("No placeholders"));
+    }
+
+    #[test]
+    fn interpolate_leaves_unknown_placeholders() {
+        let mut set = DictionarySet::new();
+        let mut ja = Dictionary::new();
+        ja.insert(KeyPath::new("docs"), "{{docsUrl}} / {{siteUrl}}".to_string());
+        ja.insert(KeyPath::new("quoted"), "{{Hello {$name}}}".to_string());
+        ja.insert(KeyPath::new("local"), ".local $x = {1} {{siteUrl {$x}}}".to_string());
+        set.insert(Locale::new("ja").unwrap(), ja);
+
+        let vars = HashMap::from([("siteUrl".to_string(), "https://example.com".to_string())]);
+        let unresolved = set.interpolate(&vars);
+
+        assert_eq!(set.translate("ja", "docs"), Some("{{docsUrl}} / https://example.com"));
+        assert_eq!(set.translate("ja", "quoted"), Some("{{Hello {$name}}}"));
+        // MF2 quoted patterns are not placeholders.
+        assert_eq!(set.translate("ja", "local"), Some(".local $x = {1} {{siteUrl {$x}}}"));
+        assert_eq!(
+            unresolved,
+            [UnresolvedPlaceholder {
+                locale: "ja".to_string(),
+                key: "docs".to_string(),
+                name: "docsUrl".to_string(),
+            }]
+        );
+    }
+
     #[test]
     fn dictionary_set_locales() {
         let mut set = DictionarySet::new();
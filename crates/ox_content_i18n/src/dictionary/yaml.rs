@@ -1,23 +1,40 @@
+use std::collections::HashMap;
+use std::path::Path;
+
 use crate::error::I18nResult;
 use crate::key::KeyPath;
 
-use super::Dictionary;
+use super::{Dictionary, KeySource};
 
 /// Loads a YAML string into a `Dictionary`, prefixing each key with `namespace.`.
 ///
 /// Nested mappings are flattened with dot separators, same as JSON loading.
-pub fn load_into(yaml_str: &str, namespace: &str, dict: &mut Dictionary) -> I18nResult<()> {
+/// When `source` is given, the file path and line of each key are recorded.
+pub fn load_into(
+    yaml_str: &str,
+    namespace: &str,
+    source: Option<&Path>,
+    dict: &mut Dictionary,
+) -> I18nResult<()> {
     let value: serde_yaml::Value = serde_yaml::from_str(yaml_str)?;
     if let serde_yaml::Value::Mapping(map) = value {
         flatten_mapping(&map, namespace, dict);
     }
+    if let Some(path) = source {
+        for (key, line) in key_lines(yaml_str) {
+            let full_key = format!("{namespace}.{key}");
+            if dict.get(&full_key).is_some() {
+                dict.set_source(&full_key, KeySource { path: path.to_path_buf(), line });
+            }
+        }
+    }
     Ok(())
 }
 
 /// Parses a YAML string into a standalone `Dictionary` with the given namespace.
 pub fn load(yaml_str: &str, namespace: &str) -> I18nResult<Dictionary> {
     let mut dict = Dictionary::new();
-    load_into(yaml_str, namespace, &mut dict)?;
+    load_into(yaml_str, namespace, None, &mut dict)?;
     Ok(dict)
 }
 
@@ -53,6 +70,71 @@ fn flatten_mapping(map: &serde_yaml::Mapping, prefix: &str, dict: &mut Dictionar
     }
 }
 
+/// Scans block-style YAML for mapping keys, returning each flattened key with its 1-based line.
+///
+/// Nesting is inferred from indentation. Sequence items and block scalar
+/// bodies are skipped.
+fn key_lines(yaml_str: &str) -> HashMap<String, usize> {
+    let mut lines = HashMap::new();
+    // (indent, key) of the enclosing mappings
+    let mut stack: Vec<(usize, String)> = Vec::new();
+    let mut block_scalar_indent: Option<usize> = None;
+
+    for (index, raw_line) in yaml_str.lines().enumerate() {
+        let content = raw_line.trim_start();
+        let indent = raw_line.len() - content.len();
+        if content.is_empty() || content.starts_with('#') {
+            continue;
+        }
+
+        if let Some(scalar_indent) = block_scalar_indent {
+            if indent > scalar_indent {
+                continue;
+            }
+            block_scalar_indent = None;
+        }
+
+        if content.starts_with('-') {
+            continue;
+        }
+
+        let Some((key, rest)) = split_key(content) else {
+            continue;
+        };
+
+        while stack.last().is_some_and(|(parent_indent, _)| *parent_indent >= indent) {
+            stack.pop();
+        }
+
+        let mut path: Vec<&str> = stack.iter().map(|(_, key)| key.as_str()).collect();
+        path.push(key);
+        lines.insert(path.join("."), index + 1);
+
+        let rest = rest.trim();
+        if rest.is_empty() || rest.starts_with('#') {
+            stack.push((indent, key.to_string()));
+        } else if rest.starts_with('|') || rest.starts_with('>') {
+            block_scalar_indent = Some(indent);
+        }
+    }
+
+    lines
+}
+
+/// Splits `key: value` into the unquoted key and the remaining value text.
+fn split_key(content: &str) -> Option<(&str, &str)> {
+    if let Some(quote) = content.chars().next().filter(|c| *c == '"' || *c == '\'') {
+        let end = content[1..].find(quote)? + 1;
+        let rest = content[end + 1..].trim_start().strip_prefix(':')?;
+        return Some((&content[1..end], rest));
+    }
+
+    let colon = content.match_indices(':').map(|(pos, _)| pos).find(|&pos| {
+        content[pos + 1..].is_empty() || content[pos + 1..].starts_with([' ', '\t'])
+    })?;
+    Some((content[..colon].trim_end(), &content[colon + 1..]))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -73,6 +155,19 @@ mod tests {
         assert_eq!(dict.get("common.nav.about"), Some("About"));
     }
 
+    #[test]
+    fn records_key_lines() {
+        let yaml = "# comment\ngreeting: Hello\nnav:\n  home: Home\n  bio: |\n    not: a key\n  about: About\n";
+        let mut dict = Dictionary::new();
+        load_into(yaml, "common", Some(Path::new("en/common.yaml")), &mut dict).unwrap();
+
+        assert_eq!(dict.source("common.greeting").unwrap().line, 2);
+        assert_eq!(dict.source("common.nav.home").unwrap().line, 4);
+        assert_eq!(dict.source("common.nav.about").unwrap().line, 7);
+        assert!(dict.source("common.nav.bio.not").is_none());
+        assert_eq!(dict.source("common.nav.home").unwrap().path, Path::new("en/common.yaml"));
+    }
+
     #[test]
     fn empty_yaml() {
         let yaml = "{}";
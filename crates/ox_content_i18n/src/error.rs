@@ -4,12 +4,17 @@ pub type I18nResult<T> = Result<T, I18nError>;
 
 #[derive(Debug, Error)]
 pub enum I18nError {
+    /// `offset` and `len` are byte positions into the message source.
     #[error("MF2 parse error at offset {offset}: {message}")]
-    Mf2Parse { offset: usize, message: String },
+    Mf2Parse { offset: usize, len: usize, message: String },
 
     #[error("MF2 validation error: {message}")]
     Mf2Validation { message: String },
 
+    /// A likely mistake that still formats, such as an unknown function.
+    #[error("MF2 validation warning: {message}")]
+    Mf2ValidationWarning { message: String },
+
     #[error("dictionary load error for locale '{locale}': {message}")]
     DictionaryLoad { locale: String, message: String },
 
@@ -28,3 +33,85 @@ pub enum I18nError {
     #[error("YAML parse error: {0}")]
     Yaml(#[from] serde_yaml::Error),
 }
+
+impl I18nError {
+    /// Returns true for findings that should be reported as warnings, not errors.
+    #[must_use]
+    pub const fn is_warning(&self) -> bool {
+        matches!(self, Self::Mf2ValidationWarning { .. })
+    }
+
+    /// Renders the error as a rustc-style diagnostic pointing into `source`.
+    ///
+    /// For [`I18nError::Mf2Parse`] this includes the offending line and a `^` marker
+    /// under the error span. Other variants render as a single `error:` (or
+    /// `warning:`) line.
+    #[must_use]
+    pub fn render_with_source(&self, source: &str) -> String {
+        let Self::Mf2Parse { offset, len, message } = self else {
+            let level = if self.is_warning() { "warning" } else { "error" };
+            return format!("{level}: {self}");
+        };
+
+        let offset = (*offset).min(source.len());
+        let line_start = source[..offset].rfind('\n').map_or(0, |i| i + 1);
+        let line_end = source[offset..].find('\n').map_or(source.len(), |i| offset + i);
+        let line = source[line_start..line_end].trim_end_matches('\r');
+        let line_number = source[..line_start].matches('\n').count() + 1;
+        let column = source[line_start..offset].chars().count() + 1;
+
+        // Clamp the underline to the offending line, but always show at least one caret.
+        let span_end = (offset + len).min(line_start + line.len()).max(offset);
+        let carets = source[offset..span_end].chars().count().max(1);
+
+        let gutter = " ".repeat(line_number.to_string().len());
+        format!(
+            "error: {message}\n{gutter}--> {line_number}:{column}\n{gutter} |\n{line_number} | {line}\n{gutter} | {}{}",
+            " ".repeat(column - 1),
+            "^".repeat(carets),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::mf2;
+
+    #[test]
+    fn render_lexer_error() {
+        let source = "Hello {$}";
+        let err = mf2::parse(source).unwrap_err();
+        assert_eq!(
+            err.render_with_source(source),
+            "error: unexpected character\n --> 1:9\n  |\n1 | Hello {$}\n  |         ^"
+        );
+    }
+
+    #[test]
+    fn render_unterminated_expression() {
+        let source = "Hello {$name";
+        let err = mf2::parse(source).unwrap_err();
+        assert_eq!(
+            err.render_with_source(source),
+            "error: unterminated `{`\n --> 1:7\n  |\n1 | Hello {$name\n  |       ^"
+        );
+    }
+
+    #[test]
+    fn render_parser_error_underlines_token() {
+        let source = ".input {$x :string}\n.match $x\nfoo bar {{x}}";
+        let err = mf2::parse(source).unwrap_err();
+        let rendered = err.render_with_source(source);
+        assert!(rendered.contains("--> 3:5"), "{rendered}");
+        assert!(rendered.ends_with("3 | foo bar {{x}}\n  |     ^^^"), "{rendered}");
+    }
+
+    #[test]
+    fn render_counts_columns_in_chars() {
+        let source = "こんにちは {$}";
+        let err = mf2::parse(source).unwrap_err();
+        let rendered = err.render_with_source(source);
+        assert!(rendered.contains("--> 1:9"), "{rendered}");
+        assert!(rendered.ends_with("  |         ^"), "{rendered}");
+    }
+}
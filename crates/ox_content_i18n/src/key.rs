@@ -1,3 +1,4 @@
+use std::borrow::Cow;
 use std::fmt;
 
 use serde::{Deserialize, Serialize};
@@ -68,6 +69,99 @@ impl From<String> for KeyPath {
     }
 }
 
+/// Naming style that translation keys are normalized to before lookup.
+///
+/// Each dot-separated segment is converted on its own, so under any style
+/// other than [`KeyStyle::AsIs`] the keys `userMenu.signOut`,
+/// `user_menu.sign_out` and `user-menu.sign-out` are the same key.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum KeyStyle {
+    /// Keys are matched exactly.
+    #[default]
+    AsIs,
+    /// `signOut`
+    CamelCase,
+    /// `sign_out`
+    SnakeCase,
+    /// `sign-out`
+    KebabCase,
+}
+
+impl KeyStyle {
+    /// Parses a style name: `"as-is"`, `"camelCase"`, `"snake_case"` or `"kebab-case"`.
+    #[must_use]
+    pub fn parse(name: &str) -> Option<Self> {
+        match name {
+            "as-is" => Some(Self::AsIs),
+            "camelCase" => Some(Self::CamelCase),
+            "snake_case" => Some(Self::SnakeCase),
+            "kebab-case" => Some(Self::KebabCase),
+            _ => None,
+        }
+    }
+
+    /// Converts every segment of `key` to this style.
+    #[must_use]
+    pub fn apply(self, key: &str) -> Cow<'_, str> {
+        if self == Self::AsIs {
+            return Cow::Borrowed(key);
+        }
+        let segments: Vec<String> = key.split('.').map(|segment| self.convert(segment)).collect();
+        Cow::Owned(segments.join("."))
+    }
+
+    fn convert(self, segment: &str) -> String {
+        let words = split_words(segment);
+        match self {
+            Self::AsIs => segment.to_string(),
+            Self::SnakeCase => words.join("_"),
+            Self::KebabCase => words.join("-"),
+            Self::CamelCase => {
+                let mut out = String::with_capacity(segment.len());
+                for (i, word) in words.iter().enumerate() {
+                    let mut chars = word.chars();
+                    if let (true, Some(first)) = (i > 0, chars.next()) {
+                        out.extend(first.to_uppercase());
+                        out.push_str(chars.as_str());
+                    } else {
+                        out.push_str(word);
+                    }
+                }
+                out
+            }
+        }
+    }
+}
+
+/// Splits a key segment into lowercase words at `_`, `-` and case changes
+/// (`HTMLParser` splits into `html` and `parser`).
+fn split_words(segment: &str) -> Vec<String> {
+    let chars: Vec<char> = segment.chars().collect();
+    let mut words = Vec::new();
+    let mut current = String::new();
+
+    for (i, &ch) in chars.iter().enumerate() {
+        if matches!(ch, '_' | '-') {
+            if !current.is_empty() {
+                words.push(std::mem::take(&mut current));
+            }
+            continue;
+        }
+        if ch.is_uppercase() && !current.is_empty() {
+            let prev = chars[i - 1];
+            let next_is_lower = chars.get(i + 1).is_some_and(|c| c.is_lowercase());
+            if prev.is_lowercase() || prev.is_numeric() || (prev.is_uppercase() && next_is_lower) {
+                words.push(std::mem::take(&mut current));
+            }
+        }
+        current.extend(ch.to_lowercase());
+    }
+    if !current.is_empty() {
+        words.push(current);
+    }
+    words
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -98,6 +192,17 @@ mod tests {
         assert_eq!(KeyPath::new("a.b.c").depth(), 3);
     }
 
+    #[test]
+    fn key_style_converts_each_segment() {
+        let key = "userMenu.sign_out.HTMLParser.item-2";
+        assert_eq!(KeyStyle::AsIs.apply(key), key);
+        assert_eq!(KeyStyle::CamelCase.apply(key), "userMenu.signOut.htmlParser.item2");
+        assert_eq!(KeyStyle::SnakeCase.apply(key), "user_menu.sign_out.html_parser.item_2");
+        assert_eq!(KeyStyle::KebabCase.apply(key), "user-menu.sign-out.html-parser.item-2");
+        assert_eq!(KeyStyle::parse("snake_case"), Some(KeyStyle::SnakeCase));
+        assert_eq!(KeyStyle::parse("SnakeCase"), None);
+    }
+
     #[test]
     fn single_segment_key() {
         let key = KeyPath::new("greeting");
@@ -5,11 +5,11 @@
 //!
 //! ## Modules
 //!
-//! - [`mf2`] — Hand-written MF2 lexer, recursive-descent parser, AST, and semantic validator
-//! - [`dictionary`] — JSON/YAML dictionary loaders with nested key flattening and multi-locale [`DictionarySet`]
-//! - [`checker`] — Static analysis rules: missing keys, unused keys, type mismatch, syntax errors
+//! - [`mf2`] — Hand-written MF2 lexer, recursive-descent parser, AST, semantic validator, and function registry
+//! - [`dictionary`] — JSON/JSONC/YAML dictionary loaders with nested key flattening and multi-locale [`DictionarySet`]
+//! - [`checker`] — Static analysis rules: missing keys, unused keys, type mismatch, syntax errors, interpolation syntax, plural categories, identical translations, duplicate values, orphaned namespaces, call arguments, structure drift
 //! - [`locale`] — BCP 47 [`Locale`] type with validation
-//! - [`key`] — Dot-separated [`KeyPath`] utilities (namespace, segments, depth)
+//! - [`key`] — Dot-separated [`KeyPath`] utilities (namespace, segments, depth) and [`KeyStyle`] normalization
 //! - [`error`] — Unified error types
 
 pub mod checker;
@@ -19,7 +19,7 @@ pub mod key;
 pub mod locale;
 pub mod mf2;
 
-pub use dictionary::{Dictionary, DictionarySet};
+pub use dictionary::{Dictionary, DictionarySet, KeySource, UnresolvedPlaceholder};
 pub use error::{I18nError, I18nResult};
-pub use key::KeyPath;
+pub use key::{KeyPath, KeyStyle};
 pub use locale::Locale;
@@ -47,6 +47,20 @@ impl Locale {
         self.0.split('-').next().unwrap_or(&self.0)
     }
 
+    /// Returns the CLDR cardinal plural categories this locale's language uses
+    /// for integer counts.
+    ///
+    /// Categories CLDR only assigns to decimals (Czech `many`) or to compact
+    /// millions (French `many`) are left out. Returns `None` for languages not covered by the built-in table.
+    #[must_use]
+    pub fn plural_categories(&self) -> Option<&'static [&'static str]> {
+        let language = self.language().to_ascii_lowercase();
+        PLURAL_CATEGORIES
+            .iter()
+            .find(|(languages, _)| languages.contains(&language.as_str()))
+            .map(|(_, categories)| *categories)
+    }
+
     /// Returns the full BCP 47 tag as a string slice.
     #[must_use]
     pub fn as_str(&self) -> &str {
@@ -54,6 +68,32 @@ impl Locale {
     }
 }
 
+/// CLDR cardinal plural categories for integer counts, grouped by language.
+///
+/// Only categories that ordinary whole-number counts select are listed, so
+/// this is narrower than the full CLDR data: `many` is left out for French,
+/// Spanish, Italian, Portuguese and Catalan (CLDR uses it only for compact
+/// millions such as `1e6`) and for Czech, Slovak and Lithuanian (decimals only).
+const PLURAL_CATEGORIES: &[(&[&str], &[&str])] = &[
+    (&["ja", "zh", "ko", "vi", "th", "id", "ms", "lo", "km", "my"], &["other"]),
+    (
+        &[
+            "en", "de", "nl", "sv", "da", "nb", "no", "nn", "fi", "et", "el", "hu", "tr", "bg",
+            "af", "hi", "bn", "ur", "fa", "sw", "ka", "az", "kk", "uz",
+        ],
+        &["one", "other"],
+    ),
+    (&["fr", "es", "it", "pt", "ca"], &["one", "other"]),
+    (&["ro", "cs", "sk", "lt"], &["one", "few", "other"]),
+    (&["hr", "sr", "bs"], &["one", "few", "other"]),
+    (&["ru", "uk", "be", "pl"], &["one", "few", "many", "other"]),
+    (&["lv"], &["zero", "one", "other"]),
+    (&["he"], &["one", "two", "other"]),
+    (&["sl"], &["one", "two", "few", "other"]),
+    (&["ga"], &["one", "two", "few", "many", "other"]),
+    (&["ar", "cy"], &["zero", "one", "two", "few", "many", "other"]),
+];
+
 impl fmt::Display for Locale {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.write_str(&self.0)
@@ -95,4 +135,22 @@ mod tests {
         let locale = Locale::new("ja").unwrap();
         assert_eq!(locale.language(), "ja");
     }
+
+    #[test]
+    fn plural_categories() {
+        let locale = Locale::new("ru-RU").unwrap();
+        assert_eq!(locale.plural_categories(), Some(&["one", "few", "many", "other"][..]));
+
+        let locale = Locale::new("fr").unwrap();
+        assert_eq!(locale.plural_categories(), Some(&["one", "other"][..]));
+
+        let locale = Locale::new("cs").unwrap();
+        assert_eq!(locale.plural_categories(), Some(&["one", "few", "other"][..]));
+
+        let locale = Locale::new("ja").unwrap();
+        assert_eq!(locale.plural_categories(), Some(&["other"][..]));
+
+        let locale = Locale::new("tlh").unwrap();
+        assert_eq!(locale.plural_categories(), None);
+    }
 }
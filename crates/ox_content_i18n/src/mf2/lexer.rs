@@ -42,13 +42,34 @@ pub struct SpannedToken {
     pub span: std::ops::Range<usize>,
 }
 
+/// Why tokenizing failed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LexErrorKind {
+    /// A `{{` quoted pattern with no closing `}}`.
+    UnterminatedPattern,
+    /// A `{` expression with no closing `}`.
+    UnterminatedExpression,
+    /// A `|` quoted literal with no closing `|`.
+    UnterminatedLiteral,
+    /// A character not allowed at this point.
+    UnexpectedCharacter,
+}
+
+/// A tokenizer error. Unterminated constructs point at their opening delimiter.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LexError {
+    /// Byte offset of the error.
+    pub offset: usize,
+    pub kind: LexErrorKind,
+}
+
 /// Context-aware MF2 tokenizer.
 ///
 /// MF2 has two modes:
 /// - **Pattern mode**: text is literal until `{` or `}}`.
 /// - **Expression mode** (inside `{ }`): variables, functions, options.
 /// - **Declaration mode**: `.input`, `.local`, `.match` and their arguments.
-pub fn tokenize(source: &str) -> Result<Vec<SpannedToken>, usize> {
+pub fn tokenize(source: &str) -> Result<Vec<SpannedToken>, LexError> {
     let mut scanner = Scanner::new(source);
     scanner.scan_message()?;
     Ok(scanner.tokens)
@@ -66,7 +87,7 @@ impl<'a> Scanner<'a> {
         Self { source, bytes: source.as_bytes(), pos: 0, tokens: Vec::new() }
     }
 
-    fn scan_message(&mut self) -> Result<(), usize> {
+    fn scan_message(&mut self) -> Result<(), LexError> {
         self.skip_whitespace_and_newlines_with_tokens();
 
         // Check if complex message (starts with `.`)
@@ -78,7 +99,7 @@ impl<'a> Scanner<'a> {
         Ok(())
     }
 
-    fn scan_complex_message(&mut self) -> Result<(), usize> {
+    fn scan_complex_message(&mut self) -> Result<(), LexError> {
         // Scan declarations and body
         loop {
             self.skip_whitespace_and_newlines_with_tokens();
@@ -127,7 +148,7 @@ impl<'a> Scanner<'a> {
         Ok(())
     }
 
-    fn scan_variant(&mut self) -> Result<(), usize> {
+    fn scan_variant(&mut self) -> Result<(), LexError> {
         // Scan variant keys until `{{`
         loop {
             self.skip_whitespace();
@@ -144,17 +165,18 @@ impl<'a> Scanner<'a> {
             } else if self.peek().is_some_and(|b| b.is_ascii_digit() || b == b'-') {
                 self.scan_number()?;
             } else {
-                return Err(self.pos);
+                return Err(self.unexpected());
             }
         }
         self.scan_quoted_pattern()?;
         Ok(())
     }
 
-    fn scan_quoted_pattern(&mut self) -> Result<(), usize> {
+    fn scan_quoted_pattern(&mut self) -> Result<(), LexError> {
         if self.peek() != Some(b'{') || self.peek_at(1) != Some(b'{') {
-            return Err(self.pos);
+            return Err(self.unexpected());
         }
+        let open = self.pos;
         self.emit(Token::DoubleOpenBrace, 2);
 
         // Scan pattern content until `}}`
@@ -186,10 +208,10 @@ impl<'a> Scanner<'a> {
                 self.pos += 1;
             }
         }
-        Err(self.pos) // unterminated `{{`
+        Err(LexError { offset: open, kind: LexErrorKind::UnterminatedPattern })
     }
 
-    fn scan_simple_pattern(&mut self) -> Result<(), usize> {
+    fn scan_simple_pattern(&mut self) -> Result<(), LexError> {
         let mut text_start = self.pos;
         while !self.is_at_end() {
             if self.peek() == Some(b'{') {
@@ -215,10 +237,11 @@ impl<'a> Scanner<'a> {
         Ok(())
     }
 
-    fn scan_expression(&mut self) -> Result<(), usize> {
+    fn scan_expression(&mut self) -> Result<(), LexError> {
         if self.peek() != Some(b'{') {
-            return Err(self.pos);
+            return Err(self.unexpected());
         }
+        let open = self.pos;
         self.emit(Token::OpenBrace, 1);
         self.skip_whitespace();
 
@@ -250,14 +273,17 @@ impl<'a> Scanner<'a> {
         }
 
         self.skip_whitespace();
+        if self.is_at_end() {
+            return Err(LexError { offset: open, kind: LexErrorKind::UnterminatedExpression });
+        }
         if self.peek() != Some(b'}') {
-            return Err(self.pos);
+            return Err(self.unexpected());
         }
         self.emit(Token::CloseBrace, 1);
         Ok(())
     }
 
-    fn scan_option(&mut self) -> Result<(), usize> {
+    fn scan_option(&mut self) -> Result<(), LexError> {
         self.scan_name()?;
         self.skip_whitespace();
         if self.peek() == Some(b'=') {
@@ -273,15 +299,15 @@ impl<'a> Scanner<'a> {
             } else if self.peek().is_some_and(|b| b.is_ascii_alphabetic() || b == b'_') {
                 self.scan_name()?;
             } else {
-                return Err(self.pos);
+                return Err(self.unexpected());
             }
         }
         Ok(())
     }
 
-    fn scan_variable_token(&mut self) -> Result<(), usize> {
+    fn scan_variable_token(&mut self) -> Result<(), LexError> {
         if self.peek() != Some(b'$') {
-            return Err(self.pos);
+            return Err(self.unexpected());
         }
         let start = self.pos;
         self.pos += 1; // skip $
@@ -290,16 +316,16 @@ impl<'a> Scanner<'a> {
             self.pos += 1;
         }
         if self.pos == name_start {
-            return Err(self.pos);
+            return Err(self.unexpected());
         }
         let name = self.source[name_start..self.pos].to_string();
         self.tokens.push(SpannedToken { token: Token::Variable(name), span: start..self.pos });
         Ok(())
     }
 
-    fn scan_function_token(&mut self) -> Result<(), usize> {
+    fn scan_function_token(&mut self) -> Result<(), LexError> {
         if self.peek() != Some(b':') {
-            return Err(self.pos);
+            return Err(self.unexpected());
         }
         let start = self.pos;
         self.pos += 1; // skip :
@@ -308,27 +334,30 @@ impl<'a> Scanner<'a> {
             self.pos += 1;
         }
         if self.pos == name_start {
-            return Err(self.pos);
+            return Err(self.unexpected());
         }
         let name = self.source[name_start..self.pos].to_string();
         self.tokens.push(SpannedToken { token: Token::Function(name), span: start..self.pos });
         Ok(())
     }
 
-    fn scan_name(&mut self) -> Result<(), usize> {
+    fn scan_name(&mut self) -> Result<(), LexError> {
         let start = self.pos;
         while self.peek().is_some_and(|b| b.is_ascii_alphanumeric() || b == b'_') {
             self.pos += 1;
         }
         if self.pos == start {
-            return Err(self.pos);
+            return Err(self.unexpected());
         }
         let name = self.source[start..self.pos].to_string();
         self.tokens.push(SpannedToken { token: Token::Name(name), span: start..self.pos });
         Ok(())
     }
 
-    fn scan_number(&mut self) -> Result<(), usize> {
+    /// Scans an MF2 number literal: `-? digits ("." digits)? ([eE] [+-]? digits)?`.
+    ///
+    /// MF2 has no hexadecimal literals, so `0x1F` stops after the `0`.
+    fn scan_number(&mut self) -> Result<(), LexError> {
         let start = self.pos;
         if self.peek() == Some(b'-') {
             self.pos += 1;
@@ -343,16 +372,30 @@ impl<'a> Scanner<'a> {
             }
         }
         if self.pos == start || (self.pos == start + 1 && self.bytes[start] == b'-') {
-            return Err(self.pos);
+            return Err(self.unexpected());
+        }
+        // Exponent: `[eE][+-]?digits`
+        if self.peek().is_some_and(|b| b == b'e' || b == b'E') {
+            self.pos += 1; // skip e
+            if self.peek().is_some_and(|b| b == b'+' || b == b'-') {
+                self.pos += 1;
+            }
+            let digits_start = self.pos;
+            while self.peek().is_some_and(|b| b.is_ascii_digit()) {
+                self.pos += 1;
+            }
+            if self.pos == digits_start {
+                return Err(self.unexpected());
+            }
         }
         let num = self.source[start..self.pos].to_string();
         self.tokens.push(SpannedToken { token: Token::Number(num), span: start..self.pos });
         Ok(())
     }
 
-    fn scan_quoted_literal(&mut self) -> Result<(), usize> {
+    fn scan_quoted_literal(&mut self) -> Result<(), LexError> {
         if self.peek() != Some(b'|') {
-            return Err(self.pos);
+            return Err(self.unexpected());
         }
         let start = self.pos;
         self.pos += 1; // skip opening |
@@ -361,7 +404,7 @@ impl<'a> Scanner<'a> {
             self.pos += 1;
         }
         if self.is_at_end() {
-            return Err(self.pos);
+            return Err(LexError { offset: start, kind: LexErrorKind::UnterminatedLiteral });
         }
         let content = self.source[content_start..self.pos].to_string();
         self.pos += 1; // skip closing |
@@ -370,9 +413,9 @@ impl<'a> Scanner<'a> {
         Ok(())
     }
 
-    fn scan_char_token(&mut self, ch: u8, token: Token) -> Result<(), usize> {
+    fn scan_char_token(&mut self, ch: u8, token: Token) -> Result<(), LexError> {
         if self.peek() != Some(ch) {
-            return Err(self.pos);
+            return Err(self.unexpected());
         }
         self.emit(token, 1);
         Ok(())
@@ -380,6 +423,10 @@ impl<'a> Scanner<'a> {
 
     // ── Helpers ──
 
+    fn unexpected(&self) -> LexError {
+        LexError { offset: self.pos, kind: LexErrorKind::UnexpectedCharacter }
+    }
+
     fn emit(&mut self, token: Token, len: usize) {
         let start = self.pos;
         self.pos += len;
@@ -511,4 +558,48 @@ mod tests {
         assert_eq!(tokens[0].token, Token::Text("You have ".to_string()));
         assert_eq!(tokens[4].token, Token::Text(" items.".to_string()));
     }
+
+    #[test]
+    fn number_with_exponent() {
+        let tokens = tokenize("{1e3}").unwrap();
+        assert_eq!(tokens[1].token, Token::Number("1e3".to_string()));
+        assert_eq!(tokens[1].span, 1..4);
+    }
+
+    #[test]
+    fn number_with_fraction_and_signed_exponent() {
+        let tokens = tokenize("{1.5e-2 :number}").unwrap();
+        assert_eq!(tokens[1].token, Token::Number("1.5e-2".to_string()));
+        assert_eq!(tokens[1].span, 1..7);
+
+        let tokens = tokenize("{2E+10}").unwrap();
+        assert_eq!(tokens[1].token, Token::Number("2E+10".to_string()));
+    }
+
+    #[test]
+    fn number_with_missing_exponent_digits() {
+        // The error points where the exponent digits were expected.
+        assert_eq!(tokenize("{1e}"), Err(unexpected(3)));
+        assert_eq!(tokenize("{1.5e+}"), Err(unexpected(6)));
+    }
+
+    #[test]
+    fn unterminated_constructs_point_at_opening_delimiter() {
+        let error = |offset, kind| Err(LexError { offset, kind });
+        assert_eq!(
+            tokenize(".match $n\none {{Hello"),
+            error(14, LexErrorKind::UnterminatedPattern)
+        );
+        assert_eq!(tokenize("Hi {|literal :string}"), error(4, LexErrorKind::UnterminatedLiteral));
+        assert_eq!(
+            tokenize("Hello {$name :string"),
+            error(6, LexErrorKind::UnterminatedExpression)
+        );
+        // A stray character inside a terminated expression still points at itself.
+        assert_eq!(tokenize("{$a $b}"), Err(unexpected(4)));
+    }
+
+    fn unexpected(offset: usize) -> LexError {
+        LexError { offset, kind: LexErrorKind::UnexpectedCharacter }
+    }
 }
@@ -1,24 +1,480 @@
 pub mod ast;
 pub mod lexer;
 pub mod parser;
+pub mod registry;
 pub mod validator;
 
 use crate::error::{I18nError, I18nResult};
-use ast::Message;
+use std::collections::{BTreeMap, BTreeSet};
+
+use ast::{
+    Annotation, ComplexBody, Declaration, Expression, Message, Operand, OptionValue, Pattern,
+    PatternPart, VariantKey,
+};
 
 /// Parses an MF2 message string into an AST.
 pub fn parse(source: &str) -> I18nResult<Message> {
-    let tokens = lexer::tokenize(source).map_err(|offset| I18nError::Mf2Parse {
-        offset,
-        message: "unexpected character".to_string(),
-    })?;
+    let tokens = lexer::tokenize(source).map_err(|error| lexer_error(source, error))?;
     let mut p = parser::Parser::new(tokens);
     p.parse()
 }
 
+/// Parses an MF2 message, reporting every error instead of only the first.
+///
+/// The parser resynchronizes at declaration and variant boundaries. Lexer
+/// errors still stop parsing. The message is returned only if there are no errors.
+pub fn parse_all(source: &str) -> (Option<Message>, Vec<I18nError>) {
+    match lexer::tokenize(source) {
+        Ok(tokens) => parser::Parser::new(tokens).parse_all(),
+        Err(error) => (None, vec![lexer_error(source, error)]),
+    }
+}
+
+fn lexer_error(source: &str, error: lexer::LexError) -> I18nError {
+    let offset = error.offset;
+    let (len, message) = match error.kind {
+        lexer::LexErrorKind::UnterminatedPattern => (2, "unterminated `{{`"),
+        lexer::LexErrorKind::UnterminatedExpression => (1, "unterminated `{`"),
+        lexer::LexErrorKind::UnterminatedLiteral => (1, "unterminated `|`"),
+        lexer::LexErrorKind::UnexpectedCharacter => {
+            (source[offset..].chars().next().map_or(0, char::len_utf8), "unexpected character")
+        }
+    };
+    I18nError::Mf2Parse { offset, len, message: message.to_string() }
+}
+
 /// Parses and validates an MF2 message, returning the AST and any validation errors.
 pub fn parse_and_validate(source: &str) -> I18nResult<(Message, Vec<I18nError>)> {
     let message = parse(source)?;
     let errors = validator::validate(&message);
     Ok((message, errors))
 }
+
+/// Returns the variables a caller must supply, sorted: every referenced
+/// variable except those bound by `.local` declarations.
+#[must_use]
+pub fn required_variables(message: &Message) -> BTreeSet<String> {
+    let mut vars: BTreeSet<String> = validator::extract_variables(message).into_iter().collect();
+    if let Message::Complex(complex) = message {
+        for decl in &complex.declarations {
+            if let Declaration::Local(local) = decl {
+                vars.remove(&local.variable);
+            }
+        }
+    }
+    vars
+}
+
+/// Evaluates the `.local` declarations of a message, for previews.
+///
+/// Locals may reference inputs from `args` and each other in any order; they
+/// are resolved in dependency order. Function annotations pass their
+/// operand's value through unformatted, and variables missing from `args`
+/// evaluate to the MF2 fallback `{$name}`. Returns an error if locals
+/// reference each other in a cycle.
+pub fn evaluate_locals(
+    message: &Message,
+    args: &BTreeMap<String, String>,
+) -> I18nResult<BTreeMap<String, String>> {
+    let mut values = BTreeMap::new();
+    let Message::Complex(complex) = message else {
+        return Ok(values);
+    };
+    let locals: BTreeMap<&str, &Expression> = complex
+        .declarations
+        .iter()
+        .filter_map(|decl| match decl {
+            Declaration::Local(local) => Some((local.variable.as_str(), &local.expression)),
+            Declaration::Input(_) => None,
+        })
+        .collect();
+
+    for name in locals.keys() {
+        evaluate_local(name, &locals, args, &mut values, &mut Vec::new())?;
+    }
+    Ok(values)
+}
+
+/// Evaluates one local, first evaluating the locals it references.
+///
+/// `stack` holds the locals currently being evaluated, for cycle detection.
+fn evaluate_local<'m>(
+    name: &'m str,
+    locals: &BTreeMap<&'m str, &'m Expression>,
+    args: &BTreeMap<String, String>,
+    values: &mut BTreeMap<String, String>,
+    stack: &mut Vec<&'m str>,
+) -> I18nResult<String> {
+    if let Some(value) = values.get(name) {
+        return Ok(value.clone());
+    }
+    if let Some(start) = stack.iter().position(|&local| local == name) {
+        let cycle: Vec<String> =
+            stack[start..].iter().chain([&name]).map(|local| format!("${local}")).collect();
+        return Err(I18nError::Mf2Validation {
+            message: format!("cyclic .local declarations: {}", cycle.join(" -> ")),
+        });
+    }
+
+    let expression = locals[name];
+    stack.push(name);
+    let option_variables = expression.annotation.iter().flat_map(|annotation| {
+        annotation.options.iter().filter_map(|option| match &option.value {
+            OptionValue::Variable(variable) => Some(variable.as_str()),
+            OptionValue::Literal(_) => None,
+        })
+    });
+    for variable in option_variables.filter(|variable| locals.contains_key(variable)) {
+        evaluate_local(variable, locals, args, values, stack)?;
+    }
+    let value = match &expression.operand {
+        Some(Operand::Literal(literal)) => literal.clone(),
+        Some(Operand::Variable(variable)) if locals.contains_key(variable.as_str()) => {
+            evaluate_local(variable, locals, args, values, stack)?
+        }
+        Some(Operand::Variable(variable)) => {
+            args.get(variable).cloned().unwrap_or_else(|| format!("{{${variable}}}"))
+        }
+        None => expression
+            .annotation
+            .as_ref()
+            .map_or_else(String::new, |annotation| format!("{{:{}}}", annotation.function)),
+    };
+    stack.pop();
+
+    values.insert(name.to_string(), value.clone());
+    Ok(value)
+}
+
+/// Serializes a message back to canonical MF2 source.
+///
+/// Declarations, the `.match` line and each variant go on their own line,
+/// and expressions are written as `{operand :function name=value}` with
+/// single spaces. Text parts are kept verbatim, so formatting is idempotent.
+#[must_use]
+pub fn to_string(message: &Message) -> String {
+    let mut out = String::new();
+    match message {
+        Message::Simple(pattern) => write_pattern(&mut out, pattern),
+        Message::Complex(complex) => {
+            for decl in &complex.declarations {
+                match decl {
+                    Declaration::Input(input) => {
+                        out.push_str(".input {$");
+                        out.push_str(&input.variable);
+                        if let Some(annotation) = &input.annotation {
+                            out.push(' ');
+                            write_annotation(&mut out, annotation);
+                        }
+                        out.push_str("}\n");
+                    }
+                    Declaration::Local(local) => {
+                        out.push_str(".local $");
+                        out.push_str(&local.variable);
+                        out.push_str(" = ");
+                        write_expression(&mut out, &local.expression);
+                        out.push('\n');
+                    }
+                }
+            }
+            match &complex.body {
+                ComplexBody::QuotedPattern(pattern) => write_quoted_pattern(&mut out, pattern),
+                ComplexBody::Matcher(matcher) => {
+                    out.push_str(".match");
+                    for selector in &matcher.selectors {
+                        out.push_str(" $");
+                        out.push_str(selector);
+                    }
+                    for variant in &matcher.variants {
+                        out.push('\n');
+                        for key in &variant.keys {
+                            match key {
+                                VariantKey::Literal(value) => out.push_str(value),
+                                VariantKey::Wildcard => out.push('*'),
+                            }
+                            out.push(' ');
+                        }
+                        write_quoted_pattern(&mut out, &variant.pattern);
+                    }
+                }
+            }
+        }
+    }
+    out
+}
+
+fn write_quoted_pattern(out: &mut String, pattern: &Pattern) {
+    out.push_str("{{");
+    write_pattern(out, pattern);
+    out.push_str("}}");
+}
+
+fn write_pattern(out: &mut String, pattern: &Pattern) {
+    for part in &pattern.parts {
+        match part {
+            PatternPart::Text(text) => out.push_str(text),
+            PatternPart::Expression(expression) => write_expression(out, expression),
+        }
+    }
+}
+
+fn write_expression(out: &mut String, expression: &Expression) {
+    out.push('{');
+    match &expression.operand {
+        Some(Operand::Variable(name)) => {
+            out.push('$');
+            out.push_str(name);
+        }
+        Some(Operand::Literal(value)) => write_literal(out, value, false),
+        None => {}
+    }
+    if let Some(annotation) = &expression.annotation {
+        if expression.operand.is_some() {
+            out.push(' ');
+        }
+        write_annotation(out, annotation);
+    }
+    out.push('}');
+}
+
+fn write_annotation(out: &mut String, annotation: &Annotation) {
+    out.push(':');
+    out.push_str(&annotation.function);
+    for option in &annotation.options {
+        out.push(' ');
+        out.push_str(&option.name);
+        out.push('=');
+        match &option.value {
+            OptionValue::Variable(name) => {
+                out.push('$');
+                out.push_str(name);
+            }
+            OptionValue::Literal(value) => write_literal(out, value, true),
+        }
+    }
+}
+
+/// Writes a literal bare when the lexer accepts it unquoted, else as `|value|`.
+fn write_literal(out: &mut String, value: &str, allow_name: bool) {
+    if is_number_literal(value) || (allow_name && is_name_literal(value)) {
+        out.push_str(value);
+    } else {
+        out.push('|');
+        out.push_str(value);
+        out.push('|');
+    }
+}
+
+fn is_name_literal(value: &str) -> bool {
+    value.starts_with(|c: char| c.is_ascii_alphabetic() || c == '_')
+        && value.bytes().all(|b| b.is_ascii_alphanumeric() || b == b'_')
+}
+
+/// Matches the lexer's number grammar: `-? digits ("." digits)? ([eE] [+-]? digits)?`.
+fn is_number_literal(value: &str) -> bool {
+    fn digits(s: &str) -> (&str, bool) {
+        let end = s.bytes().position(|b| !b.is_ascii_digit()).unwrap_or(s.len());
+        (&s[end..], end > 0)
+    }
+
+    let (rest, ok) = digits(value.strip_prefix('-').unwrap_or(value));
+    if !ok {
+        return false;
+    }
+    let rest = match rest.strip_prefix('.') {
+        Some(fraction) => match digits(fraction) {
+            (rest, true) => rest,
+            _ => return false,
+        },
+        None => rest,
+    };
+    match rest.strip_prefix(['e', 'E']) {
+        Some(exponent) => {
+            let (rest, ok) = digits(exponent.strip_prefix(['+', '-']).unwrap_or(exponent));
+            ok && rest.is_empty()
+        }
+        None => rest.is_empty(),
+    }
+}
+
+/// Returns true if two messages have the same structure, ignoring insignificant
+/// whitespace in text parts.
+///
+/// Whitespace runs compare equal to a single space, and leading/trailing
+/// whitespace of each pattern is ignored.
+#[must_use]
+pub fn semantically_equal(a: &Message, b: &Message) -> bool {
+    match (a, b) {
+        (Message::Simple(a), Message::Simple(b)) => patterns_equal(a, b),
+        (Message::Complex(a), Message::Complex(b)) => {
+            a.declarations == b.declarations
+                && match (&a.body, &b.body) {
+                    (ComplexBody::QuotedPattern(a), ComplexBody::QuotedPattern(b)) => {
+                        patterns_equal(a, b)
+                    }
+                    (ComplexBody::Matcher(a), ComplexBody::Matcher(b)) => {
+                        a.selectors == b.selectors
+                            && a.variants.len() == b.variants.len()
+                            && a.variants.iter().zip(&b.variants).all(|(a, b)| {
+                                a.keys == b.keys && patterns_equal(&a.pattern, &b.pattern)
+                            })
+                    }
+                    _ => false,
+                }
+        }
+        _ => false,
+    }
+}
+
+/// A pattern part with adjacent text merged and whitespace collapsed.
+#[derive(PartialEq)]
+enum NormalizedPart<'a> {
+    Text(String),
+    Expression(&'a Expression),
+}
+
+fn patterns_equal(a: &Pattern, b: &Pattern) -> bool {
+    normalize_pattern(a) == normalize_pattern(b)
+}
+
+fn normalize_pattern(pattern: &Pattern) -> Vec<NormalizedPart<'_>> {
+    let mut parts = Vec::new();
+    let mut text = String::new();
+
+    for part in &pattern.parts {
+        match part {
+            PatternPart::Text(value) => text.push_str(value),
+            PatternPart::Expression(expression) => {
+                parts.push(NormalizedPart::Text(std::mem::take(&mut text)));
+                parts.push(NormalizedPart::Expression(expression));
+            }
+        }
+    }
+    parts.push(NormalizedPart::Text(text));
+
+    let last = parts.len() - 1;
+    for (index, part) in parts.iter_mut().enumerate() {
+        if let NormalizedPart::Text(value) = part {
+            let mut collapsed = value.split_whitespace().collect::<Vec<_>>().join(" ");
+            if index > 0 && value.starts_with(char::is_whitespace) {
+                collapsed.insert(0, ' ');
+            }
+            if index < last && value.ends_with(char::is_whitespace) && !collapsed.ends_with(' ') {
+                collapsed.push(' ');
+            }
+            *value = collapsed;
+        }
+    }
+
+    parts
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn required(source: &str) -> Vec<String> {
+        required_variables(&parse(source).unwrap()).into_iter().collect()
+    }
+
+    #[test]
+    fn required_variables_simple_message() {
+        assert_eq!(required("Hello {$name}!"), ["name"]);
+        assert!(required("Hello!").is_empty());
+    }
+
+    #[test]
+    fn required_variables_exclude_locals() {
+        assert_eq!(
+            required(".input {$count :number}\n.local $name = {|Guest|}\n{{{$name} has {$count}, {$total}}}"),
+            ["count", "total"]
+        );
+        assert_eq!(
+            required(
+                ".local $label = {$user :string}\n.match $label\nadmin {{Admin}}\n* {{{$label}}}"
+            ),
+            ["user"]
+        );
+    }
+
+    fn locals(source: &str, args: &[(&str, &str)]) -> I18nResult<BTreeMap<String, String>> {
+        let args =
+            args.iter().map(|&(name, value)| (name.to_string(), value.to_string())).collect();
+        evaluate_locals(&parse(source).unwrap(), &args)
+    }
+
+    #[test]
+    fn evaluate_locals_resolves_chains() {
+        let values = locals(
+            ".local $c = {$b}\n.local $b = {$a :string}\n.local $a = {$x :number}\n{{{$c}}}",
+            &[("x", "42")],
+        )
+        .unwrap();
+        assert_eq!(values["a"], "42");
+        assert_eq!(values["b"], "42");
+        assert_eq!(values["c"], "42");
+
+        let values =
+            locals(".local $label = {|Guest|}\n.local $who = {$user}\n{{{$who}}}", &[]).unwrap();
+        assert_eq!(values["label"], "Guest");
+        assert_eq!(values["who"], "{$user}");
+    }
+
+    #[test]
+    fn evaluate_locals_rejects_cycles() {
+        let err = locals(".local $a = {$b}\n.local $b = {$a}\n{{{$a}}}", &[]).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "MF2 validation error: cyclic .local declarations: $a -> $b -> $a"
+        );
+
+        let err = locals(".local $a = {$x :number digits=$a}\n{{{$a}}}", &[]).unwrap_err();
+        assert!(err.to_string().contains("$a -> $a"));
+    }
+
+    fn format(source: &str) -> String {
+        to_string(&parse(source).unwrap())
+    }
+
+    #[test]
+    fn to_string_canonicalizes_matcher() {
+        let formatted = format(
+            ".input   {$count   :number minimumFractionDigits=2}\n.local $label={|a b|}\n.match   $count\n  one{{One {$label}}}\n*   {{{$count :number} items}}",
+        );
+        assert_eq!(
+            formatted,
+            ".input {$count :number minimumFractionDigits=2}\n.local $label = {|a b|}\n.match $count\none {{One {$label}}}\n* {{{$count :number} items}}"
+        );
+        assert_eq!(format(&formatted), formatted);
+    }
+
+    #[test]
+    #[allow(clippy::literal_string_with_formatting_args)]
+    fn to_string_simple_message() {
+        assert_eq!(
+            format("Hello {$name}, {  -1.5e3  :number style=|x y|}!"),
+            "Hello {$name}, {-1.5e3 :number style=|x y|}!"
+        );
+        assert_eq!(format("Today is {  :datetime }"), "Today is {:datetime}");
+    }
+
+    fn equal(a: &str, b: &str) -> bool {
+        semantically_equal(&parse(a).unwrap(), &parse(b).unwrap())
+    }
+
+    #[test]
+    fn semantically_equal_ignores_whitespace() {
+        assert!(equal("Hello   {$name}!", "  Hello {$name}!\n"));
+        assert!(equal(
+            ".input {$n :number}\n.match $n\none {{One   item}}\n* {{{$n} items}}",
+            ".input {$n :number}\n.match $n\none {{ One item }}\n* {{{$n}  items}}",
+        ));
+    }
+
+    #[test]
+    fn semantically_equal_detects_changes() {
+        assert!(!equal("Hello {$name}", "Hello {$user}"));
+        assert!(!equal("Hello {$name}", "Hello{$name}"));
+        assert!(!equal("Hello", "Goodbye"));
+    }
+}
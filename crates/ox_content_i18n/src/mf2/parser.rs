@@ -10,12 +10,33 @@ use crate::mf2::lexer::{SpannedToken, Token};
 pub struct Parser {
     tokens: Vec<SpannedToken>,
     pos: usize,
+    /// Whether to collect errors and resynchronize instead of bailing.
+    recover: bool,
+    errors: Vec<I18nError>,
 }
 
 impl Parser {
     #[must_use]
     pub fn new(tokens: Vec<SpannedToken>) -> Self {
-        Self { tokens, pos: 0 }
+        Self { tokens, pos: 0, recover: false, errors: Vec::new() }
+    }
+
+    /// Parses the message, collecting errors instead of stopping at the first.
+    ///
+    /// Resynchronizes at declaration and variant boundaries. The message is
+    /// returned only when no error was found.
+    pub fn parse_all(&mut self) -> (Option<Message>, Vec<I18nError>) {
+        self.recover = true;
+        let result = self.parse();
+        let mut errors = std::mem::take(&mut self.errors);
+        match result {
+            Ok(message) if errors.is_empty() => (Some(message), errors),
+            Ok(_) => (None, errors),
+            Err(error) => {
+                errors.push(error);
+                (None, errors)
+            }
+        }
     }
 
     pub fn parse(&mut self) -> I18nResult<Message> {
@@ -37,7 +58,13 @@ impl Parser {
         let mut declarations = Vec::new();
 
         while self.check(&Token::DotInput) || self.check(&Token::DotLocal) {
-            declarations.push(self.parse_declaration()?);
+            match self.parse_declaration() {
+                Ok(declaration) => declarations.push(declaration),
+                Err(error) => {
+                    self.report(error)?;
+                    self.skip_to_declaration_boundary();
+                }
+            }
             self.skip_newlines();
         }
 
@@ -96,12 +123,15 @@ impl Parser {
 
         let mut variants = Vec::new();
         while !self.is_at_end() {
-            if let Some(variant) = self.try_parse_variant(selectors.len())? {
-                variants.push(variant);
-                self.skip_newlines();
-            } else {
-                break;
+            match self.try_parse_variant(selectors.len()) {
+                Ok(Some(variant)) => variants.push(variant),
+                Ok(None) => break,
+                Err(error) => {
+                    self.report(error)?;
+                    self.skip_to_variant_boundary();
+                }
             }
+            self.skip_newlines();
         }
 
         if variants.is_empty() {
@@ -348,9 +378,42 @@ impl Parser {
         }
     }
 
+    /// Records `error` when recovering, otherwise returns it.
+    fn report(&mut self, error: I18nError) -> I18nResult<()> {
+        if self.recover {
+            self.errors.push(error);
+            Ok(())
+        } else {
+            Err(error)
+        }
+    }
+
+    /// Skips to the next `.input`, `.local`, `.match` or quoted body.
+    fn skip_to_declaration_boundary(&mut self) {
+        while !self.is_at_end()
+            && !self.check(&Token::DotInput)
+            && !self.check(&Token::DotLocal)
+            && !self.check(&Token::DotMatch)
+            && !self.check(&Token::DoubleOpenBrace)
+        {
+            self.advance();
+        }
+    }
+
+    /// Skips the rest of the current variant line.
+    fn skip_to_variant_boundary(&mut self) {
+        while !self.is_at_end() && !self.check(&Token::Newline) {
+            self.advance();
+        }
+    }
+
     fn error(&self, message: &str) -> I18nError {
-        let offset = self.tokens.get(self.pos).map_or(0, |t| t.span.start);
-        I18nError::Mf2Parse { offset, message: message.to_string() }
+        let (offset, len) = match self.tokens.get(self.pos) {
+            Some(t) => (t.span.start, t.span.len()),
+            // At end of input, point just past the last token.
+            None => (self.tokens.last().map_or(0, |t| t.span.end), 0),
+        };
+        I18nError::Mf2Parse { offset, len, message: message.to_string() }
     }
 }
 
@@ -473,6 +536,24 @@ mod tests {
         }
     }
 
+    #[test]
+    fn parse_all_reports_multiple_errors() {
+        let source = ".input {|x| :number}\n.match $a $b\none {{x}}\n* * {{y}}";
+        let (message, errors) = mf2::parse_all(source);
+        assert!(message.is_none());
+        assert_eq!(errors.len(), 2, "{errors:?}");
+        assert!(errors[0].to_string().contains("expected variable"));
+        assert!(errors[1].to_string().contains("expected 2 variant keys, found 1"));
+    }
+
+    #[test]
+    fn parse_all_valid_message() {
+        let source = ".input {$count :number}\n.match $count\none {{one}}\n* {{many}}";
+        let (message, errors) = mf2::parse_all(source);
+        assert!(errors.is_empty());
+        assert!(matches!(message, Some(Message::Complex(_))));
+    }
+
     #[test]
     fn text_with_punctuation() {
         let msg = mf2::parse("You have {$count} items.").unwrap();
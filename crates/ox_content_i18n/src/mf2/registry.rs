@@ -0,0 +1,136 @@
+use std::collections::{HashMap, HashSet};
+
+/// Options accepted by `:number`.
+const NUMBER_OPTIONS: &[&str] = &[
+    "select",
+    "compactDisplay",
+    "notation",
+    "numberingSystem",
+    "signDisplay",
+    "style",
+    "useGrouping",
+    "minimumIntegerDigits",
+    "minimumFractionDigits",
+    "maximumFractionDigits",
+    "minimumSignificantDigits",
+    "maximumSignificantDigits",
+    "currency",
+    "currencyDisplay",
+    "currencySign",
+    "unit",
+    "unitDisplay",
+];
+
+/// Options accepted by `:integer`.
+const INTEGER_OPTIONS: &[&str] = &[
+    "select",
+    "numberingSystem",
+    "signDisplay",
+    "style",
+    "useGrouping",
+    "minimumIntegerDigits",
+    "maximumSignificantDigits",
+];
+
+/// Options accepted by `:date`.
+const DATE_OPTIONS: &[&str] = &["style", "calendar", "numberingSystem", "timeZone"];
+
+/// Options accepted by `:time`.
+const TIME_OPTIONS: &[&str] = &["style", "calendar", "numberingSystem", "timeZone", "hour12"];
+
+/// Options accepted by `:datetime`.
+const DATETIME_OPTIONS: &[&str] = &[
+    "dateStyle",
+    "timeStyle",
+    "calendar",
+    "numberingSystem",
+    "timeZone",
+    "hour12",
+    "hourCycle",
+    "weekday",
+    "era",
+    "year",
+    "month",
+    "day",
+    "hour",
+    "minute",
+    "second",
+    "fractionalSecondDigits",
+    "timeZoneName",
+];
+
+/// The set of options a function accepts.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FunctionOptions {
+    /// Any option name is accepted.
+    Any,
+    /// Only the listed option names are accepted.
+    Only(HashSet<String>),
+}
+
+/// A registry of known MF2 functions and the options each one accepts.
+///
+/// [`FunctionRegistry::default`] contains the MF2 default functions
+/// (`:number`, `:integer`, `:string`, `:date`, `:time`, `:datetime`).
+/// Projects with custom formatters can add them via [`FunctionRegistry::register`]
+/// or [`FunctionRegistry::register_any`].
+#[derive(Debug, Clone)]
+pub struct FunctionRegistry {
+    functions: HashMap<String, FunctionOptions>,
+}
+
+impl Default for FunctionRegistry {
+    fn default() -> Self {
+        let mut registry = Self::empty();
+        registry.register("number", NUMBER_OPTIONS);
+        registry.register("integer", INTEGER_OPTIONS);
+        registry.register("string", &[]);
+        registry.register("date", DATE_OPTIONS);
+        registry.register("time", TIME_OPTIONS);
+        registry.register("datetime", DATETIME_OPTIONS);
+        registry
+    }
+}
+
+impl FunctionRegistry {
+    /// Creates a registry with no known functions.
+    #[must_use]
+    pub fn empty() -> Self {
+        Self { functions: HashMap::new() }
+    }
+
+    /// Registers a function that accepts only the given options.
+    pub fn register(&mut self, name: &str, options: &[&str]) {
+        let options = options.iter().map(|o| (*o).to_string()).collect();
+        self.functions.insert(name.to_string(), FunctionOptions::Only(options));
+    }
+
+    /// Registers a function that accepts any option.
+    pub fn register_any(&mut self, name: &str) {
+        self.functions.insert(name.to_string(), FunctionOptions::Any);
+    }
+
+    /// Returns true if the function is known.
+    #[must_use]
+    pub fn contains(&self, name: &str) -> bool {
+        self.functions.contains_key(name)
+    }
+
+    /// Returns the options accepted by a function, if it is known.
+    #[must_use]
+    pub fn options(&self, name: &str) -> Option<&FunctionOptions> {
+        self.functions.get(name)
+    }
+
+    /// Returns true if `option` is valid for `function`.
+    ///
+    /// Unknown functions accept no options.
+    #[must_use]
+    pub fn accepts_option(&self, function: &str, option: &str) -> bool {
+        match self.functions.get(function) {
+            Some(FunctionOptions::Any) => true,
+            Some(FunctionOptions::Only(options)) => options.contains(option),
+            None => false,
+        }
+    }
+}
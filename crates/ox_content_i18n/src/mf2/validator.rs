@@ -1,46 +1,64 @@
 use crate::error::I18nError;
 use crate::mf2::ast::{
-    ComplexBody, ComplexMessage, Declaration, Expression, Message, Operand, Pattern, PatternPart,
-    Variant,
+    Annotation, ComplexBody, ComplexMessage, Declaration, Expression, Matcher, Message, Operand,
+    Pattern, PatternPart, Variant,
 };
+use crate::mf2::registry::FunctionRegistry;
 use std::collections::HashSet;
 
 /// Performs semantic validation on a parsed MF2 message.
+///
+/// Functions and their options are checked against the default [`FunctionRegistry`];
+/// unknown ones are reported as [`I18nError::Mf2ValidationWarning`].
 pub fn validate(message: &Message) -> Vec<I18nError> {
+    validate_with_registry(message, &FunctionRegistry::default())
+}
+
+/// Performs semantic validation using a custom function registry.
+pub fn validate_with_registry(message: &Message, registry: &FunctionRegistry) -> Vec<I18nError> {
     let mut errors = Vec::new();
     match message {
-        Message::Simple(pattern) => validate_pattern(pattern, &HashSet::new(), &mut errors),
-        Message::Complex(complex) => validate_complex(complex, &mut errors),
+        Message::Simple(pattern) => {
+            validate_pattern(pattern, &HashSet::new(), registry, &mut errors);
+        }
+        Message::Complex(complex) => validate_complex(complex, registry, &mut errors),
     }
     errors
 }
 
-fn validate_complex(complex: &ComplexMessage, errors: &mut Vec<I18nError>) {
+fn validate_complex(
+    complex: &ComplexMessage,
+    registry: &FunctionRegistry,
+    errors: &mut Vec<I18nError>,
+) {
     let mut declared_vars: HashSet<String> = HashSet::new();
+    let mut input_vars: HashSet<&str> = HashSet::new();
 
     // Collect declared variables
     for decl in &complex.declarations {
         match decl {
             Declaration::Input(input) => {
+                // A bare `.input {$x}` is valid: it declares the external
+                // variable without a formatting function.
+                if let Some(ann) = &input.annotation {
+                    validate_annotation(ann, registry, errors);
+                }
                 if !declared_vars.insert(input.variable.clone()) {
-                    errors.push(I18nError::Mf2Validation {
-                        message: format!(
-                            "duplicate declaration of variable '${}''",
-                            input.variable
-                        ),
-                    });
+                    errors.push(redeclaration_error(
+                        &input.variable,
+                        input_vars.contains(input.variable.as_str()),
+                    ));
                 }
+                input_vars.insert(&input.variable);
             }
             Declaration::Local(local) => {
                 // Validate the RHS expression first
-                validate_expression(&local.expression, &declared_vars, errors);
+                validate_expression(&local.expression, &declared_vars, registry, errors);
                 if !declared_vars.insert(local.variable.clone()) {
-                    errors.push(I18nError::Mf2Validation {
-                        message: format!(
-                            "duplicate declaration of variable '${}''",
-                            local.variable
-                        ),
-                    });
+                    errors.push(redeclaration_error(
+                        &local.variable,
+                        !input_vars.contains(local.variable.as_str()),
+                    ));
                 }
             }
         }
@@ -60,37 +78,41 @@ fn validate_complex(complex: &ComplexMessage, errors: &mut Vec<I18nError>) {
             }
 
             // Each variant must have matching key count
-            let expected_keys = matcher.selectors.len();
+            validate_variant_key_counts(matcher, errors);
             for variant in &matcher.variants {
-                if variant.keys.len() != expected_keys {
-                    errors.push(I18nError::Mf2Validation {
-                        message: format!(
-                            "variant has {} keys but {} selectors declared",
-                            variant.keys.len(),
-                            expected_keys
-                        ),
-                    });
-                }
-                validate_pattern(&variant.pattern, &declared_vars, errors);
+                validate_pattern(&variant.pattern, &declared_vars, registry, errors);
             }
 
-            // Must have a catch-all variant
-            validate_catch_all(&matcher.variants, expected_keys, errors);
+            // Must have a catch-all variant, and nothing after it
+            validate_catch_all(&matcher.variants, matcher.selectors.len(), errors);
+            validate_unreachable_variants(matcher, errors);
         }
         ComplexBody::QuotedPattern(pattern) => {
-            validate_pattern(pattern, &declared_vars, errors);
+            validate_pattern(pattern, &declared_vars, registry, errors);
         }
     }
 }
 
+/// Error for a variable declared a second time; `same_kind` is false when
+/// one declaration is `.input` and the other `.local`.
+fn redeclaration_error(variable: &str, same_kind: bool) -> I18nError {
+    let message = if same_kind {
+        format!("duplicate declaration of variable '${variable}'")
+    } else {
+        format!("variable '${variable}' is declared by both .input and .local")
+    };
+    I18nError::Mf2Validation { message }
+}
+
 fn validate_pattern(
     pattern: &Pattern,
     declared_vars: &HashSet<String>,
+    registry: &FunctionRegistry,
     errors: &mut Vec<I18nError>,
 ) {
     for part in &pattern.parts {
         if let PatternPart::Expression(expr) = part {
-            validate_expression(expr, declared_vars, errors);
+            validate_expression(expr, declared_vars, registry, errors);
         }
     }
 }
@@ -98,18 +120,11 @@ fn validate_pattern(
 fn validate_expression(
     expr: &Expression,
     _declared_vars: &HashSet<String>,
+    registry: &FunctionRegistry,
     errors: &mut Vec<I18nError>,
 ) {
-    // Validate that annotation options are not duplicated
     if let Some(ann) = &expr.annotation {
-        let mut seen_opts: HashSet<String> = HashSet::new();
-        for opt in &ann.options {
-            if !seen_opts.insert(opt.name.clone()) {
-                errors.push(I18nError::Mf2Validation {
-                    message: format!("duplicate option '{}' in :{}", opt.name, ann.function),
-                });
-            }
-        }
+        validate_annotation(ann, registry, errors);
     }
 
     // Validate operand exists for expression without annotation
@@ -120,11 +135,67 @@ fn validate_expression(
     }
 }
 
+fn validate_annotation(ann: &Annotation, registry: &FunctionRegistry, errors: &mut Vec<I18nError>) {
+    // Validate that annotation options are not duplicated
+    let mut seen_opts: HashSet<String> = HashSet::new();
+    for opt in &ann.options {
+        if !seen_opts.insert(opt.name.clone()) {
+            errors.push(I18nError::Mf2Validation {
+                message: format!("duplicate option '{}' in :{}", opt.name, ann.function),
+            });
+        }
+    }
+
+    // Unknown functions and options are warnings: a custom formatter may
+    // simply be missing from the registry.
+    if !registry.contains(&ann.function) {
+        errors.push(I18nError::Mf2ValidationWarning {
+            message: format!("unknown function ':{}'", ann.function),
+        });
+        return;
+    }
+
+    for opt in &ann.options {
+        if !registry.accepts_option(&ann.function, &opt.name) {
+            errors.push(I18nError::Mf2ValidationWarning {
+                message: format!("option '{}' is not valid for :{}", opt.name, ann.function),
+            });
+        }
+    }
+}
+
+/// Checks that every variant has exactly one key per selector.
+///
+/// The parser already rejects mismatches, but messages may also be built or
+/// transformed programmatically, so the validator checks independently.
+fn validate_variant_key_counts(matcher: &Matcher, errors: &mut Vec<I18nError>) {
+    let expected = matcher.selectors.len();
+    for (index, variant) in matcher.variants.iter().enumerate() {
+        let actual = variant.keys.len();
+        if actual != expected {
+            let selectors: Vec<String> =
+                matcher.selectors.iter().map(|selector| format!("${selector}")).collect();
+            errors.push(I18nError::Mf2Validation {
+                message: format!(
+                    "variant {} has {actual} key{} but .match has {expected} selector{} ({})",
+                    index + 1,
+                    if actual == 1 { "" } else { "s" },
+                    if expected == 1 { "" } else { "s" },
+                    selectors.join(" "),
+                ),
+            });
+        }
+    }
+}
+
+/// Returns true if every key of `variant` is a wildcard, one per selector.
+fn is_catch_all(variant: &Variant, selector_count: usize) -> bool {
+    variant.keys.len() == selector_count
+        && variant.keys.iter().all(|k| matches!(k, crate::mf2::ast::VariantKey::Wildcard))
+}
+
 fn validate_catch_all(variants: &[Variant], selector_count: usize, errors: &mut Vec<I18nError>) {
-    let has_catch_all = variants.iter().any(|v| {
-        v.keys.len() == selector_count
-            && v.keys.iter().all(|k| matches!(k, crate::mf2::ast::VariantKey::Wildcard))
-    });
+    let has_catch_all = variants.iter().any(|v| is_catch_all(v, selector_count));
 
     if !has_catch_all {
         errors.push(I18nError::Mf2Validation {
@@ -134,6 +205,25 @@ fn validate_catch_all(variants: &[Variant], selector_count: usize, errors: &mut
     }
 }
 
+/// Flags variants that follow a catch-all variant, since they can never be selected.
+fn validate_unreachable_variants(matcher: &Matcher, errors: &mut Vec<I18nError>) {
+    let selector_count = matcher.selectors.len();
+    let Some(catch_all) = matcher.variants.iter().position(|v| is_catch_all(v, selector_count))
+    else {
+        return;
+    };
+
+    for index in catch_all + 1..matcher.variants.len() {
+        errors.push(I18nError::Mf2Validation {
+            message: format!(
+                "variant {} is unreachable because catch-all variant {} matches first",
+                index + 1,
+                catch_all + 1,
+            ),
+        });
+    }
+}
+
 /// Extracts all variable names referenced in a message.
 #[must_use]
 pub fn extract_variables(message: &Message) -> HashSet<String> {
@@ -201,6 +291,39 @@ mod tests {
         assert!(errors.is_empty());
     }
 
+    #[test]
+    fn bare_input_declaration() {
+        let source = ".input {$name}\n.local $greeting = {$name :string}\n{{Hello {$greeting}}}";
+        let msg = mf2::parse(source).unwrap();
+        let Message::Complex(complex) = &msg else { panic!("expected complex message") };
+        let Declaration::Input(input) = &complex.declarations[0] else {
+            panic!("expected input declaration")
+        };
+        assert_eq!(input.variable, "name");
+        assert!(input.annotation.is_none());
+        assert!(validate(&msg).is_empty());
+    }
+
+    #[test]
+    fn duplicate_declarations() {
+        let errors = |source| {
+            validate(&mf2::parse(source).unwrap())
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>()
+        };
+        let error = |message: &str| {
+            vec![I18nError::Mf2Validation { message: message.to_string() }.to_string()]
+        };
+        let conflict = "variable '$x' is declared by both .input and .local";
+        let duplicate = "duplicate declaration of variable '$x'";
+
+        assert_eq!(errors(".input {$x}\n.local $x = {1}\n{{{$x}}}"), error(conflict));
+        assert_eq!(errors(".local $x = {1}\n.input {$x :number}\n{{{$x}}}"), error(conflict));
+        assert_eq!(errors(".input {$x}\n.input {$x :number}\n{{{$x}}}"), error(duplicate));
+        assert_eq!(errors(".local $x = {1}\n.local $x = {2}\n{{{$x}}}"), error(duplicate));
+    }
+
     #[test]
     fn missing_catch_all() {
         let source = ".input {$count :number}\n.match $count\none {{one}}";
@@ -210,6 +333,42 @@ mod tests {
         assert!(errors.iter().any(|e| e.to_string().contains("catch-all")));
     }
 
+    #[test]
+    fn variant_key_count_mismatch() {
+        let source = ".input {$a :number}\n.input {$b :number}\n.match $a $b\n\
+                      one one {{both one}}\n* * {{other}}";
+        let mut msg = mf2::parse(source).unwrap();
+        assert!(validate(&msg).is_empty());
+
+        // Build a malformed-but-parseable shape: drop one key from the first variant
+        let Message::Complex(complex) = &mut msg else { panic!("expected complex message") };
+        let ComplexBody::Matcher(matcher) = &mut complex.body else { panic!("expected matcher") };
+        matcher.variants[0].keys.pop();
+
+        let errors = validate(&msg);
+        assert_eq!(errors.len(), 1);
+        assert_eq!(
+            errors[0].to_string(),
+            I18nError::Mf2Validation {
+                message: "variant 1 has 1 key but .match has 2 selectors ($a $b)".to_string()
+            }
+            .to_string()
+        );
+    }
+
+    #[test]
+    fn unreachable_variant_after_catch_all() {
+        let source = ".input {$a :number}\n.input {$b :number}\n.match $a $b\n\
+                      one * {{one}}\n* * {{other}}\none one {{never}}";
+        let msg = mf2::parse(source).unwrap();
+        let errors = validate(&msg);
+        assert_eq!(errors.len(), 1, "{errors:?}");
+        assert!(errors[0].to_string().contains("variant 3 is unreachable"), "{}", errors[0]);
+
+        let source = ".input {$a :number}\n.match $a\n* {{other}}";
+        assert!(validate(&mf2::parse(source).unwrap()).is_empty());
+    }
+
     #[test]
     fn duplicate_option() {
         let msg = mf2::parse("{$x :number style=decimal style=percent}").unwrap();
@@ -217,6 +376,45 @@ mod tests {
         assert!(errors.iter().any(|e| e.to_string().contains("duplicate option")));
     }
 
+    #[test]
+    fn unknown_function() {
+        let msg = mf2::parse("{$x :frobnicate}").unwrap();
+        let errors = validate(&msg);
+        assert_eq!(errors.len(), 1);
+        assert!(errors[0].is_warning());
+        assert!(errors[0].to_string().contains("unknown function ':frobnicate'"));
+    }
+
+    #[test]
+    fn invalid_option_on_number() {
+        let msg = mf2::parse("{$x :number minimumFractionDigits=2 colour=red}").unwrap();
+        let errors = validate(&msg);
+        assert_eq!(errors.len(), 1);
+        assert!(errors[0].is_warning());
+        assert!(errors[0].to_string().contains("option 'colour' is not valid for :number"));
+    }
+
+    #[test]
+    fn invalid_option_in_input_declaration() {
+        let source = ".input {$d :date hour12=true}\n{{{$d}}}";
+        let msg = mf2::parse(source).unwrap();
+        let errors = validate(&msg);
+        assert!(errors.iter().any(|e| e.to_string().contains("option 'hour12'")));
+    }
+
+    #[test]
+    fn custom_function_registry() {
+        let msg = mf2::parse("{$x :money precision=2}").unwrap();
+
+        let mut registry = FunctionRegistry::default();
+        registry.register("money", &["precision"]);
+        assert!(validate_with_registry(&msg, &registry).is_empty());
+
+        let mut registry = FunctionRegistry::default();
+        registry.register_any("money");
+        assert!(validate_with_registry(&msg, &registry).is_empty());
+    }
+
     #[test]
     fn extract_variables_simple() {
         let msg = mf2::parse("Hello {$name}, you have {$count} items").unwrap();
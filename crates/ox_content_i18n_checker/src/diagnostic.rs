@@ -1,4 +1,4 @@
-use ox_content_i18n::checker::Diagnostic;
+use ox_content_i18n::checker::{Diagnostic, Location, Severity};
 use serde::Serialize;
 
 /// Serializable diagnostic for structured output.
@@ -10,19 +10,28 @@ pub struct SerializableDiagnostic {
     pub key: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub locale: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub file: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub line: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub column: Option<u32>,
 }
 
 impl From<&Diagnostic> for SerializableDiagnostic {
     fn from(d: &Diagnostic) -> Self {
         Self {
             severity: match d.severity {
-                ox_content_i18n::checker::Severity::Error => "error".to_string(),
-                ox_content_i18n::checker::Severity::Warning => "warning".to_string(),
-                ox_content_i18n::checker::Severity::Info => "info".to_string(),
+                Severity::Error => "error".to_string(),
+                Severity::Warning => "warning".to_string(),
+                Severity::Info => "info".to_string(),
             },
             message: d.message.clone(),
             key: d.key.clone(),
             locale: d.locale.clone(),
+            file: d.location.as_ref().map(|l| l.file.clone()),
+            line: d.location.as_ref().map(|l| l.line),
+            column: d.location.as_ref().map(|l| l.column),
         }
     }
 }
@@ -32,6 +41,8 @@ impl From<&Diagnostic> for SerializableDiagnostic {
 pub enum OutputFormat {
     Text,
     Json,
+    /// GitHub Actions workflow commands (`::error file=…,line=…,col=…::message`).
+    Github,
 }
 
 /// Formats diagnostics to a string.
@@ -45,5 +56,80 @@ pub fn format_diagnostics(diagnostics: &[Diagnostic], format: OutputFormat) -> S
                 diagnostics.iter().map(SerializableDiagnostic::from).collect();
             serde_json::to_string_pretty(&serializable).unwrap_or_default()
         }
+        OutputFormat::Github => {
+            diagnostics.iter().map(github_annotation).collect::<Vec<_>>().join("\n")
+        }
+    }
+}
+
+/// Formats a diagnostic as a GitHub Actions workflow command.
+fn github_annotation(d: &Diagnostic) -> String {
+    let command = match d.severity {
+        Severity::Error => "error",
+        Severity::Warning => "warning",
+        Severity::Info => "notice",
+    };
+    let properties =
+        d.location.as_ref().map_or_else(String::new, |Location { file, line, column }| {
+            format!(" file={},line={line},col={column}", escape_property(file))
+        });
+    let mut message = d.message.clone();
+    if let Some(key) = &d.key {
+        message.push_str(&format!(" (key: {key})"));
+    }
+    if let Some(locale) = &d.locale {
+        message.push_str(&format!(" (locale: {locale})"));
+    }
+    format!("::{command}{properties}::{}", escape_data(&message))
+}
+
+/// Escapes a workflow command message.
+fn escape_data(value: &str) -> String {
+    value.replace('%', "%25").replace('\r', "%0D").replace('\n', "%0A")
+}
+
+/// Escapes a workflow command property value.
+fn escape_property(value: &str) -> String {
+    escape_data(value).replace(':', "%3A").replace(',', "%2C")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn diagnostic(severity: Severity, message: &str, location: Option<Location>) -> Diagnostic {
+        Diagnostic {
+            severity,
+            message: message.to_string(),
+            key: Some("home.title".to_string()),
+            locale: Some("ja".to_string()),
+            location,
+        }
+    }
+
+    #[test]
+    fn github_format_emits_workflow_commands() {
+        let location = Location { file: "src/app.ts".to_string(), line: 3, column: 14 };
+        let diagnostics = [
+            diagnostic(Severity::Error, "missing translation for key 'home.title'", Some(location)),
+            diagnostic(Severity::Warning, "100% identical, to en", None),
+        ];
+
+        assert_eq!(
+            format_diagnostics(&diagnostics, OutputFormat::Github),
+            "::error file=src/app.ts,line=3,col=14::missing translation for key 'home.title' \
+             (key: home.title) (locale: ja)\n\
+             ::warning::100%25 identical, to en (key: home.title) (locale: ja)"
+        );
+    }
+
+    #[test]
+    fn github_format_escapes_file_property() {
+        let location = Location { file: "C:\\a,b.ts".to_string(), line: 1, column: 1 };
+        let output = format_diagnostics(
+            &[diagnostic(Severity::Info, "note", Some(location))],
+            OutputFormat::Github,
+        );
+        assert!(output.starts_with("::notice file=C%3A\\a%2Cb.ts,line=1,col=1::note"), "{output}");
     }
 }
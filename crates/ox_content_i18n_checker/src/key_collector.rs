@@ -1,5 +1,5 @@
 use oxc_allocator::Allocator;
-use oxc_ast::ast::{Argument, CallExpression, Expression};
+use oxc_ast::ast::{Argument, CallExpression, Expression, ObjectPropertyKind};
 use oxc_ast::visit::walk;
 use oxc_ast::Visit;
 use oxc_parser::Parser;
@@ -14,6 +14,11 @@ pub struct KeyUsage {
     pub line: u32,
     pub column: u32,
     pub end_column: u32,
+    /// Argument names passed in the options object (`t('key', { name })`).
+    ///
+    /// `None` when the arguments can't be determined statically, such as a
+    /// variable, spread or computed property.
+    pub args: Option<Vec<String>>,
 }
 
 /// Extracts translation keys from TS/JS source files by finding `t('key')` calls.
@@ -130,6 +135,7 @@ impl<'a> Visit<'a> for KeyVisitor<'a> {
                         line,
                         column: col,
                         end_column: end_col,
+                        args: call_arguments(call.arguments.get(1)),
                     });
                 }
             }
@@ -140,6 +146,27 @@ impl<'a> Visit<'a> for KeyVisitor<'a> {
     }
 }
 
+/// Returns the property names of a translation call's options argument.
+fn call_arguments(argument: Option<&Argument<'_>>) -> Option<Vec<String>> {
+    let Some(argument) = argument else {
+        return Some(Vec::new());
+    };
+    let Argument::ObjectExpression(object) = argument else {
+        return None;
+    };
+
+    object
+        .properties
+        .iter()
+        .map(|property| match property {
+            ObjectPropertyKind::ObjectProperty(prop) if !prop.computed => {
+                prop.key.static_name().map(std::borrow::Cow::into_owned)
+            }
+            _ => None,
+        })
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -194,6 +221,25 @@ const c = $t('key3');
         assert!(usages.is_empty());
     }
 
+    #[test]
+    fn call_arguments_collected() {
+        let usages = collect(
+            r"
+t('a');
+t('b', { name, count: 2, 'quoted': x });
+t('c', opts);
+t('d', { ...rest });
+",
+        );
+        assert_eq!(usages[0].args, Some(vec![]));
+        assert_eq!(
+            usages[1].args,
+            Some(vec!["name".to_string(), "count".to_string(), "quoted".to_string()])
+        );
+        assert_eq!(usages[2].args, None);
+        assert_eq!(usages[3].args, None);
+    }
+
     #[test]
     fn line_column_tracking() {
         let usages = collect("const a = 1;\nconst b = t('key');");
@@ -21,13 +21,15 @@ pub mod diagnostic;
 pub mod key_collector;
 pub mod md_key_collector;
 
-use std::collections::HashSet;
+use std::collections::{BTreeMap, HashMap, HashSet};
 use std::path::Path;
 
-use ox_content_i18n::checker::{self, Diagnostic};
-use ox_content_i18n::dictionary;
+use ox_content_i18n::checker::{self, Diagnostic, Interpolation, Location, Severity};
+use ox_content_i18n::dictionary::{self, DictionarySet};
+use ox_content_i18n::mf2::registry::FunctionRegistry;
+use ox_content_i18n::KeyStyle;
 
-use key_collector::KeyCollector;
+use key_collector::{KeyCollector, KeyUsage};
 
 /// Configuration for the checker.
 pub struct CheckConfig {
@@ -41,6 +43,24 @@ pub struct CheckConfig {
     pub function_names: Vec<String>,
     /// Default locale.
     pub default_locale: Option<String>,
+    /// Keys whose diagnostics are suppressed. A trailing `*` matches any key with that prefix.
+    pub ignore_keys: Vec<String>,
+    /// Per-rule severity overrides keyed by rule name; `None` turns the rule off.
+    pub severities: BTreeMap<String, Option<Severity>>,
+    /// Style that source and dictionary keys are normalized to before matching.
+    pub key_style: KeyStyle,
+    /// Placeholder syntax dictionary values are expected to use.
+    pub interpolation: Interpolation,
+    /// Report values shared by at least this many keys in a locale (`None` disables the rule).
+    pub duplicate_value_threshold: Option<usize>,
+    /// Report keys whose namespace file or order differs from the default locale.
+    pub structure_drift: bool,
+    /// Report values left identical to the default locale.
+    pub identical_translations: bool,
+    /// Build-time constants filled into `{{name}}` placeholders before checking.
+    pub constants: HashMap<String, String>,
+    /// MF2 functions and options known to the project; others are reported as warnings.
+    pub functions: FunctionRegistry,
 }
 
 impl Default for CheckConfig {
@@ -58,6 +78,15 @@ impl Default for CheckConfig {
             ],
             function_names: vec!["t".to_string(), "$t".to_string()],
             default_locale: Some("en".to_string()),
+            ignore_keys: Vec::new(),
+            severities: BTreeMap::new(),
+            key_style: KeyStyle::AsIs,
+            interpolation: Interpolation::Mf2,
+            duplicate_value_threshold: None,
+            structure_drift: false,
+            identical_translations: false,
+            constants: HashMap::new(),
+            functions: FunctionRegistry::default(),
         }
     }
 }
@@ -66,6 +95,8 @@ impl Default for CheckConfig {
 pub struct CheckResult {
     pub diagnostics: Vec<Diagnostic>,
     pub used_keys: HashSet<String>,
+    /// Number of diagnostics reported by each rule, keyed by rule name.
+    pub counts_by_rule: BTreeMap<&'static str, usize>,
     pub error_count: usize,
     pub warning_count: usize,
 }
@@ -82,6 +113,9 @@ pub fn check(config: &CheckConfig) -> Result<CheckResult, String> {
             dict_set.set_default_locale(locale);
         }
     }
+    dict_set.set_key_style(config.key_style);
+    // Placeholders no constant fills are left for the interpolation-syntax rule.
+    dict_set.interpolate(&config.constants);
 
     // Collect keys from source files
     let collector = if config.function_names.is_empty() {
@@ -91,27 +125,213 @@ pub fn check(config: &CheckConfig) -> Result<CheckResult, String> {
     };
 
     let mut used_keys = HashSet::new();
+    let mut calls = Vec::new();
+    let mut key_locations = HashMap::new();
 
     for src_dir in &config.src_dirs {
-        collect_keys_recursive(Path::new(src_dir), &collector, &config.extensions, &mut used_keys)?;
+        for usage in collect_key_usages(Path::new(src_dir), &collector, &config.extensions)? {
+            if let Some(args) = usage.args {
+                calls.push((usage.key.clone(), args));
+            }
+            key_locations.entry(usage.key.clone()).or_insert_with(|| Location {
+                file: usage.file_path,
+                line: usage.line,
+                column: usage.column,
+            });
+            used_keys.insert(usage.key);
+        }
     }
 
-    // Run all checks
-    let diagnostics = checker::check_all(&used_keys, &dict_set);
+    Ok(run_rules(used_keys, &calls, &key_locations, &dict_set, config))
+}
 
-    let error_count = diagnostics.iter().filter(|d| d.severity == checker::Severity::Error).count();
-    let warning_count =
-        diagnostics.iter().filter(|d| d.severity == checker::Severity::Warning).count();
+/// Runs all rules against keys that were already collected from source.
+pub fn check_keys(used_keys: HashSet<String>, dict_set: &DictionarySet) -> CheckResult {
+    run_rules(used_keys, &[], &HashMap::new(), dict_set, &CheckConfig::default())
+}
 
-    Ok(CheckResult { diagnostics, used_keys, error_count, warning_count })
+/// Runs all rules with the options in `config`, applying key ignores and
+/// per-rule severity overrides.
+///
+/// `calls` pairs each statically known call site's key with its argument names,
+/// and `key_locations` maps each used key to its first call site. The
+/// MF2-only rules (`syntax-error`, `plural-category`, `call-arguments`) are
+/// skipped in i18next projects.
+fn run_rules(
+    used_keys: HashSet<String>,
+    calls: &[(String, Vec<String>)],
+    key_locations: &HashMap<String, Location>,
+    dict_set: &DictionarySet,
+    config: &CheckConfig,
+) -> CheckResult {
+    let mf2 = config.interpolation == Interpolation::Mf2;
+    let interpolation_syntax = checker::check_interpolation_syntax(dict_set, config.interpolation);
+    let mut syntax_errors = if mf2 {
+        checker::check_syntax_errors_with_registry(dict_set, &config.functions)
+    } else {
+        Vec::new()
+    };
+    // An i18next placeholder also fails to parse as MF2; report it once, with the fix.
+    syntax_errors
+        .retain(|d| !interpolation_syntax.iter().any(|i| i.key == d.key && i.locale == d.locale));
+
+    // Ignored keys count as used, so `ignore_keys` can also silence orphaned-namespace.
+    let mut used_or_ignored = used_keys.clone();
+    for dict in dict_set.locales().filter_map(|locale| dict_set.get(locale)) {
+        used_or_ignored.extend(
+            dict.keys().filter(|key| is_ignored(key, &config.ignore_keys)).map(str::to_string),
+        );
+    }
+    let orphaned_namespaces = checker::check_orphaned_namespaces(&used_or_ignored, dict_set);
+    let mut unused_keys = checker::check_unused_keys(&used_keys, dict_set);
+    // A dead namespace file is reported once, not once per key, unless its rule is off.
+    if !matches!(config.severities.get("orphaned-namespace"), Some(None)) {
+        checker::suppress_orphaned_unused_keys(&mut unused_keys, &orphaned_namespaces, dict_set);
+    }
+
+    let rules = [
+        ("missing-key", checker::check_missing_keys(&used_keys, dict_set)),
+        ("unused-key", unused_keys),
+        ("orphaned-namespace", orphaned_namespaces),
+        ("type-mismatch", checker::check_type_mismatch(dict_set)),
+        ("syntax-error", syntax_errors),
+        ("interpolation-syntax", interpolation_syntax),
+        (
+            "plural-category",
+            if mf2 { checker::check_plural_categories(dict_set) } else { Vec::new() },
+        ),
+        (
+            "identical-translation",
+            if config.identical_translations {
+                checker::check_identical_translations(dict_set)
+            } else {
+                Vec::new()
+            },
+        ),
+        (
+            "call-arguments",
+            if mf2 { checker::check_call_arguments(calls, dict_set) } else { Vec::new() },
+        ),
+        (
+            "duplicate-value",
+            config
+                .duplicate_value_threshold
+                .map_or_else(Vec::new, |n| checker::check_duplicate_values(dict_set, n)),
+        ),
+        (
+            "structure-drift",
+            if config.structure_drift {
+                checker::check_structure_drift(dict_set)
+            } else {
+                Vec::new()
+            },
+        ),
+    ];
+    debug_assert!(rules.iter().map(|(rule, _)| *rule).eq(RULES));
+
+    let mut diagnostics = Vec::new();
+    let mut counts_by_rule = BTreeMap::new();
+    for (rule, mut rule_diagnostics) in rules {
+        let severity = match config.severities.get(rule) {
+            Some(None) => continue,
+            Some(Some(severity)) => Some(*severity),
+            None => None,
+        };
+        rule_diagnostics
+            .retain(|d| !d.key.as_deref().is_some_and(|key| is_ignored(key, &config.ignore_keys)));
+        for diagnostic in &mut rule_diagnostics {
+            if let Some(severity) = severity {
+                diagnostic.severity = severity;
+            }
+            if diagnostic.location.is_none() {
+                diagnostic.location = locate(rule, diagnostic, dict_set, key_locations);
+            }
+        }
+        checker::sort_and_dedup(&mut rule_diagnostics);
+        counts_by_rule.insert(rule, rule_diagnostics.len());
+        diagnostics.extend(rule_diagnostics);
+    }
+    checker::sort_and_dedup(&mut diagnostics);
+
+    let error_count = diagnostics.iter().filter(|d| d.severity == Severity::Error).count();
+    let warning_count = diagnostics.iter().filter(|d| d.severity == Severity::Warning).count();
+
+    CheckResult { diagnostics, used_keys, counts_by_rule, error_count, warning_count }
+}
+
+/// Names of every rule `run_rules` reports, as used in severity overrides.
+pub const RULES: [&str; 11] = [
+    "missing-key",
+    "unused-key",
+    "orphaned-namespace",
+    "type-mismatch",
+    "syntax-error",
+    "interpolation-syntax",
+    "plural-category",
+    "identical-translation",
+    "call-arguments",
+    "duplicate-value",
+    "structure-drift",
+];
+
+/// Rules whose diagnostics are about call sites rather than dictionary entries.
+const SOURCE_RULES: [&str; 2] = ["missing-key", "call-arguments"];
+
+/// Finds where a diagnostic should point: the key's first call site for
+/// source rules, otherwise its dictionary entry (falling back to the call site).
+fn locate(
+    rule: &str,
+    diagnostic: &Diagnostic,
+    dict_set: &DictionarySet,
+    key_locations: &HashMap<String, Location>,
+) -> Option<Location> {
+    let key = diagnostic.key.as_deref()?;
+    let call_site = || key_locations.get(key).cloned();
+    if SOURCE_RULES.contains(&rule) {
+        return call_site();
+    }
+    diagnostic
+        .locale
+        .as_deref()
+        .and_then(|locale| dict_set.get(locale))
+        .and_then(|dict| dict.source(key))
+        .map(|source| Location {
+            file: source.path.to_string_lossy().to_string(),
+            line: u32::try_from(source.line).unwrap_or(u32::MAX),
+            column: 1,
+        })
+        .or_else(call_site)
+}
+
+/// Returns true if `key` matches one of the ignore patterns.
+fn is_ignored(key: &str, ignore_keys: &[String]) -> bool {
+    ignore_keys.iter().any(|pattern| {
+        pattern.strip_suffix('*').map_or(pattern == key, |prefix| key.starts_with(prefix))
+    })
+}
+
+/// Collects every translation key usage, with its position, from files under `dir`.
+///
+/// TS/JS files are parsed with `collector`; `.md`/`.mdx` files are scanned for
+/// `{{t('key')}}` patterns. Hidden directories, `node_modules` and files that
+/// fail to parse are skipped. Usages are sorted by file, line and column.
+pub fn collect_key_usages(
+    dir: &Path,
+    collector: &KeyCollector,
+    extensions: &[String],
+) -> Result<Vec<KeyUsage>, String> {
+    let mut usages = Vec::new();
+    collect_usages_recursive(dir, collector, extensions, &mut usages)?;
+    usages.sort_by(|a, b| (&a.file_path, a.line, a.column).cmp(&(&b.file_path, b.line, b.column)));
+    Ok(usages)
 }
 
-/// Recursively collects translation keys from files in a directory.
-fn collect_keys_recursive(
+/// Recursively collects translation key usages from files in a directory.
+fn collect_usages_recursive(
     dir: &Path,
     collector: &KeyCollector,
     extensions: &[String],
-    keys: &mut HashSet<String>,
+    usages: &mut Vec<KeyUsage>,
 ) -> Result<(), String> {
     if !dir.exists() {
         return Ok(());
@@ -130,7 +350,7 @@ fn collect_keys_recursive(
             if dir_name.starts_with('.') || dir_name == "node_modules" {
                 continue;
             }
-            collect_keys_recursive(&path, collector, extensions, keys)?;
+            collect_usages_recursive(&path, collector, extensions, usages)?;
         } else if path.is_file() {
             let ext = path.extension().and_then(|e| e.to_str()).unwrap_or("");
 
@@ -142,20 +362,22 @@ fn collect_keys_recursive(
             if ext == "md" || ext == "mdx" {
                 if let Ok(content) = std::fs::read_to_string(&path) {
                     let file_path = path.to_string_lossy().to_string();
-                    let usages = md_key_collector::collect_md_keys(&content, &file_path);
-                    for usage in usages {
-                        keys.insert(usage.key);
-                    }
-                }
-            } else {
-                // TS/JS files: use OXC key collector
-                if let Ok(usages) = collector.collect_file(&path) {
-                    for usage in usages {
-                        keys.insert(usage.key);
-                    }
-                } else {
-                    // Skip files that fail to parse
+                    usages.extend(
+                        md_key_collector::collect_md_keys(&content, &file_path).into_iter().map(
+                            |usage| KeyUsage {
+                                key: usage.key,
+                                file_path: usage.file_path,
+                                line: usage.line,
+                                column: usage.column,
+                                end_column: usage.end_column,
+                                args: None,
+                            },
+                        ),
+                    );
                 }
+            } else if let Ok(file_usages) = collector.collect_file(&path) {
+                // TS/JS files: use OXC key collector; files that fail to parse are skipped
+                usages.extend(file_usages);
             }
         }
     }
@@ -5,6 +5,8 @@ pub struct MdKeyUsage {
     pub file_path: String,
     pub line: u32,
     pub column: u32,
+    /// Column just past the closing `}}` (on the line the usage ends on).
+    pub end_column: u32,
 }
 
 /// Collects translation keys from Markdown files.
@@ -56,6 +58,7 @@ pub fn collect_md_keys(source: &str, file_path: &str) -> Vec<MdKeyUsage> {
                         file_path: file_path.to_string(),
                         line: start_line,
                         column: start_col,
+                        end_column: col + 2,
                     });
                 }
 
@@ -101,6 +104,7 @@ mod tests {
         assert_eq!(usages.len(), 1);
         assert_eq!(usages[0].key, "common.greeting");
         assert_eq!(usages[0].line, 3);
+        assert_eq!((usages[0].column, usages[0].end_column), (1, 25));
     }
 
     #[test]
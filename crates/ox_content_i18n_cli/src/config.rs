@@ -0,0 +1,225 @@
+//! `ox-i18n.toml` configuration file support.
+
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use ox_content_i18n::checker::{Interpolation, Severity};
+use ox_content_i18n::KeyStyle;
+use ox_content_i18n_checker::{CheckConfig, RULES};
+use serde::Deserialize;
+
+/// Config file looked up in the working directory when `--config` is not given.
+pub const DEFAULT_CONFIG_FILE: &str = "ox-i18n.toml";
+
+/// Settings read from an `ox-i18n.toml` file. Every field is optional.
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct FileConfig {
+    pub dict_dir: Option<String>,
+    pub src_dirs: Option<Vec<String>>,
+    pub extensions: Option<Vec<String>>,
+    pub function_names: Option<Vec<String>>,
+    pub default_locale: Option<String>,
+    pub ignore_keys: Option<Vec<String>>,
+    /// `"as-is"`, `"camelCase"`, `"snake_case"` or `"kebab-case"`.
+    pub key_style: Option<String>,
+    /// `"mf2"` or `"i18next"`.
+    pub interpolation: Option<String>,
+    /// Minimum number of keys sharing a value before `duplicate-value` reports it.
+    pub duplicate_value_threshold: Option<usize>,
+    /// Report keys whose namespace file or order differs from the default locale.
+    pub structure_drift: Option<bool>,
+    /// Report values left identical to the default locale.
+    pub identical_translations: Option<bool>,
+    /// Rule name to `"error"`, `"warning"`, `"info"` or `"off"`.
+    pub severities: BTreeMap<String, String>,
+    /// Build-time constants filled into `{{name}}` placeholders before checking.
+    pub constants: BTreeMap<String, String>,
+    /// Custom MF2 functions and the options each accepts; `["*"]` accepts any option.
+    pub functions: BTreeMap<String, Vec<String>>,
+}
+
+/// `check` options given on the command line. `None`/empty means "not given".
+#[derive(Debug, Default)]
+pub struct CheckFlags {
+    pub dict_dir: Option<String>,
+    pub src_dirs: Vec<String>,
+    pub default_locale: Option<String>,
+}
+
+impl FileConfig {
+    /// Reads and parses a config file.
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let source = std::fs::read_to_string(path)
+            .map_err(|e| format!("failed to read {}: {e}", path.display()))?;
+        toml::from_str(&source).map_err(|e| format!("invalid config {}: {e}", path.display()))
+    }
+}
+
+/// Builds the check configuration: defaults, then file values, then command-line flags.
+pub fn resolve(file: Option<FileConfig>, flags: CheckFlags) -> Result<CheckConfig, String> {
+    let mut config = CheckConfig::default();
+
+    if let Some(file) = file {
+        if let Some(dict_dir) = file.dict_dir {
+            config.dict_dir = dict_dir;
+        }
+        if let Some(src_dirs) = file.src_dirs {
+            config.src_dirs = src_dirs;
+        }
+        if let Some(extensions) = file.extensions {
+            config.extensions = extensions;
+        }
+        if let Some(function_names) = file.function_names {
+            config.function_names = function_names;
+        }
+        if let Some(default_locale) = file.default_locale {
+            config.default_locale = Some(default_locale);
+        }
+        if let Some(ignore_keys) = file.ignore_keys {
+            config.ignore_keys = ignore_keys;
+        }
+        if let Some(key_style) = file.key_style {
+            config.key_style = KeyStyle::parse(&key_style)
+                .ok_or_else(|| format!("invalid key style '{key_style}'"))?;
+        }
+        if let Some(interpolation) = file.interpolation {
+            config.interpolation = Interpolation::parse(&interpolation)
+                .ok_or_else(|| format!("invalid interpolation '{interpolation}'"))?;
+        }
+        if let Some(threshold) = file.duplicate_value_threshold {
+            config.duplicate_value_threshold = Some(threshold);
+        }
+        if let Some(structure_drift) = file.structure_drift {
+            config.structure_drift = structure_drift;
+        }
+        if let Some(identical_translations) = file.identical_translations {
+            config.identical_translations = identical_translations;
+        }
+        config.constants.extend(file.constants);
+        for (name, options) in file.functions {
+            if options.iter().any(|option| option == "*") {
+                config.functions.register_any(&name);
+            } else {
+                let options: Vec<&str> = options.iter().map(String::as_str).collect();
+                config.functions.register(&name, &options);
+            }
+        }
+        for (rule, level) in file.severities {
+            if !RULES.contains(&rule.as_str()) {
+                return Err(format!("unknown rule '{rule}' in [severities]"));
+            }
+            let severity = parse_severity(&level)
+                .map_err(|()| format!("invalid severity '{level}' for rule '{rule}'"))?;
+            config.severities.insert(rule, severity);
+        }
+    }
+
+    if let Some(dict_dir) = flags.dict_dir {
+        config.dict_dir = dict_dir;
+    }
+    if !flags.src_dirs.is_empty() {
+        config.src_dirs = flags.src_dirs;
+    }
+    if let Some(default_locale) = flags.default_locale {
+        config.default_locale = Some(default_locale);
+    }
+
+    Ok(config)
+}
+
+/// Parses a severity level; `Ok(None)` means the rule is turned off.
+fn parse_severity(level: &str) -> Result<Option<Severity>, ()> {
+    match level {
+        "error" => Ok(Some(Severity::Error)),
+        "warning" | "warn" => Ok(Some(Severity::Warning)),
+        "info" => Ok(Some(Severity::Info)),
+        "off" => Ok(None),
+        _ => Err(()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn file_settings_applied_and_flags_override() {
+        let dir = std::env::temp_dir().join(format!("ox-i18n-config-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join(DEFAULT_CONFIG_FILE);
+        std::fs::write(
+            &path,
+            r#"
+dict_dir = "locales"
+src_dirs = ["app", "lib"]
+function_names = ["translate"]
+default_locale = "ja"
+ignore_keys = ["legacy.*"]
+key_style = "snake_case"
+interpolation = "i18next"
+duplicate_value_threshold = 3
+structure_drift = true
+identical_translations = true
+
+[severities]
+unused-key = "off"
+type-mismatch = "error"
+
+[constants]
+siteUrl = "https://example.com"
+
+[functions]
+money = ["precision"]
+icon = ["*"]
+"#,
+        )
+        .unwrap();
+
+        let file = FileConfig::load(&path).unwrap();
+        std::fs::remove_dir_all(&dir).unwrap();
+        let flags = CheckFlags { dict_dir: Some("i18n".to_string()), ..CheckFlags::default() };
+        let config = resolve(Some(file), flags).unwrap();
+
+        assert_eq!(config.dict_dir, "i18n");
+        assert_eq!(config.src_dirs, vec!["app", "lib"]);
+        assert_eq!(config.function_names, vec!["translate"]);
+        assert_eq!(config.default_locale.as_deref(), Some("ja"));
+        assert_eq!(config.ignore_keys, vec!["legacy.*"]);
+        assert_eq!(config.key_style, KeyStyle::SnakeCase);
+        assert_eq!(config.interpolation, Interpolation::I18next);
+        assert_eq!(config.duplicate_value_threshold, Some(3));
+        assert!(config.structure_drift);
+        assert!(config.identical_translations);
+        assert_eq!(
+            config.constants.get("siteUrl").map(String::as_str),
+            Some("https://example.com")
+        );
+        assert_eq!(config.severities.get("unused-key"), Some(&None));
+        assert_eq!(config.severities.get("type-mismatch"), Some(&Some(Severity::Error)));
+        assert!(config.functions.accepts_option("money", "precision"));
+        assert!(!config.functions.accepts_option("money", "currency"));
+        assert!(config.functions.accepts_option("icon", "size"));
+        assert!(config.functions.contains("number"));
+        assert_eq!(config.extensions, CheckConfig::default().extensions);
+    }
+
+    #[test]
+    fn invalid_severity_is_rejected() {
+        let file = FileConfig {
+            severities: BTreeMap::from([("unused-key".to_string(), "loud".to_string())]),
+            ..FileConfig::default()
+        };
+        assert!(resolve(Some(file), CheckFlags::default()).is_err());
+    }
+
+    #[test]
+    fn unknown_severity_rule_is_rejected() {
+        let file = FileConfig {
+            severities: BTreeMap::from([("unused-keys".to_string(), "off".to_string())]),
+            ..FileConfig::default()
+        };
+        let error = resolve(Some(file), CheckFlags::default()).unwrap_err();
+        assert!(error.contains("unknown rule 'unused-keys'"));
+    }
+}
@@ -1,5 +1,25 @@
+mod config;
+
+use std::path::Path;
+
 use clap::{Parser, Subcommand, ValueEnum};
 use ox_content_i18n_checker::diagnostic::{format_diagnostics, OutputFormat};
+use ox_content_i18n_checker::CheckConfig;
+
+/// Process exit codes returned by the CLI.
+///
+/// | Code | Meaning                                       |
+/// |------|-----------------------------------------------|
+/// | `0`  | Success                                       |
+/// | `1`  | One or more errors were reported              |
+/// | `2`  | Warning count exceeded `--max-warnings`       |
+/// | `3`  | Configuration or dictionary load failure      |
+mod exit_code {
+    pub const OK: i32 = 0;
+    pub const ERRORS: i32 = 1;
+    pub const WARNING_THRESHOLD: i32 = 2;
+    pub const LOAD_FAILURE: i32 = 3;
+}
 
 #[derive(Parser)]
 #[command(name = "ox-content-i18n", about = "i18n tools for Ox Content")]
@@ -11,27 +31,42 @@ struct Cli {
 #[derive(Subcommand)]
 enum Commands {
     /// Run i18n checks on the project.
+    ///
+    /// Settings are read from `ox-i18n.toml` when present; flags override them.
     Check {
-        /// Path to the i18n dictionary directory.
-        #[arg(long, default_value = "content/i18n")]
-        dict_dir: String,
+        /// Config file to read instead of `ox-i18n.toml`.
+        #[arg(long, value_name = "PATH")]
+        config: Option<String>,
+
+        /// Path to the i18n dictionary directory [default: content/i18n].
+        #[arg(long)]
+        dict_dir: Option<String>,
 
-        /// Source directories to scan (can be specified multiple times).
-        #[arg(long, default_value = "src")]
+        /// Source directories to scan (can be specified multiple times) [default: src].
+        #[arg(long)]
         src: Vec<String>,
 
         /// Output format.
         #[arg(long, value_enum, default_value_t = Format::Text)]
         format: Format,
 
-        /// Default locale.
-        #[arg(long, default_value = "en")]
-        default_locale: String,
+        /// Default locale [default: en].
+        #[arg(long)]
+        default_locale: Option<String>,
+
+        /// Fail with exit code 2 when more than N warnings are reported.
+        #[arg(long, value_name = "N")]
+        max_warnings: Option<usize>,
     },
-    /// Validate an MF2 message string.
+    /// Validate an MF2 message string, or every message in a dictionary directory.
     Validate {
         /// The MF2 message to validate.
-        message: String,
+        #[arg(required_unless_present = "dict_dir")]
+        message: Option<String>,
+
+        /// Validate every value of every locale in this dictionary directory instead.
+        #[arg(long, conflicts_with_all = ["message", "ast"])]
+        dict_dir: Option<String>,
 
         /// Also print the AST as JSON.
         #[arg(long)]
@@ -43,60 +78,55 @@ enum Commands {
 enum Format {
     Text,
     Json,
+    /// GitHub Actions annotations.
+    Github,
 }
 
 fn main() {
     let cli = Cli::parse();
 
     match cli.command {
-        Commands::Check { dict_dir, src, format, default_locale } => {
-            let config = ox_content_i18n_checker::CheckConfig {
-                dict_dir,
-                src_dirs: src,
-                default_locale: Some(default_locale),
-                ..Default::default()
-            };
-
-            match ox_content_i18n_checker::check(&config) {
-                Ok(result) => {
-                    let output_format = match format {
-                        Format::Text => OutputFormat::Text,
-                        Format::Json => OutputFormat::Json,
-                    };
-                    let output = format_diagnostics(&result.diagnostics, output_format);
-                    if !output.is_empty() {
-                        #[allow(clippy::print_stdout)]
-                        {
-                            println!("{output}");
-                        }
-                    }
-
-                    #[allow(clippy::print_stdout)]
-                    {
-                        println!(
-                            "\n{} error(s), {} warning(s), {} key(s) used",
-                            result.error_count,
-                            result.warning_count,
-                            result.used_keys.len()
-                        );
-                    }
-
-                    if result.error_count > 0 {
-                        std::process::exit(1);
-                    }
-                }
+        Commands::Check {
+            config: config_path,
+            dict_dir,
+            src,
+            format,
+            default_locale,
+            max_warnings,
+        } => {
+            let flags = config::CheckFlags { dict_dir, src_dirs: src, default_locale };
+            let config = match load_check_config(config_path.as_deref(), flags) {
+                Ok(config) => config,
                 Err(e) => {
                     #[allow(clippy::print_stderr)]
                     {
                         eprintln!("Error: {e}");
                     }
-                    std::process::exit(1);
+                    std::process::exit(exit_code::LOAD_FAILURE);
                 }
+            };
+            let output_format = match format {
+                Format::Text => OutputFormat::Text,
+                Format::Json => OutputFormat::Json,
+                Format::Github => OutputFormat::Github,
+            };
+
+            let code = run_check(&config, output_format, max_warnings);
+            if code != exit_code::OK {
+                std::process::exit(code);
             }
         }
-        Commands::Validate { message, ast } => {
-            match ox_content_i18n::mf2::parse_and_validate(&message) {
-                Ok((parsed_ast, errors)) => {
+        Commands::Validate { dict_dir: Some(dict_dir), .. } => {
+            let code = run_validate_dir(Path::new(&dict_dir));
+            if code != exit_code::OK {
+                std::process::exit(code);
+            }
+        }
+        Commands::Validate { message, ast, .. } => {
+            let message = message.unwrap_or_default();
+            match ox_content_i18n::mf2::parse_all(&message) {
+                (Some(parsed_ast), _) => {
+                    let errors = ox_content_i18n::mf2::validator::validate(&parsed_ast);
                     if errors.is_empty() {
                         #[allow(clippy::print_stdout)]
                         {
@@ -123,10 +153,12 @@ fn main() {
                         }
                     }
                 }
-                Err(e) => {
-                    #[allow(clippy::print_stderr)]
-                    {
-                        eprintln!("Parse error: {e}");
+                (None, parse_errors) => {
+                    for e in &parse_errors {
+                        #[allow(clippy::print_stderr)]
+                        {
+                            eprintln!("{}", e.render_with_source(&message));
+                        }
                     }
                     std::process::exit(1);
                 }
@@ -134,3 +166,207 @@ fn main() {
         }
     }
 }
+
+/// Resolves the `check` configuration from the config file (explicit or
+/// `ox-i18n.toml` in the working directory) and command-line flags.
+fn load_check_config(path: Option<&str>, flags: config::CheckFlags) -> Result<CheckConfig, String> {
+    let file = match path {
+        Some(path) => Some(config::FileConfig::load(Path::new(path))?),
+        None => {
+            let default_path = Path::new(config::DEFAULT_CONFIG_FILE);
+            if default_path.is_file() {
+                Some(config::FileConfig::load(default_path)?)
+            } else {
+                None
+            }
+        }
+    };
+    config::resolve(file, flags)
+}
+
+/// Runs the `check` command and returns the process exit code.
+fn run_check(config: &CheckConfig, format: OutputFormat, max_warnings: Option<usize>) -> i32 {
+    match ox_content_i18n_checker::check(config) {
+        Ok(result) => {
+            let output = format_diagnostics(&result.diagnostics, format);
+            if !output.is_empty() {
+                #[allow(clippy::print_stdout)]
+                {
+                    println!("{output}");
+                }
+            }
+
+            #[allow(clippy::print_stdout)]
+            {
+                println!(
+                    "\n{} error(s), {} warning(s), {} key(s) used",
+                    result.error_count,
+                    result.warning_count,
+                    result.used_keys.len()
+                );
+            }
+
+            let code = check_exit_code(result.error_count, result.warning_count, max_warnings);
+            if code == exit_code::WARNING_THRESHOLD {
+                #[allow(clippy::print_stderr)]
+                {
+                    eprintln!(
+                        "Too many warnings ({}, max {}).",
+                        result.warning_count,
+                        max_warnings.unwrap_or_default()
+                    );
+                }
+            }
+            code
+        }
+        Err(e) => {
+            #[allow(clippy::print_stderr)]
+            {
+                eprintln!("Error: {e}");
+            }
+            exit_code::LOAD_FAILURE
+        }
+    }
+}
+
+/// Runs `validate --dict-dir` and returns the process exit code.
+fn run_validate_dir(dict_dir: &Path) -> i32 {
+    match validate_dir(dict_dir) {
+        Ok(report) => {
+            for problem in report.errors.iter().chain(&report.warnings) {
+                #[allow(clippy::print_stdout)]
+                {
+                    println!("{problem}");
+                }
+            }
+
+            #[allow(clippy::print_stdout)]
+            {
+                println!(
+                    "\n{} error(s), {} warning(s) in {} message(s)",
+                    report.errors.len(),
+                    report.warnings.len(),
+                    report.message_count
+                );
+            }
+
+            if report.errors.is_empty() {
+                exit_code::OK
+            } else {
+                exit_code::ERRORS
+            }
+        }
+        Err(e) => {
+            #[allow(clippy::print_stderr)]
+            {
+                eprintln!("Error: {e}");
+            }
+            exit_code::LOAD_FAILURE
+        }
+    }
+}
+
+/// Problems found by `validate --dict-dir`, each formatted as
+/// `file:line: locale key: problem` and sorted by locale and key.
+struct DirReport {
+    errors: Vec<String>,
+    /// Findings such as unknown functions, which do not fail the run.
+    warnings: Vec<String>,
+    message_count: usize,
+}
+
+/// Parses and validates every dictionary value under `dict_dir`.
+fn validate_dir(dict_dir: &Path) -> ox_content_i18n::I18nResult<DirReport> {
+    let dict_set = ox_content_i18n::dictionary::load_from_dir(dict_dir)?;
+    let mut report = DirReport { errors: Vec::new(), warnings: Vec::new(), message_count: 0 };
+
+    let mut locales: Vec<&str> = dict_set.locales().collect();
+    locales.sort_unstable();
+
+    for locale in locales {
+        let Some(dict) = dict_set.get(locale) else {
+            continue;
+        };
+        let mut entries: Vec<(&str, &str)> = dict.iter().collect();
+        entries.sort_unstable();
+
+        for (key, value) in entries {
+            report.message_count += 1;
+            let location = dict.source(key).map_or_else(
+                || format!("{}/{locale}", dict_dir.display()),
+                |source| format!("{}:{}", source.path.display(), source.line),
+            );
+            let problems = match ox_content_i18n::mf2::parse_all(value) {
+                (Some(message), _) => ox_content_i18n::mf2::validator::validate(&message),
+                (None, parse_errors) => parse_errors,
+            };
+            for problem in problems {
+                let list =
+                    if problem.is_warning() { &mut report.warnings } else { &mut report.errors };
+                list.push(format!("{location}: {locale} {key}: {problem}"));
+            }
+        }
+    }
+
+    Ok(report)
+}
+
+/// Maps diagnostic counts to an exit code. Errors take precedence over the warning budget.
+const fn check_exit_code(
+    error_count: usize,
+    warning_count: usize,
+    max_warnings: Option<usize>,
+) -> i32 {
+    if error_count > 0 {
+        return exit_code::ERRORS;
+    }
+    match max_warnings {
+        Some(max) if warning_count > max => exit_code::WARNING_THRESHOLD,
+        _ => exit_code::OK,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn exit_code_ok_without_diagnostics() {
+        assert_eq!(check_exit_code(0, 0, None), exit_code::OK);
+        assert_eq!(check_exit_code(0, 0, Some(0)), exit_code::OK);
+    }
+
+    #[test]
+    fn exit_code_ok_with_warnings_within_budget() {
+        assert_eq!(check_exit_code(0, 5, None), exit_code::OK);
+        assert_eq!(check_exit_code(0, 5, Some(5)), exit_code::OK);
+    }
+
+    #[test]
+    fn exit_code_errors() {
+        assert_eq!(check_exit_code(1, 0, None), exit_code::ERRORS);
+        // Errors win over an exceeded warning budget.
+        assert_eq!(check_exit_code(2, 10, Some(0)), exit_code::ERRORS);
+    }
+
+    #[test]
+    fn exit_code_warning_threshold_exceeded() {
+        assert_eq!(check_exit_code(0, 1, Some(0)), exit_code::WARNING_THRESHOLD);
+        assert_eq!(check_exit_code(0, 6, Some(5)), exit_code::WARNING_THRESHOLD);
+    }
+
+    #[test]
+    fn validate_dir_load_failure() {
+        assert_eq!(run_validate_dir(Path::new("does/not/exist/i18n")), exit_code::LOAD_FAILURE);
+    }
+
+    #[test]
+    fn exit_code_load_failure() {
+        let config = CheckConfig {
+            dict_dir: "does/not/exist/i18n".to_string(),
+            src_dirs: vec![],
+            ..Default::default()
+        };
+        assert_eq!(run_check(&config, OutputFormat::Text, None), exit_code::LOAD_FAILURE);
+    }
+}
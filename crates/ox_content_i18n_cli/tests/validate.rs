@@ -0,0 +1,37 @@
+use std::path::Path;
+use std::process::Command;
+
+fn run(args: &[&str]) -> (i32, String) {
+    let output = Command::new(env!("CARGO_BIN_EXE_ox-content-i18n")).args(args).output().unwrap();
+    (output.status.code().unwrap_or(-1), String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+#[test]
+fn validate_dict_dir_reports_bad_value() {
+    let fixture = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/validate");
+    let (code, stdout) = run(&["validate", "--dict-dir", fixture.to_str().unwrap()]);
+
+    assert_eq!(code, 1, "{stdout}");
+    let expected = format!("{}:3: ja common.items:", fixture.join("ja/common.json").display());
+    assert!(stdout.contains(&expected), "{stdout}");
+    assert_eq!(stdout.matches(" common.").count(), 1, "{stdout}");
+    assert!(stdout.contains("1 error(s), 0 warning(s) in 4 message(s)"), "{stdout}");
+}
+
+#[test]
+fn validate_dict_dir_warnings_do_not_fail() {
+    let fixture = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/validate-warnings");
+    let (code, stdout) = run(&["validate", "--dict-dir", fixture.to_str().unwrap()]);
+
+    assert_eq!(code, 0, "{stdout}");
+    assert!(stdout.contains("unknown function ':money'"), "{stdout}");
+    assert!(stdout.contains("0 error(s), 1 warning(s) in 2 message(s)"), "{stdout}");
+}
+
+#[test]
+fn validate_single_message() {
+    let (code, stdout) = run(&["validate", "Hello {$name}"]);
+
+    assert_eq!(code, 0);
+    assert!(stdout.contains("Valid MF2 message."), "{stdout}");
+}
@@ -25,7 +25,7 @@ impl Backend {
                 "ts" | "tsx" | "js" | "jsx" => {
                     self.state.update_file_keys(&path_str, text).await;
                 }
-                "json" | "yaml" | "yml" => {
+                "json" | "jsonc" | "json5" | "yaml" | "yml" => {
                     // Dictionary file changed — reload dictionaries
                     self.state.reload_dictionaries().await;
                 }
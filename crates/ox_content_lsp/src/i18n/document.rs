@@ -18,7 +18,10 @@ pub fn is_i18n_dictionary_path(path: &Path) -> bool {
         path_str.contains(DICT_SEGMENT_POSIX) || path_str.contains(DICT_SEGMENT_WINDOWS);
 
     in_dict_dir
-        && matches!(path.extension().and_then(|ext| ext.to_str()), Some("json" | "yaml" | "yml"))
+        && matches!(
+            path.extension().and_then(|ext| ext.to_str()),
+            Some("json" | "jsonc" | "json5" | "yaml" | "yml")
+        )
 }
 
 pub fn key_at_position(usages: &[KeyUsage], position: Position) -> Option<String> {
@@ -136,7 +136,7 @@ impl I18nState {
         let namespace = key.split('.').next().unwrap_or(key);
 
         for locale in &locales {
-            for ext in ["json", "yaml", "yml"] {
+            for ext in ["json", "jsonc", "json5", "yaml", "yml"] {
                 let candidate = dict_dir.join(locale).join(format!("{namespace}.{ext}"));
                 if candidate.exists() {
                     return Some(candidate.to_string_lossy().to_string());
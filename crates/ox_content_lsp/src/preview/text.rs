@@ -30,6 +30,7 @@ fn collect_text(node: &Node<'_>, text: &mut String) {
     match node {
         Node::Text(value) => text.push_str(value.value),
         Node::InlineCode(value) => text.push_str(value.value),
+        Node::Break(_) => text.push(' '),
         Node::Emphasis(value) => {
             for child in &value.children {
                 collect_text(child, text);
@@ -45,6 +46,21 @@ fn collect_text(node: &Node<'_>, text: &mut String) {
                 collect_text(child, text);
             }
         }
+        Node::Mark(value) => {
+            for child in &value.children {
+                collect_text(child, text);
+            }
+        }
+        Node::Subscript(value) => {
+            for child in &value.children {
+                collect_text(child, text);
+            }
+        }
+        Node::Superscript(value) => {
+            for child in &value.children {
+                collect_text(child, text);
+            }
+        }
         Node::Link(value) => {
             for child in &value.children {
                 collect_text(child, text);
@@ -15,6 +15,14 @@ export declare function buildSearchIndex(documents: Array<JsSearchDocument>): st
  */
 export declare function checkI18n(dictDir: string, usedKeys: Array<string>): I18NCheckResult
 
+/**
+ * Evaluates the `.local` declarations of an MF2 message for previews.
+ *
+ * `args` supplies input variables. Locals are resolved in dependency order;
+ * cyclic declarations are reported as an error.
+ */
+export declare function evaluateMf2Locals(message: string, args: Record<string, string>): Mf2EvaluateLocalsResult
+
 /** Extracts documented declarations from a JavaScript/TypeScript file using Oxc. */
 export declare function extractFileDocs(filePath: string, includePrivate?: boolean | undefined | null): Array<JsSourceDocItem>
 
@@ -32,6 +40,31 @@ export declare function extractSearchContent(source: string, id: string, url: st
  */
 export declare function extractTranslationKeys(source: string, filePath: string, functionNames?: Array<string> | undefined | null): Array<I18NKeyUsage>
 
+/**
+ * Extracts translation keys from every matching file under a directory.
+ *
+ * TS/JS files are parsed for `t('key')` calls and Markdown files for
+ * `{{t('key')}}` patterns. Defaults to the checker's extensions and function names.
+ * Throws if a directory cannot be read; a missing `dir` yields no keys.
+ */
+export declare function extractTranslationKeysDir(dir: string, extensions?: Array<string> | undefined | null, functionNames?: Array<string> | undefined | null): Array<I18NKeyUsage>
+
+/**
+ * Formats an MF2 message as canonical source.
+ *
+ * Used by editor "format document" actions on dictionary values. On parse
+ * failure the original text is returned unchanged along with the errors.
+ */
+export declare function formatMf2Source(message: string): Mf2FormatSourceResult
+
+/** Frontmatter-only parse result. */
+export interface FrontmatterResult {
+  /** Parsed frontmatter as JSON string. */
+  frontmatter: string
+  /** Byte offset in the source where the Markdown content begins. */
+  contentOffset: number
+}
+
 /**
  * Generates an OG image as SVG.
  *
@@ -40,13 +73,34 @@ export declare function extractTranslationKeys(source: string, filePath: string,
  */
 export declare function generateOgImageSvg(data: JsOgImageData, config?: JsOgImageConfig | undefined | null): string
 
+/** Generates an `llms-full.txt` export with every page's plain-text content. */
+export declare function generateLlmsFullTxt(pages: Array<JsSsgPageMeta>, config: JsSsgConfig): string
+
+/** Generates an `llms.txt` index listing each page's title, URL and description. */
+export declare function generateLlmsTxt(pages: Array<JsSsgPageMeta>, config: JsSsgConfig): string
+
+/** Generates a themed 404 page using the site chrome. */
+export declare function generateSsg404(navGroups: Array<JsSsgNavGroup>, config: JsSsgConfig): string
+
 /** Generates SSG HTML page with navigation and search. */
 export declare function generateSsgHtml(pageData: JsSsgPageData, navGroups: Array<JsSsgNavGroup>, config: JsSsgConfig): string
 
+/**
+ * Generates SSG HTML for many pages on a worker thread.
+ *
+ * `onProgress` is called with `{ index, total, path }` after each page completes.
+ * Resolves to the generated HTML in the same order as `pages`.
+ */
+export declare function generateSsgHtmlBatch(pages: Array<JsSsgPageData>, navGroups: Array<JsSsgNavGroup>, config: JsSsgConfig, onProgress?: (progress: JsSsgProgress) => void): Promise<Array<string>>
+
 /** Result of i18n checking. */
 export interface I18NCheckResult {
   /** All diagnostics. */
   diagnostics: Array<I18NDiagnostic>
+  /** Translation keys that were checked, sorted. */
+  usedKeys: Array<string>
+  /** Number of diagnostics reported by each rule, keyed by rule name. */
+  countsByRule: Record<string, number>
   /** Number of errors. */
   errorCount: number
   /** Number of warnings. */
@@ -89,6 +143,22 @@ export interface I18NLoadResult {
   errors: Array<string>
 }
 
+/** Diagnostic with a source position. */
+export interface JsDiagnostic {
+  /** Diagnostic message. */
+  message: string
+  /** Start line (1-based). */
+  startLine: number
+  /** Start column (1-based, in characters). */
+  startCol: number
+  /** End line (1-based). */
+  endLine: number
+  /** End column (1-based, in characters). */
+  endCol: number
+  /** Severity: "error" or "warning". */
+  severity: string
+}
+
 /** Entry page configuration. */
 export interface JsEntryPageConfig {
   /** Hero section. */
@@ -232,6 +302,8 @@ export interface JsOgImageConfig {
   titleFontSize?: number
   /** Description font size. */
   descriptionFontSize?: number
+  /** TTF/OTF font bytes, used to measure text and embedded in the image. */
+  fontData?: Buffer
 }
 
 /** OG image data for JavaScript. */
@@ -260,6 +332,16 @@ export interface JsParserOptions {
   strikethrough?: boolean
   /** Enable autolinks. */
   autolinks?: boolean
+  /** Enable definition lists. */
+  definitionLists?: boolean
+  /** Enable `==highlighted==` text. */
+  mark?: boolean
+  /** Enable `~subscript~` and `^superscript^` text. */
+  subSup?: boolean
+  /** Enable `:::kind title` ... `:::` custom containers. */
+  containers?: boolean
+  /** Parse lines indented by four spaces as code blocks (default: false). */
+  indentedCode?: boolean
 }
 
 /** Search document for JavaScript. */
@@ -274,10 +356,20 @@ export interface JsSearchDocument {
   body: string
   /** Document headings. */
   headings: Array<string>
+  /** Body offset (UTF-8 bytes) where the section under each heading starts. */
+  headingOffsets?: Array<number>
   /** Code snippets. */
   code: Array<string>
 }
 
+/** A matched search term and the field it occurs in. */
+export interface JsSearchFieldMatch {
+  /** Field name: `"title"`, `"heading"`, `"body"` or `"code"`. */
+  field: string
+  /** Matched term. */
+  term: string
+}
+
 /** Search options for JavaScript. */
 export interface JsSearchOptions {
   /** Maximum number of results. */
@@ -288,6 +380,8 @@ export interface JsSearchOptions {
   fuzzy?: boolean
   /** Minimum score threshold. */
   threshold?: number
+  /** Word similarity (0.0 - 1.0) above which near-duplicate results are collapsed. */
+  dedupe?: number
 }
 
 /** Search result for JavaScript. */
@@ -302,6 +396,8 @@ export interface JsSearchResult {
   score: number
   /** Matched terms. */
   matches: Array<string>
+  /** Matched terms with each field they occur in. */
+  fieldMatches: Array<JsSearchFieldMatch>
   /** Content snippet. */
   snippet: string
 }
@@ -327,7 +423,10 @@ export interface JsSourceDocItem {
   endLine: number
   exported: boolean
   signature?: string
+  /** Every signature of an overloaded function (empty if not overloaded). */
+  overloads: Array<string>
   params: Array<JsSourceDocParam>
+  typeParams: Array<JsSourceDocTypeParam>
   returnType?: string
   tags: Array<JsSourceDocTag>
 }
@@ -347,12 +446,30 @@ export interface JsSourceDocTag {
   value: string
 }
 
+/** Generic type parameter documentation extracted from source code. */
+export interface JsSourceDocTypeParam {
+  name: string
+  constraint?: string
+  default?: string
+  description?: string
+}
+
+/** Translation of an SSG page, emitted as an `hreflang` alternate link. */
+export interface JsSsgAlternateLink {
+  /** BCP 47 locale tag. */
+  locale: string
+  /** URL of the page in that locale. */
+  href: string
+}
+
 /** SSG configuration. */
 export interface JsSsgConfig {
   /** Site name. */
   siteName: string
   /** Base URL path. */
   base: string
+  /** Site origin (e.g. `"https://example.com"`), used where absolute URLs are required. */
+  siteUrl?: string
   /** OG image URL. */
   ogImage?: string
   /** Theme configuration. */
@@ -361,6 +478,10 @@ export interface JsSsgConfig {
   locale?: string
   /** Available locales for locale switcher. */
   availableLocales?: Array<JsLocaleInfo>
+  /** Site default locale; its alternate link is also advertised as `x-default`. */
+  defaultLocale?: string
+  /** Translated UI chrome strings (English by default). */
+  uiStrings?: JsUiStrings
 }
 
 /** Navigation group for SSG. */
@@ -379,6 +500,8 @@ export interface JsSsgNavItem {
   path: string
   /** Full href. */
   href: string
+  /** Optional icon (Iconify name, image URL/path, or text/emoji). */
+  icon?: string
 }
 
 /** Page data for SSG. */
@@ -395,6 +518,43 @@ export interface JsSsgPageData {
   path: string
   /** Entry page configuration (if layout: entry). */
   entryPage?: JsEntryPageConfig
+  /** Page-specific raw HTML to inject into `<head>` (not escaped). */
+  head?: string
+  /** Page author. */
+  author?: string
+  /** Publication date (ISO 8601). */
+  published?: string
+  /** Last modification date (ISO 8601). */
+  modified?: string
+  /**
+   * Page layout: "doc" (default), "page" (no sidebar) or "full" (no sidebar or outline).
+   * Any other value falls back to "doc".
+   */
+  layout?: string
+  /** Translations of this page, including itself; the `defaultLocale` one is also `x-default`. */
+  alternates?: Array<JsSsgAlternateLink>
+}
+
+/** Page summary for `llms.txt` generation. */
+export interface JsSsgPageMeta {
+  /** Page title. */
+  title: string
+  /** URL path relative to `base`. */
+  path: string
+  /** Page description. */
+  description?: string
+  /** Plain-text page content (used by `generateLlmsFullTxt`). */
+  content?: string
+}
+
+/** Progress event emitted by `generateSsgHtmlBatch` after each page. */
+export interface JsSsgProgress {
+  /** Zero-based index of the completed page. */
+  index: number
+  /** Total number of pages in the batch. */
+  total: number
+  /** URL path of the completed page. */
+  path: string
 }
 
 /** Theme colors for JavaScript. */
@@ -443,6 +603,25 @@ export interface JsThemeConfig {
   css?: string
   /** Additional custom JavaScript. */
   js?: string
+  /** Whether to include print-optimized styles (default: true). */
+  print?: boolean
+  /** Not-found (404) page configuration. */
+  notFound?: JsThemeNotFound
+  /** Where the page outline renders: "right", "left", or "none" (default). */
+  outlinePosition?: string
+  /** Heading depth range `[min, max]` shown in the outline (default: `[2, 3]`). */
+  outlineLevel?: Array<number>
+  /** Favicon URL (relative URLs are prefixed with `base`, like the logo). */
+  favicon?: string
+  /** `theme-color` for light mode (e.g. `"#ffffff"`). */
+  themeColorLight?: string
+  /** `theme-color` for dark mode. */
+  themeColorDark?: string
+  /**
+   * Emit schema.org JSON-LD (e.g. a `BreadcrumbList`) in `<head>` (default: false).
+   * Requires `siteUrl`, since its URLs must be absolute.
+   */
+  jsonLd?: boolean
 }
 
 /** Embedded HTML content for specific positions. */
@@ -515,6 +694,16 @@ export interface JsThemeLayout {
   maxContentWidth?: string
 }
 
+/** Not-found (404) page configuration for JavaScript. */
+export interface JsThemeNotFound {
+  /** Page heading (default: "Page not found"). */
+  title?: string
+  /** Message shown below the heading. */
+  message?: string
+  /** Text of the link back to the home page (default: "Back to home"). */
+  homeText?: string
+}
+
 /** Transform options for JavaScript. */
 export interface JsTransformOptions {
   /** Enable GFM extensions. */
@@ -529,6 +718,16 @@ export interface JsTransformOptions {
   strikethrough?: boolean
   /** Enable autolinks. */
   autolinks?: boolean
+  /** Enable definition lists. */
+  definitionLists?: boolean
+  /** Enable `==highlighted==` text. */
+  mark?: boolean
+  /** Enable `~subscript~` and `^superscript^` text. */
+  subSup?: boolean
+  /** Enable `:::kind title` ... `:::` custom containers. */
+  containers?: boolean
+  /** Parse lines indented by four spaces as code blocks (default: false). */
+  indentedCode?: boolean
   /** Maximum TOC depth (1-6). */
   tocMaxDepth?: number
   /** Convert `.md` links to `.html` links for SSG output. */
@@ -545,6 +744,77 @@ export interface JsTransformOptions {
   codeAnnotationSyntax?: string
   /** Enable line numbers for all code blocks by default. */
   codeAnnotationDefaultLineNumbers?: boolean
+  /** Add `loading="lazy"` to images. */
+  imageLazyLoading?: boolean
+  /** Add `decoding="async"` to images. */
+  imageAsyncDecoding?: boolean
+  /** Add `id` attributes and `#` permalink anchors to headings. */
+  headingAnchors?: boolean
+  /** Slug strategy for heading ids and TOC entries: `"preserve-unicode"` (default), `"ascii"` or `"github"`. */
+  slugStrategy?: string
+  /** Prefix for generated heading and footnote ids. */
+  idPrefix?: string
+  /** Drop the final newline inside code blocks (default: true). */
+  trimCodeTrailingNewline?: boolean
+  /** Wrap tables in a horizontally scrollable container. */
+  wrapTables?: boolean
+  /** Add `role`/`aria-label` attributes to callouts and code blocks. */
+  ariaAttributes?: boolean
+  /** Wrap code blocks with a copy button hook for client-side scripts. */
+  copyButton?: boolean
+  /** Shift heading levels by this amount (e.g. `1` renders `#` as `<h2>`), clamped to h1..h6. */
+  headingOffset?: number
+  /** Drop HTML comments instead of passing them through. */
+  stripComments?: boolean
+  /** Render titled images that stand alone in a paragraph as `<figure>` with a `<figcaption>`. */
+  figures?: boolean
+  /** Add `rel="noopener noreferrer"` to external links (default: true). */
+  externalRel?: boolean
+  /** Open external links in a new tab with `target="_blank"` (default: true). */
+  externalTargetBlank?: boolean
+  /** URL percent-encoding policy: `"minimal"` (default), `"strict"` or `"none"`. */
+  urlEncode?: string
+  /** Add `translate="no"` to code elements so auto-translation skips them. */
+  codeTranslateNo?: boolean
+  /** With `codeTranslateNo`, also add the `notranslate` class. */
+  codeNotranslateClass?: boolean
+  /**
+   * Tag of an element wrapping the rendered HTML (e.g. `"div"`). Tags not
+   * matching `[A-Za-z][A-Za-z0-9-]*` are reported in `errors` and skipped.
+   */
+  containerTag?: string
+  /** Class of the `containerTag` element. */
+  containerClass?: string
+}
+
+/** Translated UI chrome strings for JavaScript. */
+export interface JsUiStrings {
+  /** Skip link text. */
+  skipToContent?: string
+  /** Label of the header menu toggle. */
+  toggleMenu?: string
+  /** Search button text and label. */
+  search?: string
+  /** Search input placeholder. */
+  searchPlaceholder?: string
+  /** Search footer hint for arrow keys. */
+  searchNavigate?: string
+  /** Search footer hint for Enter. */
+  searchSelect?: string
+  /** Search footer hint for Esc. */
+  searchClose?: string
+  /** Label of the header theme toggle. */
+  toggleTheme?: string
+  /** Mobile footer menu label. */
+  menu?: string
+  /** Mobile footer theme label. */
+  theme?: string
+  /** Outline heading. */
+  onThisPage?: string
+  /** Code block copy button label. */
+  copyCode?: string
+  /** Copy button label after copying. */
+  copied?: string
 }
 
 export declare function lintMarkdown(source: string, options?: JsMarkdownLintOptions | undefined | null): JsMarkdownLintResult
@@ -567,6 +837,16 @@ export declare function loadDictionaries(dir: string): I18NLoadResult
  */
 export declare function loadDictionariesFlat(dir: string): Record<string, Record<string, string>>
 
+/**
+ * Loads dictionaries from the given directory and returns them as one nested JSON string.
+ *
+ * The result has the shape `{ "defaultLocale": ..., "locales": { "en": { "common": { ... } } } }`,
+ * ready to ship to a client i18n runtime without re-nesting the flat map.
+ * The `defaultLocale` field is `null` unless a `defaultLocale` argument is given.
+ * Throws if the directory cannot be loaded or `defaultLocale` is not a valid locale tag.
+ */
+export declare function loadDictionariesJson(dir: string, defaultLocale?: string | undefined | null): string
+
 /** Restores code block metadata after JavaScript-side syntax highlighting. */
 export declare function mergeHighlightedCodeBlocks(originalHtml: string, highlightedHtml: string): string
 
@@ -578,6 +858,22 @@ export interface MermaidTransformResult {
   errors: Array<string>
 }
 
+/** Result of evaluating MF2 `.local` declarations. */
+export interface Mf2EvaluateLocalsResult {
+  /** Computed value of each local (if evaluation succeeded). */
+  values?: Record<string, string>
+  /** Parse or evaluation errors. */
+  errors: Array<string>
+}
+
+/** Result of MF2 source formatting. */
+export interface Mf2FormatSourceResult {
+  /** Canonical MF2 source, or the original text if parsing failed. */
+  formatted?: string
+  /** Parse errors. */
+  errors: Array<string>
+}
+
 /** Result of MF2 validation. */
 export interface Mf2ValidateResult {
   /** Whether the message is valid. */
@@ -601,12 +897,21 @@ export declare function parseAndRender(source: string, options?: JsParserOptions
 /** Parses Markdown and renders to HTML asynchronously (runs on worker thread). */
 export declare function parseAndRenderAsync(source: string, options?: JsParserOptions | undefined | null): Promise<unknown>
 
+/**
+ * Parses only the frontmatter of a Markdown document.
+ *
+ * Useful for building navigation or metadata without transforming the body.
+ */
+export declare function parseFrontmatterOnly(source: string): FrontmatterResult
+
 /** Parse result containing the AST as JSON. */
 export interface ParseResult {
   /** The AST as a JSON string. */
   ast: string
   /** Parse errors, if any. */
   errors: Array<string>
+  /** Parse errors with source positions. */
+  diagnostics: Array<JsDiagnostic>
 }
 
 /** Renders an AST (provided as JSON) to HTML. */
@@ -645,6 +950,22 @@ export interface TocEntry {
  */
 export declare function transform(source: string, options?: JsTransformOptions | undefined | null): TransformResult
 
+/**
+ * Transforms Markdown and extracts its search document in a single parse.
+ *
+ * Equivalent to calling `transform` and `extractSearchContent` on the same
+ * source, without parsing it twice.
+ */
+export declare function transformAndIndex(source: string, id: string, url: string, options?: JsTransformOptions | undefined | null): TransformAndIndexResult
+
+/** Result of [`transform_and_index`]. */
+export interface TransformAndIndexResult {
+  /** The same output as `transform`. */
+  transform: TransformResult
+  /** The same output as `extractSearchContent`. */
+  searchDocument: JsSearchDocument
+}
+
 /** Transforms Markdown source asynchronously (runs on worker thread). */
 export declare function transformAsync(source: string, options?: JsTransformOptions | undefined | null): Promise<unknown>
 
@@ -667,6 +988,8 @@ export interface TransformResult {
   toc: Array<TocEntry>
   /** Parse/render errors, if any. */
   errors: Array<string>
+  /** Parse errors with source positions. */
+  diagnostics: Array<JsDiagnostic>
 }
 
 /**
@@ -72,11 +72,13 @@ module.exports = binding;
 module.exports.parse = binding.parse;
 module.exports.parseAndRender = binding.parseAndRender;
 module.exports.parseAndRenderAsync = binding.parseAndRenderAsync;
+module.exports.parseFrontmatterOnly = binding.parseFrontmatterOnly;
 module.exports.lintMarkdown = binding.lintMarkdown;
 module.exports.lintMarkdownDocuments = binding.lintMarkdownDocuments;
 module.exports.render = binding.render;
 module.exports.transform = binding.transform;
 module.exports.transformAsync = binding.transformAsync;
+module.exports.transformAndIndex = binding.transformAndIndex;
 module.exports.version = binding.version;
 module.exports.extractFileDocs = binding.extractFileDocs;
 module.exports.generateOgImageSvg = binding.generateOgImageSvg;
@@ -84,9 +86,17 @@ module.exports.buildSearchIndex = binding.buildSearchIndex;
 module.exports.searchIndex = binding.searchIndex;
 module.exports.extractSearchContent = binding.extractSearchContent;
 module.exports.generateSsgHtml = binding.generateSsgHtml;
+module.exports.generateSsgHtmlBatch = binding.generateSsgHtmlBatch;
+module.exports.generateSsg404 = binding.generateSsg404;
+module.exports.generateLlmsTxt = binding.generateLlmsTxt;
+module.exports.generateLlmsFullTxt = binding.generateLlmsFullTxt;
 module.exports.transformMermaid = binding.transformMermaid;
 module.exports.loadDictionaries = binding.loadDictionaries;
 module.exports.loadDictionariesFlat = binding.loadDictionariesFlat;
+module.exports.loadDictionariesJson = binding.loadDictionariesJson;
 module.exports.validateMf2 = binding.validateMf2;
+module.exports.formatMf2Source = binding.formatMf2Source;
+module.exports.evaluateMf2Locals = binding.evaluateMf2Locals;
 module.exports.checkI18n = binding.checkI18n;
 module.exports.extractTranslationKeys = binding.extractTranslationKeys;
+module.exports.extractTranslationKeysDir = binding.extractTranslationKeysDir;
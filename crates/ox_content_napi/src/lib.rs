@@ -8,6 +8,7 @@ mod lint;
 mod mdast;
 
 use napi::bindgen_prelude::*;
+use napi::threadsafe_function::{ThreadsafeFunction, ThreadsafeFunctionCallMode};
 use napi::Task;
 use napi_derive::napi;
 use std::collections::HashMap;
@@ -15,9 +16,9 @@ use std::path::Path;
 
 use ox_content_allocator::Allocator;
 use ox_content_ast::{Document, Heading, Node};
-use ox_content_docs::{DocExtractor, DocItem, DocItemKind, DocTag, ParamDoc};
-use ox_content_parser::{Parser, ParserOptions};
-use ox_content_renderer::{HtmlRenderer, HtmlRendererOptions};
+use ox_content_docs::{DocExtractor, DocItem, DocItemKind, DocTag, ParamDoc, TypeParamDoc};
+use ox_content_parser::{ParseError, Parser, ParserOptions};
+use ox_content_renderer::{HtmlRenderer, HtmlRendererOptions, SlugStrategy, Slugger};
 use ox_content_search::{DocumentIndexer, SearchIndex, SearchIndexBuilder, SearchOptions};
 
 const ALLOCATOR_BYTES_PER_INPUT_BYTE: usize = 8;
@@ -29,6 +30,24 @@ fn create_allocator_for_source(source: &str) -> Allocator {
     Allocator::with_capacity(capacity)
 }
 
+/// Diagnostic with a source position.
+#[napi(object)]
+#[derive(Clone)]
+pub struct JsDiagnostic {
+    /// Diagnostic message.
+    pub message: String,
+    /// Start line (1-based).
+    pub start_line: u32,
+    /// Start column (1-based, in characters).
+    pub start_col: u32,
+    /// End line (1-based).
+    pub end_line: u32,
+    /// End column (1-based, in characters).
+    pub end_col: u32,
+    /// Severity: "error" or "warning".
+    pub severity: String,
+}
+
 /// Parse result containing the AST as JSON.
 #[napi(object)]
 pub struct ParseResult {
@@ -36,6 +55,8 @@ pub struct ParseResult {
     pub ast: String,
     /// Parse errors, if any.
     pub errors: Vec<String>,
+    /// Parse errors with source positions.
+    pub diagnostics: Vec<JsDiagnostic>,
 }
 
 /// Render result containing the HTML output.
@@ -70,6 +91,17 @@ pub struct TransformResult {
     pub toc: Vec<TocEntry>,
     /// Parse/render errors, if any.
     pub errors: Vec<String>,
+    /// Parse errors with source positions.
+    pub diagnostics: Vec<JsDiagnostic>,
+}
+
+/// Frontmatter-only parse result.
+#[napi(object)]
+pub struct FrontmatterResult {
+    /// Parsed frontmatter as JSON string.
+    pub frontmatter: String,
+    /// Byte offset in the source where the Markdown content begins.
+    pub content_offset: u32,
 }
 
 /// Raw JSDoc tag extracted from source code.
@@ -91,6 +123,16 @@ pub struct JsSourceDocParam {
     pub description: Option<String>,
 }
 
+/// Generic type parameter documentation extracted from source code.
+#[napi(object)]
+#[derive(Clone)]
+pub struct JsSourceDocTypeParam {
+    pub name: String,
+    pub constraint: Option<String>,
+    pub default: Option<String>,
+    pub description: Option<String>,
+}
+
 /// Source documentation item extracted from a JS/TS file.
 #[napi(object)]
 #[derive(Clone)]
@@ -104,7 +146,10 @@ pub struct JsSourceDocItem {
     pub end_line: u32,
     pub exported: bool,
     pub signature: Option<String>,
+    /// Every signature of an overloaded function (empty if not overloaded).
+    pub overloads: Vec<String>,
     pub params: Vec<JsSourceDocParam>,
+    pub type_params: Vec<JsSourceDocTypeParam>,
     pub return_type: Option<String>,
     pub tags: Vec<JsSourceDocTag>,
 }
@@ -125,6 +170,16 @@ pub struct JsTransformOptions {
     pub strikethrough: Option<bool>,
     /// Enable autolinks.
     pub autolinks: Option<bool>,
+    /// Enable definition lists.
+    pub definition_lists: Option<bool>,
+    /// Enable `==highlighted==` text.
+    pub mark: Option<bool>,
+    /// Enable `~subscript~` and `^superscript^` text.
+    pub sub_sup: Option<bool>,
+    /// Enable `:::kind title` ... `:::` custom containers.
+    pub containers: Option<bool>,
+    /// Parse lines indented by four spaces as code blocks (default: false).
+    pub indented_code: Option<bool>,
     /// Maximum TOC depth (1-6).
     pub toc_max_depth: Option<u8>,
     /// Convert `.md` links to `.html` links for SSG output.
@@ -141,6 +196,46 @@ pub struct JsTransformOptions {
     pub code_annotation_syntax: Option<String>,
     /// Enable line numbers for all code blocks by default.
     pub code_annotation_default_line_numbers: Option<bool>,
+    /// Add `loading="lazy"` to images.
+    pub image_lazy_loading: Option<bool>,
+    /// Add `decoding="async"` to images.
+    pub image_async_decoding: Option<bool>,
+    /// Add `id` attributes and `#` permalink anchors to headings.
+    pub heading_anchors: Option<bool>,
+    /// Slug strategy for heading ids and TOC entries:
+    /// `"preserve-unicode"` (default), `"ascii"` or `"github"`.
+    pub slug_strategy: Option<String>,
+    /// Prefix for generated heading and footnote ids.
+    pub id_prefix: Option<String>,
+    /// Drop the final newline inside code blocks (default: true).
+    pub trim_code_trailing_newline: Option<bool>,
+    /// Wrap tables in a horizontally scrollable container.
+    pub wrap_tables: Option<bool>,
+    /// Add `role`/`aria-label` attributes to callouts and code blocks.
+    pub aria_attributes: Option<bool>,
+    /// Wrap code blocks with a copy button hook for client-side scripts.
+    pub copy_button: Option<bool>,
+    /// Shift heading levels by this amount, clamped to h1..h6.
+    pub heading_offset: Option<i32>,
+    /// Drop HTML comments instead of passing them through.
+    pub strip_comments: Option<bool>,
+    /// Render titled images that stand alone in a paragraph as `<figure>` with a `<figcaption>`.
+    pub figures: Option<bool>,
+    /// Add `rel="noopener noreferrer"` to external links (default: true).
+    pub external_rel: Option<bool>,
+    /// Open external links in a new tab with `target="_blank"` (default: true).
+    pub external_target_blank: Option<bool>,
+    /// URL percent-encoding policy: `"minimal"` (default), `"strict"` or `"none"`.
+    pub url_encode: Option<String>,
+    /// Add `translate="no"` to code elements so auto-translation skips them.
+    pub code_translate_no: Option<bool>,
+    /// With `code_translate_no`, also add the `notranslate` class.
+    pub code_notranslate_class: Option<bool>,
+    /// Tag of an element wrapping the rendered HTML (e.g. `"div"`). Tags not
+    /// matching `[A-Za-z][A-Za-z0-9-]*` are reported in `errors` and skipped.
+    pub container_tag: Option<String>,
+    /// Class of the `container_tag` element.
+    pub container_class: Option<String>,
 }
 
 /// Parser options for JavaScript.
@@ -159,6 +254,16 @@ pub struct JsParserOptions {
     pub strikethrough: Option<bool>,
     /// Enable autolinks.
     pub autolinks: Option<bool>,
+    /// Enable definition lists.
+    pub definition_lists: Option<bool>,
+    /// Enable `==highlighted==` text.
+    pub mark: Option<bool>,
+    /// Enable `~subscript~` and `^superscript^` text.
+    pub sub_sup: Option<bool>,
+    /// Enable `:::kind title` ... `:::` custom containers.
+    pub containers: Option<bool>,
+    /// Parse lines indented by four spaces as code blocks (default: false).
+    pub indented_code: Option<bool>,
 }
 
 impl From<JsParserOptions> for ParserOptions {
@@ -181,6 +286,21 @@ impl From<JsParserOptions> for ParserOptions {
         if let Some(v) = opts.autolinks {
             options.autolinks = v;
         }
+        if let Some(v) = opts.definition_lists {
+            options.definition_lists = v;
+        }
+        if let Some(v) = opts.mark {
+            options.mark = v;
+        }
+        if let Some(v) = opts.sub_sup {
+            options.sub_sup = v;
+        }
+        if let Some(v) = opts.containers {
+            options.containers = v;
+        }
+        if let Some(v) = opts.indented_code {
+            options.indented_code = v;
+        }
 
         options
     }
@@ -199,9 +319,13 @@ pub fn parse(source: String, options: Option<JsParserOptions>) -> ParseResult {
     match result {
         Ok(doc) => {
             let ast = mdast::to_mdast_json(&doc);
-            ParseResult { ast, errors: vec![] }
+            ParseResult { ast, errors: vec![], diagnostics: vec![] }
         }
-        Err(e) => ParseResult { ast: String::new(), errors: vec![e.to_string()] },
+        Err(e) => ParseResult {
+            ast: String::new(),
+            errors: vec![e.to_string()],
+            diagnostics: vec![parse_error_diagnostic(&source, 0, &e)],
+        },
     }
 }
 
@@ -276,6 +400,15 @@ fn map_param_doc(param: ParamDoc) -> JsSourceDocParam {
     }
 }
 
+fn map_type_param_doc(param: TypeParamDoc) -> JsSourceDocTypeParam {
+    JsSourceDocTypeParam {
+        name: param.name,
+        constraint: param.constraint,
+        default: param.default,
+        description: param.description,
+    }
+}
+
 fn map_doc_item(item: DocItem) -> JsSourceDocItem {
     JsSourceDocItem {
         name: item.name,
@@ -287,7 +420,9 @@ fn map_doc_item(item: DocItem) -> JsSourceDocItem {
         end_line: item.end_line,
         exported: item.exported,
         signature: item.signature,
+        overloads: item.overloads,
         params: item.params.into_iter().map(map_param_doc).collect(),
+        type_params: item.type_params.into_iter().map(map_type_param_doc).collect(),
         return_type: item.return_type,
         tags: item.tags.into_iter().map(map_doc_tag).collect(),
     }
@@ -318,42 +453,112 @@ pub fn merge_highlighted_code_blocks(original_html: String, highlighted_html: St
 /// This is the main entry point for @ox-content/unplugin.
 #[napi]
 pub fn transform(source: String, options: Option<JsTransformOptions>) -> TransformResult {
-    let opts = options.unwrap_or_default();
+    run_transform(&source, &options.unwrap_or_default(), |_, _| ()).0
+}
+
+/// Parses and renders Markdown the way `transform` does.
+///
+/// `inspect` also receives the parsed document (`None` after a parse error)
+/// and the frontmatter, so callers can derive more output from the same parse.
+fn run_transform<T>(
+    source: &str,
+    opts: &JsTransformOptions,
+    inspect: impl FnOnce(Option<&Document<'_>>, &HashMap<String, serde_json::Value>) -> T,
+) -> (TransformResult, T) {
     let toc_max_depth = opts.toc_max_depth.unwrap_or(3);
 
     // Parse frontmatter
-    let (content, frontmatter) = parse_frontmatter(&source);
+    let (content, frontmatter) = parse_frontmatter(source);
 
     // Parse markdown
     let allocator = create_allocator_for_source(&content);
-    let parser_options = transform_options_to_parser_options(&opts);
+    let parser_options = transform_options_to_parser_options(opts);
     let parser = Parser::with_options(&allocator, &content, parser_options);
 
-    let result = parser.parse();
-    match result {
+    match parser.parse() {
         Ok(doc) => {
+            let renderer_options = transform_options_to_renderer_options(opts);
+
             // Extract TOC from headings
-            let toc = extract_toc(&doc, toc_max_depth);
+            let toc = extract_toc(&doc, toc_max_depth, renderer_options.slug_strategy);
 
             // Render to HTML
-            let renderer_options = transform_options_to_renderer_options(&opts);
             let mut renderer = HtmlRenderer::with_options(renderer_options);
             let html = renderer.render(&doc);
 
-            TransformResult {
+            // The renderer skips an invalid container tag; report it here.
+            let errors = opts
+                .container_tag
+                .as_deref()
+                .filter(|tag| !ox_content_renderer::is_valid_tag_name(tag))
+                .map(|tag| format!("invalid containerTag '{tag}': expected [A-Za-z][A-Za-z0-9-]*"))
+                .into_iter()
+                .collect();
+
+            let result = TransformResult {
                 html,
                 frontmatter: serde_json::to_string(&frontmatter)
                     .unwrap_or_else(|_| "{}".to_string()),
                 toc,
-                errors: vec![],
-            }
+                errors,
+                diagnostics: vec![],
+            };
+            (result, inspect(Some(&doc), &frontmatter))
         }
-        Err(e) => TransformResult {
-            html: String::new(),
-            frontmatter: "{}".to_string(),
-            toc: vec![],
-            errors: vec![e.to_string()],
-        },
+        Err(e) => {
+            let result = TransformResult {
+                html: String::new(),
+                frontmatter: "{}".to_string(),
+                toc: vec![],
+                errors: vec![e.to_string()],
+                diagnostics: vec![parse_error_diagnostic(source, source.len() - content.len(), &e)],
+            };
+            (result, inspect(None, &frontmatter))
+        }
+    }
+}
+
+/// Converts a parse error into a positioned diagnostic.
+///
+/// `base_offset` is the byte offset of the parsed content within `source`
+/// (non-zero when frontmatter was stripped before parsing).
+fn parse_error_diagnostic(source: &str, base_offset: usize, error: &ParseError) -> JsDiagnostic {
+    let span = error.span();
+    let (start_line, start_col) = line_col(source, base_offset + span.start as usize);
+    let (end_line, end_col) = line_col(source, base_offset + span.end as usize);
+    JsDiagnostic {
+        message: error.to_string(),
+        start_line,
+        start_col,
+        end_line,
+        end_col,
+        severity: "error".to_string(),
+    }
+}
+
+/// Returns the 1-based line and character column of a byte offset.
+fn line_col(source: &str, offset: usize) -> (u32, u32) {
+    let mut offset = offset.min(source.len());
+    while !source.is_char_boundary(offset) {
+        offset -= 1;
+    }
+    let before = &source[..offset];
+    let line_start = before.rfind('\n').map_or(0, |index| index + 1);
+    let line = before.matches('\n').count() + 1;
+    let column = before[line_start..].chars().count() + 1;
+    (line as u32, column as u32)
+}
+
+/// Parses only the frontmatter of a Markdown document.
+///
+/// Useful for building navigation or metadata without transforming the body.
+#[napi]
+pub fn parse_frontmatter_only(source: String) -> FrontmatterResult {
+    let (content, frontmatter) = parse_frontmatter(&source);
+
+    FrontmatterResult {
+        frontmatter: serde_json::to_string(&frontmatter).unwrap_or_else(|_| "{}".to_string()),
+        content_offset: (source.len() - content.len()) as u32,
     }
 }
 
@@ -411,14 +616,17 @@ fn parse_frontmatter(source: &str) -> (String, HashMap<String, serde_json::Value
 }
 
 /// Extracts table of contents from document headings.
-fn extract_toc(doc: &Document, max_depth: u8) -> Vec<TocEntry> {
+///
+/// Deeper headings still claim their slugs so duplicate suffixes match the rendered ids.
+fn extract_toc(doc: &Document, max_depth: u8, strategy: SlugStrategy) -> Vec<TocEntry> {
     let mut entries = Vec::new();
+    let mut slugger = Slugger::new(strategy);
 
     for node in &doc.children {
         if let Node::Heading(heading) = node {
+            let text = extract_heading_text(heading);
+            let slug = slugger.slug(&text);
             if heading.depth <= max_depth {
-                let text = extract_heading_text(heading);
-                let slug = slugify(&text);
                 entries.push(TocEntry { depth: heading.depth, text, slug });
             }
         }
@@ -440,6 +648,7 @@ fn extract_heading_text(heading: &Heading) -> String {
 fn collect_text(node: &Node, text: &mut String) {
     match node {
         Node::Text(t) => text.push_str(t.value),
+        Node::Break(_) => text.push(' '),
         Node::Emphasis(e) => {
             for child in &e.children {
                 collect_text(child, text);
@@ -456,6 +665,21 @@ fn collect_text(node: &Node, text: &mut String) {
                 collect_text(child, text);
             }
         }
+        Node::Mark(d) => {
+            for child in &d.children {
+                collect_text(child, text);
+            }
+        }
+        Node::Subscript(d) => {
+            for child in &d.children {
+                collect_text(child, text);
+            }
+        }
+        Node::Superscript(d) => {
+            for child in &d.children {
+                collect_text(child, text);
+            }
+        }
         Node::Link(l) => {
             for child in &l.children {
                 collect_text(child, text);
@@ -465,17 +689,6 @@ fn collect_text(node: &Node, text: &mut String) {
     }
 }
 
-/// Converts text to URL-friendly slug.
-fn slugify(text: &str) -> String {
-    text.to_lowercase()
-        .chars()
-        .map(|c| if c.is_alphanumeric() || c == ' ' || c == '-' { c } else { ' ' })
-        .collect::<String>()
-        .split_whitespace()
-        .collect::<Vec<_>>()
-        .join("-")
-}
-
 /// Converts transform options to parser options.
 fn transform_options_to_parser_options(opts: &JsTransformOptions) -> ParserOptions {
     let mut options =
@@ -496,6 +709,21 @@ fn transform_options_to_parser_options(opts: &JsTransformOptions) -> ParserOptio
     if let Some(v) = opts.autolinks {
         options.autolinks = v;
     }
+    if let Some(v) = opts.definition_lists {
+        options.definition_lists = v;
+    }
+    if let Some(v) = opts.mark {
+        options.mark = v;
+    }
+    if let Some(v) = opts.sub_sup {
+        options.sub_sup = v;
+    }
+    if let Some(v) = opts.containers {
+        options.containers = v;
+    }
+    if let Some(v) = opts.indented_code {
+        options.indented_code = v;
+    }
 
     options
 }
@@ -529,6 +757,68 @@ fn transform_options_to_renderer_options(opts: &JsTransformOptions) -> HtmlRende
     if let Some(v) = opts.code_annotation_default_line_numbers {
         options.code_annotation_default_line_numbers = v;
     }
+    if let Some(v) = opts.image_lazy_loading {
+        options.image_lazy_loading = v;
+    }
+    if let Some(v) = opts.image_async_decoding {
+        options.image_async_decoding = v;
+    }
+    if let Some(v) = opts.heading_anchors {
+        options.heading_anchors = v;
+    }
+    if let Some(ref v) = opts.slug_strategy {
+        options.slug_strategy = match v.as_str() {
+            "ascii" => SlugStrategy::Ascii,
+            "github" => SlugStrategy::GitHub,
+            _ => SlugStrategy::PreserveUnicode,
+        };
+    }
+    if let Some(ref v) = opts.id_prefix {
+        options.id_prefix = Some(v.clone());
+    }
+    if let Some(v) = opts.trim_code_trailing_newline {
+        options.trim_code_trailing_newline = v;
+    }
+    if let Some(v) = opts.wrap_tables {
+        options.wrap_tables = v;
+    }
+    if let Some(v) = opts.aria_attributes {
+        options.aria_attributes = v;
+    }
+    if let Some(v) = opts.copy_button {
+        options.copy_button = v;
+    }
+    if let Some(v) = opts.heading_offset {
+        options.heading_offset = i8::try_from(v.clamp(-5, 5)).unwrap_or_default();
+    }
+    if let Some(v) = opts.strip_comments {
+        options.strip_comments = v;
+    }
+    if let Some(v) = opts.figures {
+        options.figures = v;
+    }
+    if let Some(v) = opts.external_rel {
+        options.external_rel = v;
+    }
+    if let Some(v) = opts.external_target_blank {
+        options.external_target_blank = v;
+    }
+    if let Some(ref v) = opts.url_encode {
+        options.url_encode = match v.as_str() {
+            "strict" => ox_content_renderer::UrlEncode::Strict,
+            "none" => ox_content_renderer::UrlEncode::None,
+            _ => ox_content_renderer::UrlEncode::Minimal,
+        };
+    }
+    if let Some(v) = opts.code_translate_no {
+        options.code_translate_no = v;
+    }
+    if let Some(v) = opts.code_notranslate_class {
+        options.code_notranslate_class = v;
+    }
+    if let Some(ref tag) = opts.container_tag {
+        options.container = Some((tag.clone(), opts.container_class.clone().unwrap_or_default()));
+    }
 
     options
 }
@@ -588,39 +878,7 @@ impl Task for TransformTask {
     type JsValue = TransformResult;
 
     fn compute(&mut self) -> Result<Self::Output> {
-        let toc_max_depth = self.options.toc_max_depth.unwrap_or(3);
-
-        // Parse frontmatter
-        let (content, frontmatter) = parse_frontmatter(&self.source);
-
-        // Parse markdown
-        let allocator = create_allocator_for_source(&content);
-        let parser_options = transform_options_to_parser_options(&self.options);
-        let parser = Parser::with_options(&allocator, &content, parser_options);
-
-        let result = match parser.parse() {
-            Ok(doc) => {
-                let toc = extract_toc(&doc, toc_max_depth);
-                let renderer_options = transform_options_to_renderer_options(&self.options);
-                let mut renderer = HtmlRenderer::with_options(renderer_options);
-                let html = renderer.render(&doc);
-
-                TransformResult {
-                    html,
-                    frontmatter: serde_json::to_string(&frontmatter)
-                        .unwrap_or_else(|_| "{}".to_string()),
-                    toc,
-                    errors: vec![],
-                }
-            }
-            Err(e) => TransformResult {
-                html: String::new(),
-                frontmatter: "{}".to_string(),
-                toc: vec![],
-                errors: vec![e.to_string()],
-            },
-        };
-        Ok(result)
+        Ok(run_transform(&self.source, &self.options, |_, _| ()).0)
     }
 
     fn resolve(&mut self, _env: Env, output: Self::Output) -> Result<Self::JsValue> {
@@ -644,7 +902,7 @@ pub fn transform_async(
 
 /// OG image configuration for JavaScript.
 #[napi(object)]
-#[derive(Default, Clone)]
+#[derive(Default)]
 pub struct JsOgImageConfig {
     /// Image width in pixels.
     pub width: Option<u32>,
@@ -658,6 +916,8 @@ pub struct JsOgImageConfig {
     pub title_font_size: Option<u32>,
     /// Description font size.
     pub description_font_size: Option<u32>,
+    /// TTF/OTF font bytes, used to measure text and embedded in the image.
+    pub font_data: Option<Buffer>,
 }
 
 /// OG image data for JavaScript.
@@ -702,6 +962,9 @@ pub fn generate_og_image_svg(data: JsOgImageData, config: Option<JsOgImageConfig
     if let Some(ds) = cfg.description_font_size {
         og_config.description_font_size = ds;
     }
+    if let Some(font) = cfg.font_data {
+        og_config.font_data = Some(font.to_vec());
+    }
 
     let og_data = OgImageData {
         title: data.title,
@@ -734,6 +997,8 @@ pub struct JsSearchDocument {
     pub body: String,
     /// Document headings.
     pub headings: Vec<String>,
+    /// Body offset (UTF-8 bytes) where the section under each heading starts.
+    pub heading_offsets: Option<Vec<u32>>,
     /// Code snippets.
     pub code: Vec<String>,
 }
@@ -751,10 +1016,21 @@ pub struct JsSearchResult {
     pub score: f64,
     /// Matched terms.
     pub matches: Vec<String>,
+    /// Matched terms with each field they occur in.
+    pub field_matches: Vec<JsSearchFieldMatch>,
     /// Content snippet.
     pub snippet: String,
 }
 
+/// A matched search term and the field it occurs in.
+#[napi(object)]
+pub struct JsSearchFieldMatch {
+    /// Field name: `"title"`, `"heading"`, `"body"` or `"code"`.
+    pub field: String,
+    /// Matched term.
+    pub term: String,
+}
+
 /// Search options for JavaScript.
 #[napi(object)]
 #[derive(Default, Clone)]
@@ -767,6 +1043,8 @@ pub struct JsSearchOptions {
     pub fuzzy: Option<bool>,
     /// Minimum score threshold.
     pub threshold: Option<f64>,
+    /// Word similarity (0.0 - 1.0) above which near-duplicate results are collapsed.
+    pub dedupe: Option<f64>,
 }
 
 impl From<JsSearchOptions> for SearchOptions {
@@ -776,6 +1054,7 @@ impl From<JsSearchOptions> for SearchOptions {
             prefix: opts.prefix.unwrap_or(true),
             fuzzy: opts.fuzzy.unwrap_or(false),
             threshold: opts.threshold.unwrap_or(0.0),
+            dedupe: opts.dedupe,
         }
     }
 }
@@ -794,6 +1073,12 @@ pub fn build_search_index(documents: Vec<JsSearchDocument>) -> String {
             url: doc.url,
             body: doc.body,
             headings: doc.headings,
+            heading_offsets: doc
+                .heading_offsets
+                .unwrap_or_default()
+                .into_iter()
+                .map(|offset| offset as usize)
+                .collect(),
             code: doc.code,
         });
     }
@@ -827,6 +1112,11 @@ pub fn search_index(
             url: r.url,
             score: r.score,
             matches: r.matches,
+            field_matches: r
+                .field_matches
+                .into_iter()
+                .map(|(field, term)| JsSearchFieldMatch { field: field.as_str().to_string(), term })
+                .collect(),
             snippet: r.snippet,
         })
         .collect()
@@ -846,6 +1136,22 @@ pub struct JsSsgNavItem {
     pub path: String,
     /// Full href.
     pub href: String,
+    /// Optional icon (Iconify name, image URL/path, or text/emoji).
+    pub icon: Option<String>,
+}
+
+/// Page summary for `llms.txt` generation.
+#[napi(object)]
+#[derive(Clone)]
+pub struct JsSsgPageMeta {
+    /// Page title.
+    pub title: String,
+    /// URL path relative to `base`.
+    pub path: String,
+    /// Page description.
+    pub description: Option<String>,
+    /// Plain-text page content (used by `generateLlmsFullTxt`).
+    pub content: Option<String>,
 }
 
 /// Navigation group for SSG.
@@ -942,6 +1248,15 @@ pub struct JsEntryPageConfig {
     pub features: Option<Vec<JsFeatureConfig>>,
 }
 
+/// Translation of an SSG page, emitted as an `hreflang` alternate link.
+#[napi(object)]
+pub struct JsSsgAlternateLink {
+    /// BCP 47 locale tag.
+    pub locale: String,
+    /// URL of the page in that locale.
+    pub href: String,
+}
+
 /// Page data for SSG.
 #[napi(object)]
 pub struct JsSsgPageData {
@@ -957,6 +1272,19 @@ pub struct JsSsgPageData {
     pub path: String,
     /// Entry page configuration (if layout: entry).
     pub entry_page: Option<JsEntryPageConfig>,
+    /// Page-specific raw HTML to inject into `<head>` (not escaped).
+    pub head: Option<String>,
+    /// Page author.
+    pub author: Option<String>,
+    /// Publication date (ISO 8601).
+    pub published: Option<String>,
+    /// Last modification date (ISO 8601).
+    pub modified: Option<String>,
+    /// Page layout: "doc" (default), "page" (no sidebar) or "full" (no sidebar or outline).
+    /// Any other value falls back to "doc".
+    pub layout: Option<String>,
+    /// Translations of this page, including itself; the `default_locale` one is also `x-default`.
+    pub alternates: Option<Vec<JsSsgAlternateLink>>,
 }
 
 // =============================================================================
@@ -1107,6 +1435,35 @@ pub struct JsThemeConfig {
     pub css: Option<String>,
     /// Additional custom JavaScript.
     pub js: Option<String>,
+    /// Whether to include print-optimized styles (default: true).
+    pub print: Option<bool>,
+    /// Not-found (404) page configuration.
+    pub not_found: Option<JsThemeNotFound>,
+    /// Where the page outline renders: "right", "left", or "none" (default).
+    pub outline_position: Option<String>,
+    /// Heading depth range `[min, max]` shown in the outline (default: `[2, 3]`).
+    pub outline_level: Option<Vec<u32>>,
+    /// Favicon URL (relative URLs are prefixed with `base`, like the logo).
+    pub favicon: Option<String>,
+    /// `theme-color` for light mode (e.g. `"#ffffff"`).
+    pub theme_color_light: Option<String>,
+    /// `theme-color` for dark mode.
+    pub theme_color_dark: Option<String>,
+    /// Emit schema.org JSON-LD (e.g. a `BreadcrumbList`) in `<head>` (default: false).
+    /// Requires `siteUrl`, since its URLs must be absolute.
+    pub json_ld: Option<bool>,
+}
+
+/// Not-found (404) page configuration for JavaScript.
+#[napi(object)]
+#[derive(Clone, Default)]
+pub struct JsThemeNotFound {
+    /// Page heading (default: "Page not found").
+    pub title: Option<String>,
+    /// Message shown below the heading.
+    pub message: Option<String>,
+    /// Text of the link back to the home page (default: "Back to home").
+    pub home_text: Option<String>,
 }
 
 /// SSG configuration.
@@ -1117,6 +1474,8 @@ pub struct JsSsgConfig {
     pub site_name: String,
     /// Base URL path.
     pub base: String,
+    /// Site origin (e.g. `"https://example.com"`), used where absolute URLs are required.
+    pub site_url: Option<String>,
     /// OG image URL.
     pub og_image: Option<String>,
     /// Theme configuration.
@@ -1125,6 +1484,42 @@ pub struct JsSsgConfig {
     pub locale: Option<String>,
     /// Available locales for locale switcher.
     pub available_locales: Option<Vec<JsLocaleInfo>>,
+    /// Site default locale; its alternate link is also advertised as `x-default`.
+    pub default_locale: Option<String>,
+    /// Translated UI chrome strings (English by default).
+    pub ui_strings: Option<JsUiStrings>,
+}
+
+/// Translated UI chrome strings for JavaScript.
+#[napi(object)]
+#[derive(Clone, Default)]
+pub struct JsUiStrings {
+    /// Skip link text.
+    pub skip_to_content: Option<String>,
+    /// Label of the header menu toggle.
+    pub toggle_menu: Option<String>,
+    /// Search button text and label.
+    pub search: Option<String>,
+    /// Search input placeholder.
+    pub search_placeholder: Option<String>,
+    /// Search footer hint for arrow keys.
+    pub search_navigate: Option<String>,
+    /// Search footer hint for Enter.
+    pub search_select: Option<String>,
+    /// Search footer hint for Esc.
+    pub search_close: Option<String>,
+    /// Label of the header theme toggle.
+    pub toggle_theme: Option<String>,
+    /// Mobile footer menu label.
+    pub menu: Option<String>,
+    /// Mobile footer theme label.
+    pub theme: Option<String>,
+    /// Outline heading.
+    pub on_this_page: Option<String>,
+    /// Code block copy button label.
+    pub copy_code: Option<String>,
+    /// Copy button label after copying.
+    pub copied: Option<String>,
 }
 
 /// Locale information for the locale switcher.
@@ -1195,6 +1590,23 @@ fn convert_theme_config(theme: Option<JsThemeConfig>) -> Option<ox_content_ssg::
         }),
         css: t.css,
         js: t.js,
+        print: t.print,
+        not_found: t.not_found.map(|n| ox_content_ssg::ThemeNotFound {
+            title: n.title,
+            message: n.message,
+            home_text: n.home_text,
+        }),
+        outline_position: t.outline_position,
+        outline_level: t.outline_level.and_then(|level| match level.as_slice() {
+            [min, max] => {
+                Some((u8::try_from(*min).unwrap_or(u8::MAX), u8::try_from(*max).unwrap_or(u8::MAX)))
+            }
+            _ => None,
+        }),
+        favicon: t.favicon,
+        theme_color_light: t.theme_color_light,
+        theme_color_dark: t.theme_color_dark,
+        json_ld: t.json_ld,
     })
 }
 
@@ -1244,42 +1656,32 @@ fn convert_entry_page_config(
     })
 }
 
-/// Generates SSG HTML page with navigation and search.
-#[napi]
-pub fn generate_ssg_html(
-    page_data: JsSsgPageData,
-    nav_groups: Vec<JsSsgNavGroup>,
-    config: JsSsgConfig,
-) -> String {
-    // Convert NAPI types to ox_content_ssg types
-    let ssg_page_data = ox_content_ssg::PageData {
-        title: page_data.title,
-        description: page_data.description,
-        content: page_data.content,
-        toc: page_data
-            .toc
-            .into_iter()
-            .map(|t| ox_content_ssg::TocEntry { depth: t.depth, text: t.text, slug: t.slug })
-            .collect(),
-        path: page_data.path,
-        entry_page: convert_entry_page_config(page_data.entry_page),
-    };
-
-    let ssg_nav_groups: Vec<ox_content_ssg::NavGroup> = nav_groups
+/// Converts JsSsgNavGroup values to ox_content_ssg::NavGroup.
+fn convert_nav_groups(nav_groups: Vec<JsSsgNavGroup>) -> Vec<ox_content_ssg::NavGroup> {
+    nav_groups
         .into_iter()
         .map(|g| ox_content_ssg::NavGroup {
             title: g.title,
             items: g
                 .items
                 .into_iter()
-                .map(|i| ox_content_ssg::NavItem { title: i.title, path: i.path, href: i.href })
+                .map(|i| ox_content_ssg::NavItem {
+                    title: i.title,
+                    path: i.path,
+                    href: i.href,
+                    icon: i.icon,
+                })
                 .collect(),
         })
-        .collect();
+        .collect()
+}
 
-    let ssg_config = ox_content_ssg::SsgConfig {
+/// Converts JsSsgConfig to ox_content_ssg::SsgConfig.
+fn convert_ssg_config(config: JsSsgConfig) -> ox_content_ssg::SsgConfig {
+    ox_content_ssg::SsgConfig {
         site_name: config.site_name,
         base: config.base,
+        site_url: config.site_url,
         og_image: config.og_image,
         theme: convert_theme_config(config.theme),
         locale: config.locale,
@@ -1289,11 +1691,198 @@ pub fn generate_ssg_html(
                 .map(|l| ox_content_ssg::LocaleInfo { code: l.code, name: l.name, dir: l.dir })
                 .collect()
         }),
+        default_locale: config.default_locale,
+        ui_strings: convert_ui_strings(config.ui_strings),
+    }
+}
+
+/// Converts JsUiStrings to ox_content_ssg::UiStrings, keeping English defaults for unset fields.
+fn convert_ui_strings(strings: Option<JsUiStrings>) -> ox_content_ssg::UiStrings {
+    let defaults = ox_content_ssg::UiStrings::default();
+    let Some(s) = strings else {
+        return defaults;
     };
+    ox_content_ssg::UiStrings {
+        skip_to_content: s.skip_to_content.unwrap_or(defaults.skip_to_content),
+        toggle_menu: s.toggle_menu.unwrap_or(defaults.toggle_menu),
+        search: s.search.unwrap_or(defaults.search),
+        search_placeholder: s.search_placeholder.unwrap_or(defaults.search_placeholder),
+        search_navigate: s.search_navigate.unwrap_or(defaults.search_navigate),
+        search_select: s.search_select.unwrap_or(defaults.search_select),
+        search_close: s.search_close.unwrap_or(defaults.search_close),
+        toggle_theme: s.toggle_theme.unwrap_or(defaults.toggle_theme),
+        menu: s.menu.unwrap_or(defaults.menu),
+        theme: s.theme.unwrap_or(defaults.theme),
+        on_this_page: s.on_this_page.unwrap_or(defaults.on_this_page),
+        copy_code: s.copy_code.unwrap_or(defaults.copy_code),
+        copied: s.copied.unwrap_or(defaults.copied),
+    }
+}
+
+/// Converts JsSsgPageData to ox_content_ssg::PageData; unknown layouts use the default.
+fn convert_page_data(page_data: JsSsgPageData) -> ox_content_ssg::PageData {
+    ox_content_ssg::PageData {
+        title: page_data.title,
+        description: page_data.description,
+        content: page_data.content,
+        toc: page_data
+            .toc
+            .into_iter()
+            .map(|t| ox_content_ssg::TocEntry { depth: t.depth, text: t.text, slug: t.slug })
+            .collect(),
+        path: page_data.path,
+        entry_page: convert_entry_page_config(page_data.entry_page),
+        head: page_data.head,
+        author: page_data.author,
+        published: page_data.published,
+        modified: page_data.modified,
+        layout: page_data.layout.and_then(|name| ox_content_ssg::PageLayout::parse(&name)),
+        alternates: page_data
+            .alternates
+            .unwrap_or_default()
+            .into_iter()
+            .map(|a| (a.locale, a.href))
+            .collect(),
+    }
+}
+
+/// Generates SSG HTML page with navigation and search.
+#[napi]
+pub fn generate_ssg_html(
+    page_data: JsSsgPageData,
+    nav_groups: Vec<JsSsgNavGroup>,
+    config: JsSsgConfig,
+) -> String {
+    // Convert NAPI types to ox_content_ssg types
+    let ssg_page_data = convert_page_data(page_data);
+    let ssg_nav_groups = convert_nav_groups(nav_groups);
+    let ssg_config = convert_ssg_config(config);
 
     ox_content_ssg::generate_html(&ssg_page_data, &ssg_nav_groups, &ssg_config)
 }
 
+/// Progress event emitted by `generateSsgHtmlBatch` after each page.
+#[napi(object)]
+pub struct JsSsgProgress {
+    /// Zero-based index of the completed page.
+    pub index: u32,
+    /// Total number of pages in the batch.
+    pub total: u32,
+    /// URL path of the completed page.
+    pub path: String,
+}
+
+/// Progress callback invoked from the worker thread.
+type SsgProgressCallback =
+    ThreadsafeFunction<JsSsgProgress, Unknown<'static>, JsSsgProgress, Status, false>;
+
+/// Generates HTML for each page in order, reporting progress after every page.
+fn generate_ssg_batch(
+    pages: &[ox_content_ssg::PageData],
+    nav_groups: &[ox_content_ssg::NavGroup],
+    config: &ox_content_ssg::SsgConfig,
+    mut on_progress: impl FnMut(usize, usize, &str),
+) -> Vec<String> {
+    let total = pages.len();
+    pages
+        .iter()
+        .enumerate()
+        .map(|(index, page)| {
+            let html = ox_content_ssg::generate_html(page, nav_groups, config);
+            on_progress(index, total, &page.path);
+            html
+        })
+        .collect()
+}
+
+/// Async task for SSG batch generation.
+pub struct SsgBatchTask {
+    pages: Vec<ox_content_ssg::PageData>,
+    nav_groups: Vec<ox_content_ssg::NavGroup>,
+    config: ox_content_ssg::SsgConfig,
+    on_progress: Option<SsgProgressCallback>,
+}
+
+impl Task for SsgBatchTask {
+    type Output = Vec<String>;
+    type JsValue = Vec<String>;
+
+    fn compute(&mut self) -> Result<Self::Output> {
+        let on_progress = self.on_progress.as_ref();
+        Ok(generate_ssg_batch(&self.pages, &self.nav_groups, &self.config, |index, total, path| {
+            if let Some(callback) = on_progress {
+                callback.call(
+                    JsSsgProgress {
+                        index: index as u32,
+                        total: total as u32,
+                        path: path.to_string(),
+                    },
+                    ThreadsafeFunctionCallMode::NonBlocking,
+                );
+            }
+        }))
+    }
+
+    fn resolve(&mut self, _env: Env, output: Self::Output) -> Result<Self::JsValue> {
+        Ok(output)
+    }
+}
+
+/// Generates SSG HTML for many pages on a worker thread.
+///
+/// `onProgress` is called with `{ index, total, path }` after each page completes.
+/// Resolves to the generated HTML in the same order as `pages`.
+#[napi(
+    ts_args_type = "pages: Array<JsSsgPageData>, navGroups: Array<JsSsgNavGroup>, config: JsSsgConfig, onProgress?: (progress: JsSsgProgress) => void"
+)]
+pub fn generate_ssg_html_batch(
+    pages: Vec<JsSsgPageData>,
+    nav_groups: Vec<JsSsgNavGroup>,
+    config: JsSsgConfig,
+    on_progress: Option<SsgProgressCallback>,
+) -> AsyncTask<SsgBatchTask> {
+    AsyncTask::new(SsgBatchTask {
+        pages: pages.into_iter().map(convert_page_data).collect(),
+        nav_groups: convert_nav_groups(nav_groups),
+        config: convert_ssg_config(config),
+        on_progress,
+    })
+}
+
+/// Generates a themed 404 page using the site chrome.
+#[napi]
+pub fn generate_ssg_404(nav_groups: Vec<JsSsgNavGroup>, config: JsSsgConfig) -> String {
+    let ssg_nav_groups = convert_nav_groups(nav_groups);
+    let ssg_config = convert_ssg_config(config);
+
+    ox_content_ssg::generate_404(&ssg_nav_groups, &ssg_config)
+}
+
+/// Converts JsSsgPageMeta values to ox_content_ssg::PageMeta.
+fn convert_page_metas(pages: Vec<JsSsgPageMeta>) -> Vec<ox_content_ssg::PageMeta> {
+    pages
+        .into_iter()
+        .map(|page| ox_content_ssg::PageMeta {
+            title: page.title,
+            path: page.path,
+            description: page.description,
+            content: page.content,
+        })
+        .collect()
+}
+
+/// Generates an `llms.txt` index listing each page's title, URL and description.
+#[napi]
+pub fn generate_llms_txt(pages: Vec<JsSsgPageMeta>, config: JsSsgConfig) -> String {
+    ox_content_ssg::generate_llms_txt(&convert_page_metas(pages), &convert_ssg_config(config))
+}
+
+/// Generates an `llms-full.txt` export with every page's plain-text content.
+#[napi]
+pub fn generate_llms_full_txt(pages: Vec<JsSsgPageMeta>, config: JsSsgConfig) -> String {
+    ox_content_ssg::generate_llms_full_txt(&convert_page_metas(pages), &convert_ssg_config(config))
+}
+
 /// Extracts searchable content from Markdown source.
 ///
 /// Parses the Markdown and extracts title, body text, headings, and code.
@@ -1309,27 +1898,70 @@ pub fn extract_search_content(
     let allocator = create_allocator_for_source(&content);
     let parser_options = options.map(ParserOptions::from).unwrap_or_default();
 
-    // Try to get title from frontmatter
-    let frontmatter_title = frontmatter.get("title").and_then(|v| v.as_str()).map(String::from);
-
     let parser = Parser::with_options(&allocator, &content, parser_options);
-
     let result = parser.parse();
-    let (title, body, headings, code) = if let Ok(ref doc) = result {
+    build_search_document(result.as_ref().ok(), &frontmatter, id, url)
+}
+
+/// Builds a search document from a parsed document (or `None` if parsing failed).
+///
+/// A frontmatter `title` takes precedence over the first `# heading`.
+fn build_search_document(
+    doc: Option<&Document>,
+    frontmatter: &HashMap<String, serde_json::Value>,
+    id: String,
+    url: String,
+) -> JsSearchDocument {
+    let frontmatter_title = frontmatter.get("title").and_then(|v| v.as_str()).map(String::from);
+
+    let (title, body, headings, heading_offsets, code) = if let Some(doc) = doc {
         let mut indexer = DocumentIndexer::new();
         indexer.extract(doc);
 
         let title = frontmatter_title
             .unwrap_or_else(|| indexer.title().map(String::from).unwrap_or_default());
 
-        (title, indexer.body().to_string(), indexer.headings().to_vec(), indexer.code().to_vec())
+        let heading_offsets =
+            indexer.heading_offsets().iter().map(|&offset| offset as u32).collect();
+        (
+            title,
+            indexer.body().to_string(),
+            indexer.headings().to_vec(),
+            Some(heading_offsets),
+            indexer.code().to_vec(),
+        )
     } else {
-        (frontmatter_title.unwrap_or_default(), String::new(), Vec::new(), Vec::new())
+        (frontmatter_title.unwrap_or_default(), String::new(), Vec::new(), None, Vec::new())
     };
-    // Explicitly drop the result to release the borrow
-    drop(result);
 
-    JsSearchDocument { id, title, url, body, headings, code }
+    JsSearchDocument { id, title, url, body, headings, heading_offsets, code }
+}
+
+/// Result of [`transform_and_index`].
+#[napi(object)]
+pub struct TransformAndIndexResult {
+    /// The same output as `transform`.
+    pub transform: TransformResult,
+    /// The same output as `extractSearchContent`.
+    pub search_document: JsSearchDocument,
+}
+
+/// Transforms Markdown and extracts its search document in a single parse.
+///
+/// Equivalent to calling `transform` and `extractSearchContent` on the same
+/// source, without parsing it twice.
+#[napi]
+pub fn transform_and_index(
+    source: String,
+    id: String,
+    url: String,
+    options: Option<JsTransformOptions>,
+) -> TransformAndIndexResult {
+    let (transform, search_document) =
+        run_transform(&source, &options.unwrap_or_default(), |doc, frontmatter| {
+            build_search_document(doc, frontmatter, id, url)
+        });
+    TransformAndIndexResult { transform, search_document }
 }
 
 // =============================================================================
@@ -1546,6 +2178,24 @@ pub struct Mf2ValidateResult {
     pub ast_json: Option<String>,
 }
 
+/// Result of MF2 source formatting.
+#[napi(object)]
+pub struct Mf2FormatSourceResult {
+    /// Canonical MF2 source, or the original text if parsing failed.
+    pub formatted: Option<String>,
+    /// Parse errors.
+    pub errors: Vec<String>,
+}
+
+/// Result of evaluating MF2 `.local` declarations.
+#[napi(object)]
+pub struct Mf2EvaluateLocalsResult {
+    /// Computed value of each local (if evaluation succeeded).
+    pub values: Option<HashMap<String, String>>,
+    /// Parse or evaluation errors.
+    pub errors: Vec<String>,
+}
+
 /// A single i18n diagnostic.
 #[napi(object)]
 pub struct I18nDiagnostic {
@@ -1564,6 +2214,10 @@ pub struct I18nDiagnostic {
 pub struct I18nCheckResult {
     /// All diagnostics.
     pub diagnostics: Vec<I18nDiagnostic>,
+    /// Translation keys that were checked, sorted.
+    pub used_keys: Vec<String>,
+    /// Number of diagnostics reported by each rule, keyed by rule name.
+    pub counts_by_rule: HashMap<String, u32>,
     /// Number of errors.
     pub error_count: u32,
     /// Number of warnings.
@@ -1608,6 +2262,26 @@ pub fn load_dictionaries_flat(dir: String) -> HashMap<String, HashMap<String, St
     result
 }
 
+/// Loads dictionaries from the given directory and returns them as one nested JSON string.
+///
+/// The result has the shape `{ "defaultLocale": ..., "locales": { "en": { "common": { ... } } } }`,
+/// ready to ship to a client i18n runtime without re-nesting the flat map.
+/// `defaultLocale` is `null` unless `default_locale` is given.
+///
+/// Fails if the directory cannot be loaded or `default_locale` is not a valid locale tag.
+#[napi]
+pub fn load_dictionaries_json(dir: String, default_locale: Option<String>) -> Result<String> {
+    let path = std::path::Path::new(&dir);
+    let mut set = ox_content_i18n::dictionary::load_from_dir(path)
+        .map_err(|err| Error::from_reason(err.to_string()))?;
+    if let Some(locale) = default_locale {
+        let locale = ox_content_i18n::Locale::new(&locale)
+            .map_err(|err| Error::from_reason(err.to_string()))?;
+        set.set_default_locale(locale);
+    }
+    Ok(set.to_json().to_string())
+}
+
 /// Validates an MF2 message string.
 ///
 /// Returns parsing and semantic validation results.
@@ -1623,6 +2297,41 @@ pub fn validate_mf2(message: String) -> Mf2ValidateResult {
     }
 }
 
+/// Formats an MF2 message as canonical source.
+///
+/// Used by editor "format document" actions on dictionary values. On parse
+/// failure the original text is returned unchanged along with the errors.
+#[napi]
+pub fn format_mf2_source(message: String) -> Mf2FormatSourceResult {
+    match ox_content_i18n::mf2::parse(&message) {
+        Ok(ast) => Mf2FormatSourceResult {
+            formatted: Some(ox_content_i18n::mf2::to_string(&ast)),
+            errors: vec![],
+        },
+        Err(e) => Mf2FormatSourceResult { formatted: Some(message), errors: vec![e.to_string()] },
+    }
+}
+
+/// Evaluates the `.local` declarations of an MF2 message for previews.
+///
+/// `args` supplies input variables. Locals are resolved in dependency order;
+/// cyclic declarations are reported as an error.
+#[napi]
+pub fn evaluate_mf2_locals(
+    message: String,
+    args: HashMap<String, String>,
+) -> Mf2EvaluateLocalsResult {
+    let args = args.into_iter().collect();
+    match ox_content_i18n::mf2::parse(&message)
+        .and_then(|ast| ox_content_i18n::mf2::evaluate_locals(&ast, &args))
+    {
+        Ok(values) => {
+            Mf2EvaluateLocalsResult { values: Some(values.into_iter().collect()), errors: vec![] }
+        }
+        Err(e) => Mf2EvaluateLocalsResult { values: None, errors: vec![e.to_string()] },
+    }
+}
+
 /// Runs i18n checks on dictionaries against used translation keys.
 ///
 /// `dict_dir` is the path to the i18n directory with locale subdirectories.
@@ -1640,6 +2349,8 @@ pub fn check_i18n(dict_dir: String, used_keys: Vec<String>) -> I18nCheckResult {
                     key: None,
                     locale: None,
                 }],
+                used_keys: vec![],
+                counts_by_rule: HashMap::new(),
                 error_count: 1,
                 warning_count: 0,
             };
@@ -1647,22 +2358,15 @@ pub fn check_i18n(dict_dir: String, used_keys: Vec<String>) -> I18nCheckResult {
     };
 
     let keys_set: std::collections::HashSet<String> = used_keys.into_iter().collect();
-    let diagnostics = ox_content_i18n::checker::check_all(&keys_set, &dict_set);
+    let result = ox_content_i18n_checker::check_keys(keys_set, &dict_set);
 
-    let mut error_count = 0u32;
-    let mut warning_count = 0u32;
-    let js_diagnostics: Vec<I18nDiagnostic> = diagnostics
+    let js_diagnostics: Vec<I18nDiagnostic> = result
+        .diagnostics
         .into_iter()
         .map(|d| {
             let severity = match d.severity {
-                ox_content_i18n::checker::Severity::Error => {
-                    error_count += 1;
-                    "error"
-                }
-                ox_content_i18n::checker::Severity::Warning => {
-                    warning_count += 1;
-                    "warning"
-                }
+                ox_content_i18n::checker::Severity::Error => "error",
+                ox_content_i18n::checker::Severity::Warning => "warning",
                 ox_content_i18n::checker::Severity::Info => "info",
             };
             I18nDiagnostic {
@@ -1674,7 +2378,21 @@ pub fn check_i18n(dict_dir: String, used_keys: Vec<String>) -> I18nCheckResult {
         })
         .collect();
 
-    I18nCheckResult { diagnostics: js_diagnostics, error_count, warning_count }
+    let mut used_keys: Vec<String> = result.used_keys.into_iter().collect();
+    used_keys.sort();
+    let counts_by_rule = result
+        .counts_by_rule
+        .into_iter()
+        .map(|(rule, count)| (rule.to_string(), count as u32))
+        .collect();
+
+    I18nCheckResult {
+        diagnostics: js_diagnostics,
+        used_keys,
+        counts_by_rule,
+        error_count: result.error_count as u32,
+        warning_count: result.warning_count as u32,
+    }
 }
 
 /// A translation key usage found in source code.
@@ -1724,3 +2442,261 @@ pub fn extract_translation_keys(
         Err(_) => vec![],
     }
 }
+
+/// Extracts translation keys from every matching file under a directory.
+///
+/// TS/JS files are parsed for `t('key')` calls and Markdown files for
+/// `{{t('key')}}` patterns. Defaults to the checker's extensions and function names.
+/// Throws if a directory cannot be read; a missing `dir` yields no keys.
+#[napi]
+pub fn extract_translation_keys_dir(
+    dir: String,
+    extensions: Option<Vec<String>>,
+    function_names: Option<Vec<String>>,
+) -> Result<Vec<I18nKeyUsage>> {
+    let defaults = ox_content_i18n_checker::CheckConfig::default();
+    let collector = ox_content_i18n_checker::key_collector::KeyCollector::with_function_names(
+        function_names.unwrap_or(defaults.function_names),
+    );
+    let extensions = extensions.unwrap_or(defaults.extensions);
+
+    let usages = ox_content_i18n_checker::collect_key_usages(
+        std::path::Path::new(&dir),
+        &collector,
+        &extensions,
+    )
+    .map_err(Error::from_reason)?;
+
+    Ok(usages
+        .into_iter()
+        .map(|u| I18nKeyUsage {
+            key: u.key,
+            file_path: u.file_path,
+            line: u.line,
+            column: u.column,
+            end_column: u.end_column,
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_extract_translation_keys_dir() {
+        let dir = std::env::temp_dir().join(format!("ox_napi_keys_{}", std::process::id()));
+        std::fs::create_dir_all(dir.join("pages")).unwrap();
+        std::fs::write(dir.join("app.ts"), "const a = t('app.title');\nt('app.body');\n").unwrap();
+        std::fs::write(dir.join("pages/guide.md"), "# Guide\n\n{{ $t('guide.intro') }}\n").unwrap();
+        std::fs::write(dir.join("notes.txt"), "t('ignored')").unwrap();
+
+        let usages =
+            extract_translation_keys_dir(dir.to_string_lossy().to_string(), None, None).unwrap();
+        std::fs::remove_dir_all(&dir).unwrap();
+
+        let keys: Vec<_> = usages.iter().map(|u| (u.key.as_str(), u.line)).collect();
+        assert_eq!(keys, [("app.title", 1), ("app.body", 2), ("guide.intro", 3)]);
+        assert!(usages[2].file_path.ends_with("guide.md"));
+    }
+
+    #[test]
+    fn test_transform_and_index_matches_separate_calls() {
+        let source =
+            "---\ntitle: Guide\n---\n# Intro\n\nSome *text*.\n\n```rust\nfn main() {}\n```\n";
+        let result = transform_and_index(
+            source.to_string(),
+            "guide".to_string(),
+            "/guide".to_string(),
+            None,
+        );
+
+        let transformed = transform(source.to_string(), None);
+        assert_eq!(result.transform.html, transformed.html);
+        assert_eq!(result.transform.frontmatter, transformed.frontmatter);
+        assert_eq!(result.transform.toc.len(), transformed.toc.len());
+
+        let doc = extract_search_content(
+            source.to_string(),
+            "guide".to_string(),
+            "/guide".to_string(),
+            None,
+        );
+        let indexed = result.search_document;
+        assert_eq!(indexed.title, "Guide");
+        assert_eq!(
+            (indexed.id, indexed.title, indexed.url, indexed.body, indexed.headings, indexed.code),
+            (doc.id, doc.title, doc.url, doc.body, doc.headings, doc.code)
+        );
+    }
+
+    #[test]
+    fn test_transform_toc_decodes_entities() {
+        let result = transform("## A &amp; B\n".to_string(), None);
+        assert_eq!(result.toc.len(), 1);
+        assert_eq!(result.toc[0].text, "A & B");
+        assert_eq!(result.toc[0].slug, "a-b");
+    }
+
+    #[test]
+    fn test_transform_toc_suffixes_duplicate_slugs() {
+        let opts = JsTransformOptions { heading_anchors: Some(true), ..Default::default() };
+        let result = transform("## Usage\n\n#### Usage\n\n## Usage\n".to_string(), Some(opts));
+        let slugs: Vec<_> = result.toc.iter().map(|entry| entry.slug.as_str()).collect();
+        assert_eq!(slugs, ["usage", "usage-2"]);
+        assert!(result.html.contains("<h2 id=\"usage-2\">"), "{}", result.html);
+    }
+
+    #[test]
+    fn test_transform_reports_positioned_diagnostic() {
+        let source = format!("---\ntitle: Deep\n---\n# Title\n\n{} deep\n", ">".repeat(102));
+        let result = transform(source, None);
+
+        assert!(result.html.is_empty());
+        assert_eq!(result.errors.len(), 1);
+        assert_eq!(result.diagnostics.len(), 1);
+        let diagnostic = &result.diagnostics[0];
+        assert_eq!(diagnostic.severity, "error");
+        assert!(diagnostic.message.contains("nesting too deep"));
+        // Nesting exceeds the default limit of 100 at the 102nd `>` on line 6.
+        assert_eq!((diagnostic.start_line, diagnostic.start_col), (6, 102));
+        assert_eq!((diagnostic.end_line, diagnostic.end_col), (6, 102));
+    }
+
+    #[test]
+    fn test_parse_frontmatter_only() {
+        let source = "---\ntitle: Hello\ndraft: true\n---\n# Body\n";
+        let result = parse_frontmatter_only(source.to_string());
+        let frontmatter: serde_json::Value = serde_json::from_str(&result.frontmatter).unwrap();
+
+        assert_eq!(frontmatter["title"], "Hello");
+        assert_eq!(frontmatter["draft"], true);
+        assert_eq!(&source[result.content_offset as usize..], "# Body\n");
+    }
+
+    #[test]
+    fn test_generate_ssg_batch_reports_progress() {
+        let page = |path: &str| ox_content_ssg::PageData {
+            title: path.to_string(),
+            content: format!("<p>{path}</p>"),
+            path: path.to_string(),
+            ..Default::default()
+        };
+        let pages = vec![page("index"), page("guide"), page("api")];
+        let config = ox_content_ssg::SsgConfig {
+            site_name: "Docs".to_string(),
+            base: "/".to_string(),
+            ..Default::default()
+        };
+
+        let mut events = Vec::new();
+        let html = generate_ssg_batch(&pages, &[], &config, |index, total, path| {
+            events.push((index, total, path.to_string()));
+        });
+
+        assert_eq!(html.len(), 3);
+        assert!(html[1].contains("<p>guide</p>"));
+        assert_eq!(
+            events,
+            vec![
+                (0, 3, "index".to_string()),
+                (1, 3, "guide".to_string()),
+                (2, 3, "api".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_check_i18n_reports_used_keys_and_rule_counts() {
+        let dict_dir = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/i18n");
+        let result = check_i18n(
+            dict_dir.to_string(),
+            vec!["common.missing".to_string(), "common.greeting".to_string()],
+        );
+
+        assert_eq!(result.used_keys, vec!["common.greeting", "common.missing"]);
+        assert_eq!(result.counts_by_rule.get("missing-key"), Some(&2));
+        assert_eq!(result.counts_by_rule.get("unused-key"), Some(&1));
+        assert_eq!(result.counts_by_rule.get("type-mismatch"), Some(&0));
+        assert_eq!(result.counts_by_rule.get("syntax-error"), Some(&0));
+        assert_eq!(result.error_count, 2);
+        assert_eq!(result.warning_count, 1);
+    }
+
+    #[test]
+    fn test_load_dictionaries_json_nests_locales() {
+        let dict_dir = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/i18n");
+        let json: serde_json::Value =
+            serde_json::from_str(&load_dictionaries_json(dict_dir.to_string(), None).unwrap())
+                .unwrap();
+
+        assert_eq!(json["locales"]["en"]["common"]["greeting"], "Hello, {$name}!");
+        assert_eq!(json["locales"]["en"]["common"]["farewell"], "Goodbye");
+        assert_eq!(json["locales"]["ja"]["common"]["greeting"], "こんにちは、{$name}!");
+        assert!(json["defaultLocale"].is_null());
+
+        let json: serde_json::Value = serde_json::from_str(
+            &load_dictionaries_json(dict_dir.to_string(), Some("en".to_string())).unwrap(),
+        )
+        .unwrap();
+        assert_eq!(json["defaultLocale"], "en");
+
+        // The JSON reads back into the same dictionaries.
+        let loaded =
+            ox_content_i18n::dictionary::load_from_dir(std::path::Path::new(dict_dir)).unwrap();
+        let restored = ox_content_i18n::DictionarySet::from_json(&json).unwrap();
+        assert_eq!(restored.default_locale().map(ox_content_i18n::Locale::as_str), Some("en"));
+        let mut locales: Vec<_> = restored.locales().collect();
+        locales.sort_unstable();
+        assert_eq!(locales, ["en", "ja"]);
+        for locale in locales {
+            let mut expected: Vec<_> = loaded.get(locale).unwrap().iter().collect();
+            let mut entries: Vec<_> = restored.get(locale).unwrap().iter().collect();
+            expected.sort_unstable();
+            entries.sort_unstable();
+            assert_eq!(entries, expected, "{locale}");
+        }
+
+        assert!(load_dictionaries_json("/nonexistent".to_string(), None).is_err());
+        assert!(load_dictionaries_json(dict_dir.to_string(), Some(" ".to_string())).is_err());
+    }
+
+    #[test]
+    fn test_parse_frontmatter_only_without_frontmatter() {
+        let result = parse_frontmatter_only("# Body\n".to_string());
+
+        assert_eq!(result.frontmatter, "{}");
+        assert_eq!(result.content_offset, 0);
+    }
+
+    #[test]
+    fn test_evaluate_mf2_locals() {
+        let args = HashMap::from([("x".to_string(), "3".to_string())]);
+        let result = evaluate_mf2_locals(
+            ".local $a = {$x :number}\n.local $b = {$a}\n{{{$b}}}".to_string(),
+            args.clone(),
+        );
+        assert!(result.errors.is_empty());
+        assert_eq!(result.values.unwrap().get("b").map(String::as_str), Some("3"));
+
+        let cyclic = evaluate_mf2_locals(".local $a = {$a}\n{{{$a}}}".to_string(), args);
+        assert!(cyclic.values.is_none());
+        assert_eq!(cyclic.errors.len(), 1);
+    }
+
+    #[test]
+    fn test_format_mf2_source_is_idempotent() {
+        let source = ".input {$n   :number}\n.match $n\n  one {{One item}}\n*{{{$n} items}}";
+        let first = format_mf2_source(source.to_string());
+        assert!(first.errors.is_empty());
+        let formatted = first.formatted.unwrap();
+        assert_eq!(formatted, ".input {$n :number}\n.match $n\none {{One item}}\n* {{{$n} items}}");
+
+        let second = format_mf2_source(formatted.clone());
+        assert_eq!(second.formatted.as_deref(), Some(formatted.as_str()));
+
+        let invalid = format_mf2_source("Hello {$name".to_string());
+        assert_eq!(invalid.formatted.as_deref(), Some("Hello {$name"));
+        assert_eq!(invalid.errors.len(), 1);
+    }
+}
@@ -1,8 +1,9 @@
 use ox_content_allocator::Vec as ArenaVec;
 use ox_content_ast::{
-    AlignKind, BlockQuote, CodeBlock, Definition, Delete, Document, Emphasis, FootnoteDefinition,
-    FootnoteReference, Heading, Html, Image, InlineCode, Link, List, ListItem, Node, Paragraph,
-    Strong, Table, TableCell, TableRow, Text, ThematicBreak,
+    AlignKind, BlockQuote, CodeBlock, Container, Definition, Delete, DescriptionDetails,
+    DescriptionList, DescriptionTerm, Document, Emphasis, FootnoteDefinition, FootnoteReference,
+    Heading, Html, Image, InlineCode, Link, List, ListItem, Mark, Node, Paragraph, Strong,
+    Subscript, Superscript, Table, TableCell, TableRow, Text, ThematicBreak,
 };
 
 pub fn to_mdast_json(document: &Document<'_>) -> String {
@@ -78,14 +79,22 @@ impl MdastJsonSerializer {
             Node::CodeBlock(node) => self.write_code_block(node),
             Node::Html(node) => self.write_html(node),
             Node::Table(node) => self.write_table(node),
+            Node::DescriptionList(node) => self.write_description_list(node),
+            Node::DescriptionTerm(node) => self.write_description_term(node),
+            Node::DescriptionDetails(node) => self.write_description_details(node),
+            Node::Container(node) => self.write_container(node),
             Node::Text(node) => self.write_text(node),
             Node::Emphasis(node) => self.write_emphasis(node),
             Node::Strong(node) => self.write_strong(node),
             Node::InlineCode(node) => self.write_inline_code(node),
-            Node::Break(_) => self.output.push_str("{\"type\":\"break\"}"),
+            Node::Break(node) if node.hard => self.output.push_str("{\"type\":\"break\"}"),
+            Node::Break(_) => self.output.push_str("{\"type\":\"text\",\"value\":\"\\n\"}"),
             Node::Link(node) => self.write_link(node),
             Node::Image(node) => self.write_image(node),
             Node::Delete(node) => self.write_delete(node),
+            Node::Mark(node) => self.write_mark(node),
+            Node::Subscript(node) => self.write_subscript(node),
+            Node::Superscript(node) => self.write_superscript(node),
             Node::FootnoteReference(node) => self.write_footnote_reference(node),
             Node::Definition(node) => self.write_definition(node),
             Node::FootnoteDefinition(node) => self.write_footnote_definition(node),
@@ -171,6 +180,36 @@ impl MdastJsonSerializer {
         self.output.push('}');
     }
 
+    fn write_description_list(&mut self, description_list: &DescriptionList<'_>) {
+        self.output.push_str("{\"type\":\"descriptionList\",\"children\":");
+        self.write_nodes(&description_list.children);
+        self.output.push('}');
+    }
+
+    fn write_description_term(&mut self, description_term: &DescriptionTerm<'_>) {
+        self.output.push_str("{\"type\":\"descriptionTerm\",\"children\":");
+        self.write_nodes(&description_term.children);
+        self.output.push('}');
+    }
+
+    fn write_description_details(&mut self, description_details: &DescriptionDetails<'_>) {
+        self.output.push_str("{\"type\":\"descriptionDetails\",\"children\":");
+        self.write_nodes(&description_details.children);
+        self.output.push('}');
+    }
+
+    fn write_container(&mut self, container: &Container<'_>) {
+        self.output.push_str("{\"type\":\"containerDirective\",\"name\":");
+        self.write_string(container.kind);
+        if let Some(title) = container.title {
+            self.output.push_str(",\"title\":");
+            self.write_string(title);
+        }
+        self.output.push_str(",\"children\":");
+        self.write_nodes(&container.children);
+        self.output.push('}');
+    }
+
     fn write_table_row(&mut self, row: &TableRow<'_>) {
         self.output.push_str("{\"type\":\"tableRow\",\"children\":");
         self.write_table_cells(&row.children);
@@ -237,6 +276,24 @@ impl MdastJsonSerializer {
         self.output.push('}');
     }
 
+    fn write_mark(&mut self, mark: &Mark<'_>) {
+        self.output.push_str("{\"type\":\"mark\",\"children\":");
+        self.write_nodes(&mark.children);
+        self.output.push('}');
+    }
+
+    fn write_subscript(&mut self, subscript: &Subscript<'_>) {
+        self.output.push_str("{\"type\":\"subscript\",\"children\":");
+        self.write_nodes(&subscript.children);
+        self.output.push('}');
+    }
+
+    fn write_superscript(&mut self, superscript: &Superscript<'_>) {
+        self.output.push_str("{\"type\":\"superscript\",\"children\":");
+        self.write_nodes(&superscript.children);
+        self.output.push('}');
+    }
+
     fn write_footnote_reference(&mut self, footnote_ref: &FootnoteReference<'_>) {
         self.output.push_str("{\"type\":\"footnoteReference\",\"identifier\":");
         self.write_string(footnote_ref.identifier);
@@ -19,6 +19,12 @@ pub struct OgImageConfig {
     pub description_font_size: u32,
     /// Font family name.
     pub font_family: Option<String>,
+    /// TTF/OTF font bytes to embed in the image.
+    ///
+    /// When set, text is wrapped using the font's glyph advances and the font
+    /// is embedded so the image renders the same everywhere.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub font_data: Option<Vec<u8>>,
     /// Logo path.
     pub logo_path: Option<String>,
     /// Output format.
@@ -35,6 +41,7 @@ impl Default for OgImageConfig {
             title_font_size: 70,
             description_font_size: 28,
             font_family: None,
+            font_data: None,
             logo_path: None,
             format: ImageFormat::Png,
         }
@@ -0,0 +1,147 @@
+//! Embedded font support: glyph advance measurement and SVG embedding.
+
+use base64::Engine;
+use ttf_parser::{Face, GlyphId};
+
+use crate::generator::{OgImageError, OgImageResult};
+
+/// Font family name the embedded font is registered under in the SVG.
+pub const EMBEDDED_FONT_FAMILY: &str = "OxContentOgFont";
+
+/// Horizontal metrics read from a TrueType or OpenType font.
+#[derive(Debug, Clone)]
+pub struct FontMetrics {
+    data: Vec<u8>,
+    units_per_em: u16,
+}
+
+impl FontMetrics {
+    /// Parses a TTF/OTF font.
+    pub fn parse(data: Vec<u8>) -> OgImageResult<Self> {
+        let face = Face::parse(&data, 0).map_err(|e| OgImageError::FontLoad(e.to_string()))?;
+        let units_per_em = face.units_per_em();
+        Ok(Self { data, units_per_em })
+    }
+
+    /// Returns the raw font bytes.
+    #[must_use]
+    pub fn data(&self) -> &[u8] {
+        &self.data
+    }
+
+    /// Returns the width of `text` in pixels at `font_size`.
+    ///
+    /// `letter_spacing` is added after every character, as SVG does.
+    /// Characters the font lacks are measured as its `.notdef` glyph.
+    #[must_use]
+    pub fn measure(&self, text: &str, font_size: f64, letter_spacing: f64) -> f64 {
+        // The data was validated in `parse`.
+        let Ok(face) = Face::parse(&self.data, 0) else {
+            return 0.0;
+        };
+        let scale = font_size / f64::from(self.units_per_em);
+        text.chars()
+            .map(|ch| {
+                let glyph = face.glyph_index(ch).unwrap_or(GlyphId(0));
+                let advance = face.glyph_hor_advance(glyph).unwrap_or(0);
+                f64::from(advance).mul_add(scale, letter_spacing)
+            })
+            .sum()
+    }
+
+    /// Returns an `@font-face` rule embedding the font as a data URL.
+    #[must_use]
+    pub fn font_face_css(&self) -> String {
+        let mime = if self.data.starts_with(b"OTTO") { "font/otf" } else { "font/ttf" };
+        format!(
+            "@font-face {{ font-family: \"{EMBEDDED_FONT_FAMILY}\"; src: url(data:{mime};base64,{}); }}",
+            base64::engine::general_purpose::STANDARD.encode(&self.data)
+        )
+    }
+}
+
+/// Builds a minimal TrueType font for tests.
+///
+/// Printable ASCII maps to glyphs `1..=95` with an advance of 500 units per
+/// 1000 em, except for the given overrides.
+#[cfg(test)]
+pub fn test_font(overrides: &[(char, u16)]) -> Vec<u8> {
+    let be16 = |out: &mut Vec<u8>, value: u16| out.extend_from_slice(&value.to_be_bytes());
+
+    let mut head = vec![0; 54];
+    head[18..20].copy_from_slice(&1000u16.to_be_bytes());
+    let mut hhea = vec![0; 36];
+    hhea[34..36].copy_from_slice(&96u16.to_be_bytes());
+    let mut maxp = vec![0, 0, 0x50, 0];
+    be16(&mut maxp, 96);
+
+    let mut hmtx = Vec::new();
+    for glyph in 0u16..96 {
+        let ch = char::from_u32(u32::from(glyph) + 0x1f).unwrap_or('\0');
+        let advance = overrides.iter().find(|(c, _)| *c == ch).map_or(500, |(_, a)| *a);
+        be16(&mut hmtx, advance);
+        be16(&mut hmtx, 0);
+    }
+
+    // cmap with one format 4 subtable: 0x20..=0x7e plus the 0xffff terminator.
+    let mut cmap = Vec::new();
+    for value in [0, 1, 3, 1] {
+        be16(&mut cmap, value);
+    }
+    cmap.extend_from_slice(&12u32.to_be_bytes());
+    for value in [4, 32, 0, 4, 4, 1, 0, 0x7e, 0xffff, 0, 0x20, 0xffff] {
+        be16(&mut cmap, value);
+    }
+    for value in [1u16.wrapping_sub(0x20), 1, 0, 0] {
+        be16(&mut cmap, value);
+    }
+
+    let tables: [(&[u8; 4], Vec<u8>); 5] =
+        [(b"cmap", cmap), (b"head", head), (b"hhea", hhea), (b"hmtx", hmtx), (b"maxp", maxp)];
+    let mut font = vec![0, 1, 0, 0];
+    be16(&mut font, 5);
+    font.extend_from_slice(&[0; 6]);
+    let mut offset = 12 + tables.len() * 16;
+    for (tag, data) in &tables {
+        font.extend_from_slice(*tag);
+        font.extend_from_slice(&0u32.to_be_bytes());
+        font.extend_from_slice(&(offset as u32).to_be_bytes());
+        font.extend_from_slice(&(data.len() as u32).to_be_bytes());
+        offset += data.len();
+    }
+    for (_, data) in tables {
+        font.extend(data);
+    }
+    font
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn measures_glyph_advances() {
+        let font = FontMetrics::parse(test_font(&[('W', 1000), ('i', 250)])).unwrap();
+        assert!((font.measure("W", 100.0, 0.0) - 100.0).abs() < 1e-9);
+        assert!((font.measure("ii", 100.0, 0.0) - 50.0).abs() < 1e-9);
+        assert!((font.measure("ab", 10.0, -1.0) - 8.0).abs() < 1e-9);
+        // Unmapped characters fall back to glyph 0.
+        assert!((font.measure("é", 100.0, 0.0) - 50.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn rejects_non_font_data() {
+        assert!(matches!(
+            FontMetrics::parse(b"not a font".to_vec()),
+            Err(OgImageError::FontLoad(_))
+        ));
+    }
+
+    #[test]
+    fn embeds_font_as_data_url() {
+        let data = test_font(&[]);
+        let css = FontMetrics::parse(data.clone()).unwrap().font_face_css();
+        let encoded = base64::engine::general_purpose::STANDARD.encode(&data);
+        assert!(css.contains(&format!("url(data:font/ttf;base64,{encoded})")), "{css}");
+    }
+}
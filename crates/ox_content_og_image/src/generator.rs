@@ -1,7 +1,12 @@
 //! OG image generator.
 
-use crate::config::OgImageConfig;
+use std::sync::Arc;
+
+use crate::config::{ImageFormat, OgImageConfig};
+use crate::font::{FontMetrics, EMBEDDED_FONT_FAMILY};
 use crate::template::{OgImageData, OgImageTemplate};
+use resvg::usvg::fontdb::{Language, Source};
+use resvg::{tiny_skia, usvg};
 use thiserror::Error;
 
 /// Result type for OG image operations.
@@ -27,23 +32,39 @@ pub enum OgImageError {
     InvalidConfig(String),
 }
 
+/// Horizontal margin around text, in pixels.
+const MARGIN_X: u32 = 64;
+
+/// Letter spacing applied to the title, in pixels.
+const TITLE_LETTER_SPACING: f64 = -3.8;
+
 /// OG image generator.
 pub struct OgImageGenerator {
     config: OgImageConfig,
     template: OgImageTemplate,
+    font: Option<FontMetrics>,
 }
 
 impl OgImageGenerator {
     /// Creates a new generator with the given configuration.
+    ///
+    /// Invalid `font_data` is ignored; use [`Self::try_new`] to reject it.
     #[must_use]
     pub fn new(config: OgImageConfig) -> Self {
-        Self { config, template: OgImageTemplate::default() }
+        Self::with_template(config, OgImageTemplate::default())
+    }
+
+    /// Creates a new generator, failing if `font_data` is not a usable font.
+    pub fn try_new(config: OgImageConfig) -> OgImageResult<Self> {
+        let font = config.font_data.clone().map(FontMetrics::parse).transpose()?;
+        Ok(Self { config, template: OgImageTemplate::default(), font })
     }
 
     /// Creates a new generator with custom config and template.
     #[must_use]
     pub fn with_template(config: OgImageConfig, template: OgImageTemplate) -> Self {
-        Self { config, template }
+        let font = config.font_data.clone().and_then(|data| FontMetrics::parse(data).ok());
+        Self { config, template, font }
     }
 
     /// Returns the current configuration.
@@ -60,12 +81,39 @@ impl OgImageGenerator {
 
     /// Generates an OG image for the given data.
     ///
-    /// Returns the image as a byte vector.
+    /// Rasterizes [`Self::generate_svg`] and returns the encoded image. Only
+    /// [`ImageFormat::Png`] is supported. Text is drawn with the embedded font
+    /// (`font_data`); installed fonts are not used.
     pub fn generate(&self, data: &OgImageData) -> OgImageResult<Vec<u8>> {
-        // TODO: Implement actual image generation using an image library
-        // For now, return a placeholder
-        let _ = data;
-        Err(OgImageError::Encode("Image generation not yet implemented".to_string()))
+        if self.config.format != ImageFormat::Png {
+            return Err(OgImageError::Encode(format!(
+                "unsupported image format {:?}",
+                self.config.format
+            )));
+        }
+
+        let mut options = usvg::Options::default();
+        if let Some(font) = &self.font {
+            let fontdb = options.fontdb_mut();
+            for id in fontdb.load_font_source(Source::Binary(Arc::new(font.data().to_vec()))) {
+                // Register the face under the family name the SVG refers to.
+                if let Some(mut info) = fontdb.face(id).cloned() {
+                    fontdb.remove_face(id);
+                    info.families =
+                        vec![(EMBEDDED_FONT_FAMILY.to_string(), Language::English_UnitedStates)];
+                    fontdb.push_face_info(info);
+                }
+            }
+        }
+
+        let tree = usvg::Tree::from_str(&self.generate_svg(data), &options)
+            .map_err(|e| OgImageError::Encode(e.to_string()))?;
+        let mut pixmap =
+            tiny_skia::Pixmap::new(self.config.width, self.config.height).ok_or_else(|| {
+                OgImageError::InvalidConfig("width and height must be non-zero".to_string())
+            })?;
+        resvg::render(&tree, tiny_skia::Transform::default(), &mut pixmap.as_mut());
+        pixmap.encode_png().map_err(|e| OgImageError::Encode(e.to_string()))
     }
 
     /// Generates an OG image and saves it to a file.
@@ -90,6 +138,13 @@ impl OgImageGenerator {
         let text_color = &self.config.text_color;
         let font_family =
             self.config.font_family.as_deref().unwrap_or("IBM Plex Sans, system-ui, sans-serif");
+        let (font_family, font_style) = match &self.font {
+            Some(font) => (
+                format!("{EMBEDDED_FONT_FAMILY}, {font_family}"),
+                format!("<style>{}</style>", font.font_face_css()),
+            ),
+            None => (font_family.to_string(), String::new()),
+        };
         let brand = data
             .site_name
             .as_deref()
@@ -108,7 +163,8 @@ impl OgImageGenerator {
                 .to_string()
         };
 
-        let title_lines = wrap_text_limited(&hero_title, 28, 2);
+        let title_lines =
+            self.wrap(&hero_title, self.config.title_font_size, TITLE_LETTER_SPACING, 28);
         let title_line_height = u64::from(self.config.title_font_size) + 10;
         let title_svg = title_lines.iter().enumerate().fold(String::new(), |mut acc, (i, line)| {
             use std::fmt::Write;
@@ -116,14 +172,15 @@ impl OgImageGenerator {
             let y = 300_u64.saturating_add(line_index.saturating_mul(title_line_height));
             let _ = write!(
                 acc,
-                r#"<text x="64" y="{y}" fill="{text_color}" font-size="{}" font-weight="700" letter-spacing="-3.8px" font-family="{font_family}">{}</text>"#,
+                r#"<text x="{MARGIN_X}" y="{y}" fill="{text_color}" font-size="{}" font-weight="700" letter-spacing="{TITLE_LETTER_SPACING}px" font-family="{font_family}">{}</text>"#,
                 self.config.title_font_size,
                 escape_xml(line)
             );
             acc
         });
 
-        let description_lines = wrap_text_limited(&hero_description, 56, 2);
+        let description_lines =
+            self.wrap(&hero_description, self.config.description_font_size, 0.0, 56);
         let title_line_offset =
             u64::try_from(title_lines.len().saturating_sub(1)).unwrap_or(u64::MAX);
         let description_start_y = 300_u64
@@ -141,7 +198,7 @@ impl OgImageGenerator {
                         .saturating_add(line_index.saturating_mul(description_line_height));
                     let _ = write!(
                         acc,
-                        r##"<text x="64" y="{y}" fill="#93a4c3" font-size="{}" font-family="{font_family}">{}</text>"##,
+                        r##"<text x="{MARGIN_X}" y="{y}" fill="#93a4c3" font-size="{}" font-family="{font_family}">{}</text>"##,
                         self.config.description_font_size,
                         escape_xml(line)
                     );
@@ -155,6 +212,7 @@ impl OgImageGenerator {
   <rect width="100%" height="4" fill="#4f6fae"/>
 
   <defs>
+    {font_style}
     <linearGradient id="brand_mark_gradient" x1="138" y1="118" x2="360" y2="392" gradientUnits="userSpaceOnUse">
       <stop offset="0%" stop-color="#355cff"/>
       <stop offset="100%" stop-color="#74c7ff"/>
@@ -179,19 +237,40 @@ impl OgImageGenerator {
     }
 }
 
+impl OgImageGenerator {
+    /// Wraps text to the content width, measuring with the embedded font when
+    /// there is one and counting characters (`max_chars`) otherwise.
+    fn wrap(
+        &self,
+        text: &str,
+        font_size: u32,
+        letter_spacing: f64,
+        max_chars: usize,
+    ) -> Vec<String> {
+        let max_width = f64::from(self.config.width.saturating_sub(2 * MARGIN_X));
+        match &self.font {
+            Some(font) => wrap_text_limited(text, 2, |line| {
+                font.measure(line, f64::from(font_size), letter_spacing) <= max_width
+            }),
+            None => wrap_text_limited(text, 2, |line| line.len() <= max_chars),
+        }
+    }
+}
+
 fn normalize_for_compare(value: &str) -> String {
     value.chars().filter(|ch| !ch.is_whitespace()).flat_map(char::to_lowercase).collect()
 }
 
-/// Wraps text into lines of approximately max_chars length.
-fn wrap_text_limited(text: &str, max_chars: usize, max_lines: usize) -> Vec<String> {
+/// Wraps text into at most `max_lines` lines, breaking before a word when the
+/// extended line no longer `fits`.
+fn wrap_text_limited(text: &str, max_lines: usize, fits: impl Fn(&str) -> bool) -> Vec<String> {
     let mut lines = Vec::new();
     let mut current_line = String::new();
 
     for word in text.split_whitespace() {
         if current_line.is_empty() {
             current_line = word.to_string();
-        } else if current_line.len() + 1 + word.len() <= max_chars {
+        } else if fits(&format!("{current_line} {word}")) {
             current_line.push(' ');
             current_line.push_str(word);
         } else {
@@ -252,4 +331,65 @@ mod tests {
         assert!(svg.contains("Test Title"));
         assert!(svg.contains("Test description"));
     }
+
+    #[test]
+    fn test_embedded_font_wraps_at_measured_width() {
+        let data = OgImageData {
+            title: "WWWW WWWW WWWW WWWW WWWW".to_string(),
+            description: None,
+            site_name: None,
+            author: None,
+            date: None,
+            tags: vec![],
+        };
+
+        // Without a font, the title wraps by character count.
+        let svg = OgImageGenerator::default().generate_svg(&data);
+        assert!(svg.contains(">WWWW WWWW WWWW WWWW WWWW</text>"));
+        assert!(!svg.contains("@font-face"));
+
+        // `W` is a full em wide, so only three words fit in the 1072px content width.
+        let config = OgImageConfig {
+            font_data: Some(crate::font::test_font(&[('W', 1000)])),
+            ..OgImageConfig::default()
+        };
+        let svg = OgImageGenerator::try_new(config).unwrap().generate_svg(&data);
+        assert!(svg.contains(">WWWW WWWW WWWW</text>"));
+        assert!(svg.contains(">WWWW WWWW</text>"));
+        assert!(svg.contains("src: url(data:font/ttf;base64,"));
+        assert!(svg.contains("font-family=\"OxContentOgFont, IBM Plex Sans"));
+
+        let config =
+            OgImageConfig { font_data: Some(b"not a font".to_vec()), ..OgImageConfig::default() };
+        assert!(matches!(OgImageGenerator::try_new(config), Err(OgImageError::FontLoad(_))));
+    }
+
+    #[test]
+    fn test_generate_png() {
+        let data = OgImageData {
+            title: "Test Title".to_string(),
+            description: None,
+            site_name: None,
+            author: None,
+            date: None,
+            tags: vec![],
+        };
+        let config = OgImageConfig {
+            width: 600,
+            height: 315,
+            font_data: Some(crate::font::test_font(&[])),
+            ..OgImageConfig::default()
+        };
+
+        let png = OgImageGenerator::try_new(config).unwrap().generate(&data).unwrap();
+        assert!(png.starts_with(b"\x89PNG\r\n\x1a\n"));
+        // IHDR holds the big-endian width and height.
+        assert_eq!(png[16..24], [0, 0, 2, 88, 0, 0, 1, 59]);
+
+        let config = OgImageConfig { format: ImageFormat::WebP, ..OgImageConfig::default() };
+        assert!(matches!(
+            OgImageGenerator::new(config).generate(&data),
+            Err(OgImageError::Encode(_))
+        ));
+    }
 }
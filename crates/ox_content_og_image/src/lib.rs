@@ -4,9 +4,11 @@
 //! for documentation pages, creating social media preview images.
 
 mod config;
+mod font;
 mod generator;
 mod template;
 
 pub use config::{ImageFormat, OgImageConfig};
+pub use font::FontMetrics;
 pub use generator::{OgImageError, OgImageGenerator, OgImageResult};
 pub use template::{OgImageData, OgImageTemplate, TemplateLayout};
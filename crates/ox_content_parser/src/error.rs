@@ -57,4 +57,17 @@ impl ParseError {
             | Self::NestingTooDeep { span, .. } => *span,
         }
     }
+
+    /// Returns the error with its span offsets translated by `map`.
+    #[must_use]
+    pub(crate) fn map_span(mut self, map: impl Fn(u32) -> u32) -> Self {
+        let span = match &mut self {
+            Self::UnexpectedToken { span, .. }
+            | Self::UnexpectedEof { span }
+            | Self::InvalidSyntax { span, .. }
+            | Self::NestingTooDeep { span, .. } => span,
+        };
+        *span = Span::new(map(span.start), map(span.end));
+        self
+    }
 }
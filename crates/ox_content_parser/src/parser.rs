@@ -1,15 +1,19 @@
 //! Markdown parser implementation.
 
+use std::collections::HashMap;
+use std::rc::Rc;
+
 use ox_content_allocator::{Allocator, Vec};
 use ox_content_ast::{
-    AlignKind, BlockQuote, Document, Html, Image, Link, List, ListItem, Node, Paragraph, Span,
-    Table, TableCell, TableRow, Text,
+    decode_entity, AlignKind, BlockQuote, Container, Definition, DescriptionDetails,
+    DescriptionList, DescriptionTerm, Document, FootnoteDefinition, FootnoteReference, Html, Image,
+    Link, List, ListDelimiter, ListItem, Node, Paragraph, Span, Table, TableCell, TableRow, Text,
 };
 
 use crate::error::{ParseError, ParseResult};
 
 /// Parser options.
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone)]
 pub struct ParserOptions {
     /// Enable GFM (GitHub Flavored Markdown) extensions.
     pub gfm: bool,
@@ -23,8 +27,52 @@ pub struct ParserOptions {
     pub strikethrough: bool,
     /// Enable autolinks.
     pub autolinks: bool,
+    /// Enable definition lists (`Term` followed by `: Definition`).
+    pub definition_lists: bool,
+    /// Enable `==highlighted==` text.
+    pub mark: bool,
+    /// Enable `~subscript~` and `^superscript^` text.
+    ///
+    /// A single `~` is subscript; `~~` remains strikethrough.
+    pub sub_sup: bool,
+    /// Enable `:::kind title` ... `:::` custom containers.
+    ///
+    /// Containers nest; the closing line is three or more colons.
+    pub containers: bool,
+    /// Parse lines indented by four or more spaces (or a tab) as code blocks.
+    ///
+    /// Off by default, unlike CommonMark: list items, containers and definition
+    /// lists do not absorb indented continuation lines yet, so enabling it
+    /// turns those into code.
+    /// When disabled, indented lines are parsed like unindented ones, so an
+    /// indented paragraph stays a paragraph.
+    pub indented_code: bool,
     /// Maximum nesting depth for block elements.
     pub max_nesting_depth: usize,
+    /// Record parse errors and keep going instead of aborting on the first one.
+    ///
+    /// Blocks that fail to parse are kept as plain-text paragraphs.
+    pub recover: bool,
+}
+
+impl Default for ParserOptions {
+    fn default() -> Self {
+        Self {
+            gfm: false,
+            footnotes: false,
+            task_lists: false,
+            tables: false,
+            strikethrough: false,
+            autolinks: false,
+            definition_lists: false,
+            mark: false,
+            sub_sup: false,
+            containers: false,
+            indented_code: false,
+            max_nesting_depth: 100,
+            recover: false,
+        }
+    }
 }
 
 impl ParserOptions {
@@ -38,7 +86,13 @@ impl ParserOptions {
             tables: true,
             strikethrough: true,
             autolinks: true,
+            definition_lists: false,
+            mark: false,
+            sub_sup: false,
+            containers: false,
+            indented_code: false,
             max_nesting_depth: 100,
+            recover: false,
         }
     }
 }
@@ -55,11 +109,19 @@ pub struct Parser<'a> {
     position: usize,
     /// Current nesting depth.
     nesting_depth: usize,
+    /// Errors collected so far.
+    errors: std::vec::Vec<ParseError>,
+    /// Link reference definitions by normalized label, shared with sub-parsers.
+    definitions: Option<Rc<HashMap<String, LinkTarget<'a>>>>,
 }
 
+/// URL and title of a link reference definition.
+type LinkTarget<'a> = (&'a str, Option<&'a str>);
+
 struct ParsedListItem<'a> {
     ordered: bool,
     start: Option<u32>,
+    delimiter: Option<ListDelimiter>,
     content: &'a str,
     content_offset: usize,
     checked: Option<bool>,
@@ -69,27 +131,200 @@ impl<'a> Parser<'a> {
     /// Creates a new parser with default options.
     #[must_use]
     pub fn new(allocator: &'a Allocator, source: &'a str) -> Self {
-        Self { allocator, source, options: ParserOptions::default(), position: 0, nesting_depth: 0 }
+        Self::with_options(allocator, source, ParserOptions::default())
     }
 
     /// Creates a new parser with the specified options.
+    ///
+    /// A leading UTF-8 byte order mark is skipped; spans still index `source`.
     #[must_use]
     pub fn with_options(allocator: &'a Allocator, source: &'a str, options: ParserOptions) -> Self {
-        Self { allocator, source, options, position: 0, nesting_depth: 0 }
+        Self {
+            allocator,
+            source,
+            options,
+            position: if source.starts_with('\u{feff}') { '\u{feff}'.len_utf8() } else { 0 },
+            nesting_depth: 0,
+            errors: std::vec::Vec::new(),
+            definitions: None,
+        }
     }
 
     /// Parses the source into a document AST.
-    pub fn parse(mut self) -> ParseResult<Document<'a>> {
+    ///
+    /// Returns the first error unless [`ParserOptions::recover`] is set, in
+    /// which case errors are dropped; use [`Parser::parse_with_errors`] to
+    /// inspect them.
+    pub fn parse(self) -> ParseResult<Document<'a>> {
+        let recover = self.options.recover;
+        let (document, errors) = self.parse_with_errors();
+        match errors.into_iter().next() {
+            Some(error) if !recover => Err(error),
+            _ => Ok(document),
+        }
+    }
+
+    /// Parses the source, returning the document together with any errors.
+    ///
+    /// Without [`ParserOptions::recover`], parsing stops at the first error
+    /// and the document holds the blocks parsed before it.
+    pub fn parse_with_errors(mut self) -> (Document<'a>, std::vec::Vec<ParseError>) {
+        if self.definitions.is_none() {
+            self.collect_link_definitions();
+        }
         let mut children = self.allocator.new_vec();
 
         while !self.is_at_end() {
-            if let Some(node) = self.parse_block()? {
-                children.push(node);
+            match self.parse_block() {
+                Ok(Some(node)) => children.push(node),
+                Ok(None) => {}
+                Err(error) => {
+                    self.errors.push(error);
+                    if !self.options.recover {
+                        break;
+                    }
+                    children.push(self.recover_block());
+                }
             }
         }
 
         let span = Span::new(0, self.source.len() as u32);
-        Ok(Document { children, span })
+        (Document { children, span }, self.errors)
+    }
+
+    /// Skips the rest of the current block, returning it as a plain-text paragraph.
+    fn recover_block(&mut self) -> Node<'a> {
+        let start = self.position;
+
+        while !self.is_at_end() {
+            let line = self.remaining().lines().next().unwrap_or("");
+            if line.trim().is_empty() && self.position > start {
+                break;
+            }
+            self.position += line.len();
+            if self.peek() == Some('\n') {
+                self.advance();
+            }
+        }
+
+        let value = self.source[start..self.position].trim_end();
+        let span = Span::new(start as u32, (start + value.len()) as u32);
+        let mut children = self.allocator.new_vec();
+        children.push(Node::Text(Text { value, span }));
+        Node::Paragraph(Paragraph { children, span })
+    }
+
+    /// Collects every link reference definition so references can be
+    /// resolved before their definition is reached.
+    fn collect_link_definitions(&mut self) {
+        let mut definitions = HashMap::new();
+        self.scan_link_definitions(&mut definitions);
+        self.definitions = Some(Rc::new(definitions));
+    }
+
+    /// Adds the link reference definitions in the source to `definitions`.
+    ///
+    /// Definitions are document-global, so block quotes and containers are
+    /// scanned too. Fenced code is skipped and, as in block parsing, a
+    /// definition cannot interrupt a paragraph. The first definition of a
+    /// label wins.
+    fn scan_link_definitions(&mut self, definitions: &mut HashMap<String, LinkTarget<'a>>) {
+        let start = self.position;
+        let mut in_paragraph = false;
+
+        while !self.is_at_end() {
+            let line = self.remaining().lines().next().unwrap_or("");
+            if line.trim().is_empty() {
+                in_paragraph = false;
+                self.consume_line();
+            } else if !in_paragraph && self.try_parse_indented_code() {
+                self.consume_line();
+            } else if self.try_parse_block_quote() {
+                in_paragraph = false;
+                let (inner, _) = self.block_quote_lines();
+                if self.nesting_depth < self.options.max_nesting_depth {
+                    let inner = self.allocator.alloc_str(&inner);
+                    let mut sub_parser =
+                        Parser::with_options(self.allocator, inner, self.options.clone());
+                    sub_parser.nesting_depth = self.nesting_depth + 1;
+                    sub_parser.scan_link_definitions(definitions);
+                }
+            } else if self.options.containers
+                && (Self::container_open(line).is_some() || Self::is_container_close(line))
+            {
+                // Container content is scanned in place; its fences end paragraphs.
+                in_paragraph = false;
+                self.consume_line();
+            } else if self.try_parse_fenced_code() {
+                in_paragraph = false;
+                let _ = self.parse_fenced_code(self.position);
+            } else if let Some(definition) =
+                (!in_paragraph).then(|| self.link_definition_here()).flatten()
+            {
+                definitions
+                    .entry(normalize_label(definition.label))
+                    .or_insert((definition.url, definition.title));
+                self.position += definition.len;
+            } else {
+                in_paragraph = !(self.try_parse_heading() || self.try_parse_thematic_break());
+                self.consume_line();
+            }
+        }
+
+        self.position = start;
+    }
+
+    /// Parses a link reference definition starting at the current position.
+    ///
+    /// Labels starting with `^` are reserved for footnotes, even when
+    /// footnotes are disabled.
+    fn link_definition_here(&self) -> Option<LinkDefinition<'a>> {
+        link_definition(self.remaining()).filter(|definition| !definition.label.starts_with('^'))
+    }
+
+    /// Parses a link reference definition: `[label]: url "title"`.
+    fn parse_link_definition(&mut self, start: usize) -> ParseResult<Option<Node<'a>>> {
+        let Some(definition) = self.link_definition_here() else {
+            return Ok(None);
+        };
+        self.position += definition.len;
+
+        let identifier = self.allocator.alloc_str(&normalize_label(definition.label));
+        let span = Span::new(start as u32, self.position as u32);
+        Ok(Some(Node::Definition(Definition {
+            identifier,
+            label: Some(definition.label),
+            url: definition.url,
+            title: definition.title,
+            span,
+        })))
+    }
+
+    /// Looks up the definition a reference link or image refers to.
+    ///
+    /// `close` is the position of the `]` ending `text`; the reference is
+    /// full (`[text][label]`), collapsed (`[text][]`) or a shortcut
+    /// (`[text]`). Returns the target and the position after the reference.
+    fn reference_target(
+        &self,
+        content: &str,
+        text: &str,
+        close: usize,
+    ) -> Option<(LinkTarget<'a>, usize)> {
+        let definitions =
+            self.definitions.as_ref().filter(|definitions| !definitions.is_empty())?;
+        let (label, end) = match content[close + 1..].strip_prefix('[') {
+            Some(rest) => {
+                let len = rest.find(']')?;
+                let label = &rest[..len];
+                if label.contains('[') {
+                    return None;
+                }
+                (if label.is_empty() { text } else { label }, close + len + 3)
+            }
+            None => (text, close + 1),
+        };
+        definitions.get(&normalize_label(label)).map(|&target| (target, end))
     }
 
     /// Checks if we've reached the end of input.
@@ -125,16 +360,26 @@ impl<'a> Parser<'a> {
         }
     }
 
-    /// Skips blank lines.
+    /// Skips blank lines, including a whitespace-only last line without a newline.
     fn skip_blank_lines(&mut self) {
         while !self.is_at_end() {
             let start = self.position;
             self.skip_whitespace();
-            if self.peek() == Some('\n') {
-                self.advance();
-            } else {
-                self.position = start;
-                break;
+            match self.peek() {
+                Some('\n') => {
+                    self.advance();
+                }
+                Some('\r') => {
+                    self.advance();
+                    if self.peek() == Some('\n') {
+                        self.advance();
+                    }
+                }
+                None => {}
+                Some(_) => {
+                    self.position = start;
+                    break;
+                }
             }
         }
     }
@@ -160,6 +405,10 @@ impl<'a> Parser<'a> {
         let trimmed = line.trim_start();
         let first = trimmed.as_bytes().first().copied();
 
+        if self.try_parse_indented_code() {
+            return self.parse_indented_code(start);
+        }
+
         // Try to parse different block types
         match first {
             Some(b'#') if self.try_parse_heading() => return self.parse_heading(start),
@@ -179,9 +428,16 @@ impl<'a> Parser<'a> {
                 return self.parse_fenced_code(start);
             }
             Some(b'<') if self.try_parse_html_block() => return self.parse_html_block(start),
+            Some(b':') if self.try_parse_container() => return self.parse_container(start),
             Some(b'+' | b'0'..=b'9') if self.try_parse_list() => {
                 return self.parse_list(start);
             }
+            Some(b'[') if self.try_parse_footnote_definition() => {
+                return self.parse_footnote_definition(start);
+            }
+            Some(b'[') if self.link_definition_here().is_some() => {
+                return self.parse_link_definition(start);
+            }
             _ => {}
         }
 
@@ -189,6 +445,10 @@ impl<'a> Parser<'a> {
             return self.parse_table(start);
         }
 
+        if self.try_parse_definition_list() {
+            return self.parse_definition_list(start);
+        }
+
         // Default: parse as paragraph
         self.parse_paragraph(start)
     }
@@ -205,11 +465,14 @@ impl<'a> Parser<'a> {
             Some(b'>') => self.try_parse_block_quote(),
             Some(b'`' | b'~') => self.try_parse_fenced_code(),
             Some(b'<') => self.try_parse_html_block(),
+            Some(b':') => self.try_parse_container(),
             Some(b'+' | b'0'..=b'9') => self.try_parse_list(),
             _ => false,
         };
 
-        starts_block || (self.options.tables && line.contains('|') && self.try_parse_table())
+        starts_block
+            || (self.options.tables && line.contains('|') && self.try_parse_table())
+            || self.try_parse_definition_list()
     }
 
     fn try_parse_html_block(&self) -> bool {
@@ -325,12 +588,14 @@ impl<'a> Parser<'a> {
         trimmed.starts_with('>')
     }
 
-    /// Parses a block quote.
-    fn parse_block_quote(&mut self, start: usize) -> ParseResult<Option<Node<'a>>> {
-        self.nesting_depth += 1;
-
-        // Collect lines belonging to this block quote and strip the `>` prefix.
+    /// Collects the lines of the block quote at the current position with
+    /// their `>` prefix stripped.
+    ///
+    /// Also returns the (inner offset, source offset) of each line's content.
+    fn block_quote_lines(&mut self) -> (String, std::vec::Vec<(usize, usize)>) {
         let mut inner = String::new();
+        // (inner offset, source offset) of each collected line's content.
+        let mut line_map: std::vec::Vec<(usize, usize)> = std::vec::Vec::new();
 
         loop {
             if self.is_at_end() {
@@ -355,6 +620,7 @@ impl<'a> Parser<'a> {
             if let Some(after_gt) = trimmed.strip_prefix('>') {
                 // Strip the optional single space after `>`
                 let stripped = after_gt.strip_prefix(' ').unwrap_or(after_gt);
+                line_map.push((inner.len(), self.position + line.len() - stripped.len()));
                 inner.push_str(stripped);
                 inner.push('\n');
 
@@ -369,15 +635,190 @@ impl<'a> Parser<'a> {
             }
         }
 
-        // Recursively parse the inner content
-        let inner_str = self.allocator.alloc_str(&inner);
-        let sub_parser = Parser::with_options(self.allocator, inner_str, self.options.clone());
-        let sub_doc = sub_parser.parse()?;
+        (inner, line_map)
+    }
+
+    /// Parses a block quote.
+    fn parse_block_quote(&mut self, start: usize) -> ParseResult<Option<Node<'a>>> {
+        let (inner, line_map) = self.block_quote_lines();
+        let children = self.parse_nested(&inner, &line_map)?;
+
+        let span = Span::new(start as u32, self.position as u32);
+        Ok(Some(Node::BlockQuote(BlockQuote { children, span })))
+    }
 
+    /// Parses collected container content with a sub-parser.
+    ///
+    /// `line_map` holds the (inner offset, source offset) of each collected
+    /// line; the sub-parser's error spans are mapped back to source offsets.
+    fn parse_nested(
+        &mut self,
+        inner: &str,
+        line_map: &[(usize, usize)],
+    ) -> ParseResult<Vec<'a, Node<'a>>> {
+        self.nesting_depth += 1;
+        let inner_str = self.allocator.alloc_str(inner);
+        let mut sub_parser = Parser::with_options(self.allocator, inner_str, self.options.clone());
+        sub_parser.nesting_depth = self.nesting_depth;
+        sub_parser.definitions.clone_from(&self.definitions);
+        let (sub_doc, errors) = sub_parser.parse_with_errors();
         self.nesting_depth -= 1;
 
+        let mut errors = errors.into_iter().map(|error| {
+            error.map_span(|offset| {
+                let offset = offset as usize;
+                let line = line_map.partition_point(|&(inner_start, _)| inner_start <= offset);
+                line.checked_sub(1).map_or(offset, |index| {
+                    let (inner_start, source_start) = line_map[index];
+                    source_start + offset - inner_start
+                }) as u32
+            })
+        });
+        if self.options.recover {
+            self.errors.extend(errors);
+        } else if let Some(error) = errors.next() {
+            return Err(error);
+        }
+
+        Ok(sub_doc.children)
+    }
+
+    /// Checks if the current line opens a custom container.
+    fn try_parse_container(&self) -> bool {
+        let line = self.remaining().lines().next().unwrap_or("");
+        self.options.containers && Self::container_open(line).is_some()
+    }
+
+    /// Splits a `:::kind title` opening line into its kind and optional title.
+    fn container_open(line: &str) -> Option<(&str, Option<&str>)> {
+        let trimmed = line.trim();
+        let fence = trimmed.bytes().take_while(|&b| b == b':').count();
+        if fence < 3 {
+            return None;
+        }
+        let rest = trimmed[fence..].trim_start();
+        let kind_len = rest
+            .bytes()
+            .take_while(|b| b.is_ascii_alphanumeric() || matches!(b, b'-' | b'_'))
+            .count();
+        let (kind, title) = rest.split_at(kind_len);
+        if kind.is_empty() || !(title.is_empty() || title.starts_with(char::is_whitespace)) {
+            return None;
+        }
+        let title = title.trim();
+        Some((kind, (!title.is_empty()).then_some(title)))
+    }
+
+    /// Returns true if the line closes a custom container.
+    fn is_container_close(line: &str) -> bool {
+        let trimmed = line.trim();
+        trimmed.len() >= 3 && trimmed.bytes().all(|b| b == b':')
+    }
+
+    /// Parses a custom container.
+    fn parse_container(&mut self, start: usize) -> ParseResult<Option<Node<'a>>> {
+        let Some((kind, title)) = Self::container_open(self.consume_line()) else {
+            return Ok(None);
+        };
+
+        // Collect lines up to the matching closing fence, skipping nested containers.
+        let content_start = self.position;
+        let mut content_end = self.source.len();
+        let mut depth = 0usize;
+        while !self.is_at_end() {
+            let line_start = self.position;
+            let line = self.consume_line();
+            if Self::is_container_close(line) {
+                if depth == 0 {
+                    content_end = line_start;
+                    break;
+                }
+                depth -= 1;
+            } else if Self::container_open(line).is_some() {
+                depth += 1;
+            }
+        }
+
+        let inner = &self.source[content_start..content_end];
+        let children = self.parse_nested(inner, &[(0, content_start)])?;
+
+        let span = Span::new(start as u32, self.position as u32);
+        Ok(Some(Node::Container(Container { kind, title, children, span })))
+    }
+
+    /// Checks if the current line starts a `[^label]: ` footnote definition.
+    fn try_parse_footnote_definition(&self) -> bool {
+        let line = self.remaining().lines().next().unwrap_or("");
+        self.options.footnotes && Self::footnote_definition_open(line).is_some()
+    }
+
+    /// Splits a footnote definition line into its label and the offset of its content.
+    fn footnote_definition_open(line: &str) -> Option<(&str, usize)> {
+        let indent = line.len() - line.trim_start_matches(' ').len();
+        if indent > 3 {
+            return None;
+        }
+        let rest = line[indent..].strip_prefix("[^")?;
+        let label_len = footnote_label_len(rest)?;
+        let content = rest[label_len..].strip_prefix("]:")?.trim_start();
+        Some((&rest[..label_len], line.len() - content.len()))
+    }
+
+    /// Parses a footnote definition.
+    ///
+    /// The definition continues over following non-blank lines and over
+    /// blank lines followed by a line indented by four spaces (or a tab);
+    /// that indentation is stripped before its content is parsed as blocks.
+    fn parse_footnote_definition(&mut self, start: usize) -> ParseResult<Option<Node<'a>>> {
+        let first_line = self.consume_line();
+        let Some((label, content_offset)) = Self::footnote_definition_open(first_line) else {
+            return Ok(None);
+        };
+
+        let mut inner = String::from(&first_line[content_offset..]);
+        inner.push('\n');
+        // (inner offset, source offset) of each collected line's content.
+        let mut line_map: std::vec::Vec<(usize, usize)> = vec![(0, start + content_offset)];
+
+        while !self.is_at_end() {
+            let line = self.remaining().lines().next().unwrap_or("");
+            if line.trim().is_empty() {
+                let next = self.remaining().lines().find(|line| !line.trim().is_empty());
+                if !next.is_some_and(|line| line.starts_with("    ") || line.starts_with('\t')) {
+                    break;
+                }
+                self.consume_line();
+                inner.push('\n');
+                continue;
+            }
+
+            let indented = line.starts_with("    ") || line.starts_with('\t');
+            if !indented
+                && (self.line_starts_block() || Self::footnote_definition_open(line).is_some())
+            {
+                break;
+            }
+
+            let line_start = self.position;
+            let line = self.consume_line();
+            let stripped = line
+                .strip_prefix('\t')
+                .or_else(|| line.strip_prefix("    "))
+                .unwrap_or_else(|| line.trim_start());
+            line_map.push((inner.len(), line_start + line.len() - stripped.len()));
+            inner.push_str(stripped);
+            inner.push('\n');
+        }
+
+        let children = self.parse_nested(&inner, &line_map)?;
+
         let span = Span::new(start as u32, self.position as u32);
-        Ok(Some(Node::BlockQuote(BlockQuote { children: sub_doc.children, span })))
+        Ok(Some(Node::FootnoteDefinition(FootnoteDefinition {
+            identifier: label,
+            label: Some(label),
+            children,
+            span,
+        })))
     }
 
     /// Checks if the current position starts a list.
@@ -465,6 +906,7 @@ impl<'a> Parser<'a> {
             return Some(ParsedListItem {
                 ordered: false,
                 start: None,
+                delimiter: None,
                 content,
                 content_offset,
                 checked,
@@ -481,14 +923,19 @@ impl<'a> Parser<'a> {
             return None;
         }
 
-        let marker = bytes[marker_end];
-        if !matches!(marker, b'.' | b')') || bytes[marker_end + 1] != b' ' {
+        let delimiter = match bytes[marker_end] {
+            b'.' => ListDelimiter::Period,
+            b')' => ListDelimiter::Paren,
+            _ => return None,
+        };
+        if bytes[marker_end + 1] != b' ' {
             return None;
         }
 
         Some(ParsedListItem {
             ordered: true,
             start: trimmed[..marker_end].parse().ok(),
+            delimiter: Some(delimiter),
             content: &trimmed[marker_end + 2..],
             content_offset: trimmed_offset + marker_end + 2,
             checked: None,
@@ -506,6 +953,7 @@ impl<'a> Parser<'a> {
         };
         let ordered = first_item.ordered;
         let list_start = first_item.start;
+        let delimiter = first_item.delimiter;
 
         let mut children: Vec<'a, ListItem<'a>> = self.allocator.new_vec();
 
@@ -566,8 +1014,8 @@ impl<'a> Parser<'a> {
             let Some(item) = self.parse_list_item_line(line_start) else {
                 break;
             };
-            if item.ordered != ordered {
-                // Not a list item, break
+            if item.ordered != ordered || item.delimiter != delimiter {
+                // A different marker type or delimiter starts a new list
                 break;
             }
 
@@ -606,7 +1054,14 @@ impl<'a> Parser<'a> {
         }
 
         let span = Span::new(start as u32, self.position as u32);
-        Ok(Some(Node::List(List { ordered, start: list_start, spread: false, children, span })))
+        Ok(Some(Node::List(List {
+            ordered,
+            start: list_start,
+            delimiter,
+            spread: false,
+            children,
+            span,
+        })))
     }
 
     /// Checks if the current position starts a heading.
@@ -651,6 +1106,48 @@ impl<'a> Parser<'a> {
         remaining.starts_with("```") || remaining.starts_with("~~~")
     }
 
+    /// Checks if the current line starts an indented code block.
+    fn try_parse_indented_code(&self) -> bool {
+        let line = self.remaining().lines().next().unwrap_or("");
+        self.options.indented_code && strip_code_indent(line).is_some() && !line.trim().is_empty()
+    }
+
+    /// Parses an indented code block.
+    ///
+    /// The block continues over indented lines and blank lines between them;
+    /// trailing blank lines are left out. One level of indentation is
+    /// stripped from each line.
+    fn parse_indented_code(&mut self, start: usize) -> ParseResult<Option<Node<'a>>> {
+        let mut value = String::new();
+        let mut blank_lines = 0;
+        let mut content_end = start;
+
+        while !self.is_at_end() {
+            let line = self.remaining().lines().next().unwrap_or("");
+            if line.trim().is_empty() {
+                blank_lines += 1;
+                self.consume_line();
+                continue;
+            }
+            let Some(code) = strip_code_indent(line) else {
+                break;
+            };
+            for _ in 0..blank_lines {
+                value.push('\n');
+            }
+            blank_lines = 0;
+            value.push_str(code);
+            value.push('\n');
+            self.consume_line();
+            content_end = self.position;
+        }
+        self.position = content_end;
+
+        let value = self.allocator.alloc_str(&value);
+        let span = Span::new(start as u32, content_end as u32);
+        Ok(Some(Node::CodeBlock(ox_content_ast::CodeBlock { lang: None, meta: None, value, span })))
+    }
+
     /// Checks if the current position starts a table.
     fn try_parse_table(&self) -> bool {
         let remaining = self.remaining();
@@ -685,6 +1182,107 @@ impl<'a> Parser<'a> {
         is_delimiter
     }
 
+    /// Checks if the current position starts a definition list.
+    ///
+    /// A definition list starts with a term line immediately followed by a
+    /// line beginning with `: `.
+    fn try_parse_definition_list(&self) -> bool {
+        if !self.options.definition_lists {
+            return false;
+        }
+
+        let mut lines = self.remaining().lines();
+        let Some(term) = lines.next() else {
+            return false;
+        };
+        if term.trim().is_empty() || Self::definition_marker_offset(term).is_some() {
+            return false;
+        }
+
+        lines.next().is_some_and(|line| Self::definition_marker_offset(line).is_some())
+    }
+
+    /// Returns the offset of the definition content if the line starts with `: `.
+    fn definition_marker_offset(line: &str) -> Option<usize> {
+        let indent = line.len() - line.trim_start_matches(' ').len();
+        if indent > 3 {
+            return None;
+        }
+
+        let rest = line[indent..].strip_prefix(':')?;
+        if !rest.starts_with([' ', '\t']) {
+            return None;
+        }
+
+        Some(line.len() - rest.trim_start().len())
+    }
+
+    /// Parses a definition list.
+    fn parse_definition_list(&mut self, start: usize) -> ParseResult<Option<Node<'a>>> {
+        let mut children: Vec<'a, Node<'a>> = self.allocator.new_vec();
+        let mut end = start;
+
+        loop {
+            // Term line
+            let term_start = self.position;
+            let term_line = self.consume_line();
+            let term_offset = term_start + (term_line.len() - term_line.trim_start().len());
+            let term_children = self.parse_inline(term_line.trim(), term_offset)?;
+            let term_span = Span::new(term_start as u32, (term_start + term_line.len()) as u32);
+            children.push(Node::DescriptionTerm(DescriptionTerm {
+                children: term_children,
+                span: term_span,
+            }));
+
+            // One or more `: Definition` lines
+            loop {
+                let line = self.remaining().lines().next().unwrap_or("");
+                let Some(marker_offset) = Self::definition_marker_offset(line) else {
+                    break;
+                };
+
+                let details_start = self.position;
+                let content_start = details_start + marker_offset;
+                let first_line = self.consume_line();
+                let mut content_end = details_start + first_line.trim_end().len();
+
+                // Indented continuation lines belong to the same definition
+                while !self.is_at_end() {
+                    let next = self.remaining().lines().next().unwrap_or("");
+                    if next.trim().is_empty()
+                        || !next.starts_with([' ', '\t'])
+                        || Self::definition_marker_offset(next).is_some()
+                    {
+                        break;
+                    }
+                    let line_start = self.position;
+                    let continuation = self.consume_line();
+                    content_end = line_start + continuation.trim_end().len();
+                }
+
+                let content = &self.source[content_start..content_end.max(content_start)];
+                let details_children = self.parse_inline(content, content_start)?;
+                let details_span = Span::new(details_start as u32, content_end as u32);
+                children.push(Node::DescriptionDetails(DescriptionDetails {
+                    children: details_children,
+                    span: details_span,
+                }));
+                end = content_end;
+            }
+
+            // Another term may follow after blank lines
+            let before_blank = self.position;
+            self.skip_blank_lines();
+            if self.is_at_end() || !self.try_parse_definition_list() {
+                self.position = before_blank;
+                break;
+            }
+        }
+
+        let span = Span::new(start as u32, end as u32);
+        Ok(Some(Node::DescriptionList(DescriptionList { children, span })))
+    }
+
     /// Parses a heading.
     fn parse_heading(&mut self, start: usize) -> ParseResult<Option<Node<'a>>> {
         let mut depth = 0u8;
@@ -786,20 +1384,17 @@ impl<'a> Parser<'a> {
 
             let line_start = self.position;
 
-            // Check for closing fence
-            let mut closing_fence_len = 0;
-            while self.peek() == Some(fence_char) {
-                closing_fence_len += 1;
-                self.advance();
-            }
-
-            if closing_fence_len >= fence_len {
-                // Skip rest of line
-                while let Some(ch) = self.peek() {
-                    if ch == '\n' {
-                        self.advance();
-                        break;
-                    }
+            // Check for closing fence: up to 3 spaces of indentation, a run of at least
+            // `fence_len` fence chars, and nothing but whitespace after it.
+            let line = self.remaining().split('\n').next().unwrap_or("");
+            let unindented = line.trim_start_matches(' ');
+            let closing_fence_len = unindented.chars().take_while(|&ch| ch == fence_char).count();
+            if line.len() - unindented.len() <= 3
+                && closing_fence_len >= fence_len
+                && unindented[closing_fence_len..].trim().is_empty()
+            {
+                self.position += line.len();
+                if self.peek() == Some('\n') {
                     self.advance();
                 }
                 content_end = line_start;
@@ -877,10 +1472,13 @@ impl<'a> Parser<'a> {
             rows.push(row_cells);
         }
 
-        // Build the table AST
+        // Build the table AST. As in GFM, every row gets the delimiter row's
+        // column count: extra cells are dropped and missing ones left empty.
+        let columns = align.len();
         let mut children: Vec<'a, TableRow<'a>> = self.allocator.new_vec();
 
-        for row_cells in rows {
+        for mut row_cells in rows {
+            row_cells.resize(columns, "");
             let mut cells: Vec<'a, TableCell<'a>> = self.allocator.new_vec();
             for cell_content in row_cells {
                 let cell_children = self.parse_inline(cell_content, 0)?;
@@ -908,11 +1506,52 @@ impl<'a> Parser<'a> {
     }
 
     /// Parses table row cells from a line.
+    ///
+    /// Pipes escaped with a backslash or inside a code span (`` `a|b` ``) do
+    /// not split cells. A backtick run without a closing run of the same
+    /// length is literal text, so pipes after it still split.
     fn parse_table_row_cells(line: &'a str) -> std::vec::Vec<&'a str> {
         let trimmed = line.trim();
         let trimmed = trimmed.strip_prefix('|').unwrap_or(trimmed);
-        let trimmed = trimmed.strip_suffix('|').unwrap_or(trimmed);
-        trimmed.split('|').map(str::trim).collect()
+        let bytes = trimmed.as_bytes();
+        let mut cells = std::vec::Vec::new();
+        let mut cell_start = 0;
+        let mut i = 0;
+
+        while i < bytes.len() {
+            match bytes[i] {
+                b'\\' => i += 2,
+                b'`' => {
+                    let run = backtick_run(&bytes[i..]);
+                    let mut j = i + run;
+                    i += run;
+                    while j < bytes.len() {
+                        if bytes[j] == b'`' {
+                            let closing = backtick_run(&bytes[j..]);
+                            j += closing;
+                            if closing == run {
+                                i = j;
+                                break;
+                            }
+                        } else {
+                            j += 1;
+                        }
+                    }
+                }
+                b'|' => {
+                    cells.push(trimmed[cell_start..i].trim());
+                    i += 1;
+                    cell_start = i;
+                }
+                _ => i += 1,
+            }
+        }
+
+        // A trailing pipe closes the last cell rather than opening an empty one.
+        if cell_start < bytes.len() || cells.is_empty() {
+            cells.push(trimmed[cell_start..].trim());
+        }
+        cells
     }
 
     /// Parses a paragraph.
@@ -934,6 +1573,16 @@ impl<'a> Parser<'a> {
 
             self.position = line_start;
 
+            // A `===` or `---` underline below paragraph text makes a setext
+            // heading; this takes precedence over thematic breaks and lists.
+            if content_end > start {
+                let line = self.remaining().lines().next().unwrap_or("");
+                if let Some(depth) = Self::setext_underline_depth(line) {
+                    self.consume_line();
+                    return self.parse_setext_heading(start, content_end, depth);
+                }
+            }
+
             // Check for block-level element that would end paragraph
             if self.line_starts_block() {
                 break;
@@ -962,11 +1611,57 @@ impl<'a> Parser<'a> {
         Ok(Some(Node::Paragraph(Paragraph { children, span })))
     }
 
+    /// Returns the heading depth if `line` is a setext underline (`===` or `---`).
+    ///
+    /// Only meaningful directly below paragraph text; elsewhere a `---` run is
+    /// a thematic break and `===` is plain text.
+    fn setext_underline_depth(line: &str) -> Option<u8> {
+        let trimmed = line.trim();
+        if line.len() - line.trim_start().len() > 3 {
+            return None;
+        }
+        let marker = *trimmed.as_bytes().first()?;
+        let depth = match marker {
+            b'=' => 1,
+            b'-' => 2,
+            _ => return None,
+        };
+        trimmed.bytes().all(|b| b == marker).then_some(depth)
+    }
+
+    /// Parses the paragraph text in `start..content_end` as a setext heading.
+    fn parse_setext_heading(
+        &self,
+        start: usize,
+        content_end: usize,
+        depth: u8,
+    ) -> ParseResult<Option<Node<'a>>> {
+        let content = self.source[start..content_end].trim();
+        let children = self.parse_inline(content, start)?;
+        let span = Span::new(start as u32, self.position as u32);
+        Ok(Some(Node::Heading(ox_content_ast::Heading { depth, children, span })))
+    }
+
     /// Parses inline content.
     fn parse_inline(&self, content: &'a str, offset: usize) -> ParseResult<Vec<'a, Node<'a>>> {
+        self.parse_inline_with(content, offset, self.options.autolinks)
+    }
+
+    /// Parses inline content, detecting bare URLs and emails when `autolinks` is set.
+    ///
+    /// Autolinks are only recognized in text runs, never inside code spans or
+    /// link text.
+    fn parse_inline_with(
+        &self,
+        content: &'a str,
+        offset: usize,
+        autolinks: bool,
+    ) -> ParseResult<Vec<'a, Node<'a>>> {
         let mut children = self.allocator.new_vec();
         let mut pos = 0;
         let bytes = content.as_bytes();
+        // End of the last email local part that was scanned without a match.
+        let mut local_end = 0;
 
         while pos < content.len() {
             let start = pos;
@@ -974,18 +1669,32 @@ impl<'a> Parser<'a> {
             // Look for special characters
             while pos < content.len() {
                 let ch = bytes[pos];
-                if matches!(ch, b'*' | b'_' | b'`' | b'[' | b'!' | b'~' | b'\\') {
+                if matches!(
+                    ch,
+                    b'*' | b'_' | b'`' | b'[' | b'!' | b'~' | b'\\' | b'&' | b'<' | b'\n'
+                ) || (ch == b'=' && self.options.mark)
+                    || (ch == b'^' && self.options.sub_sup)
+                {
+                    break;
+                }
+                if autolinks && autolink_len(content, pos, &mut local_end).is_some() {
                     break;
                 }
                 pos += 1;
             }
 
-            // Emit text before special character
-            if pos > start {
-                let text_content = &content[start..pos];
+            // Emit text before special character. Trailing whitespace before
+            // a line ending belongs to the break.
+            let text_end = if bytes.get(pos) == Some(&b'\n') {
+                start + content[start..pos].trim_end_matches([' ', '\t', '\r']).len()
+            } else {
+                pos
+            };
+            if text_end > start {
+                let text_content = &content[start..text_end];
                 let text = Text {
                     value: text_content,
-                    span: Span::new((offset + start) as u32, (offset + pos) as u32),
+                    span: Span::new((offset + start) as u32, (offset + text_end) as u32),
                 };
                 children.push(Node::Text(text));
             }
@@ -994,21 +1703,98 @@ impl<'a> Parser<'a> {
                 break;
             }
 
-            // Handle special characters
-            let ch = bytes[pos];
-            match ch {
-                b'\\' if pos + 1 < content.len() && bytes[pos + 1] == b'\n' => {
-                    let break_node = ox_content_ast::Break {
-                        span: Span::new((offset + pos) as u32, (offset + pos + 2) as u32),
-                    };
-                    children.push(Node::Break(break_node));
-                    pos += 2;
-                }
-                b'\\' if pos + 1 < content.len() => {
-                    // Escape sequence
-                    pos += 1;
-                    let escaped = &content[pos..pos + 1];
-                    let text = Text {
+            if let Some(len) =
+                autolinks.then(|| autolink_len(content, pos, &mut local_end)).flatten()
+            {
+                children.push(self.autolink(&content[pos..pos + len], offset + pos));
+                pos += len;
+                continue;
+            }
+
+            // Entity reference: decode so slugs and search see the character.
+            // An unrecognized `&` stays literal (default arm below).
+            if let Some((decoded, len)) =
+                (bytes[pos] == b'&').then(|| decode_entity(&content[pos..])).flatten()
+            {
+                let text = Text {
+                    value: self.allocator.alloc_str(decoded.encode_utf8(&mut [0; 4])),
+                    span: Span::new((offset + pos) as u32, (offset + pos + len) as u32),
+                };
+                children.push(Node::Text(text));
+                pos += len;
+                continue;
+            }
+
+            // Footnote reference `[^label]`.
+            if let Some(len) = (self.options.footnotes && content[pos..].starts_with("[^"))
+                .then(|| footnote_label_len(&content[pos + 2..]))
+                .flatten()
+            {
+                let label = &content[pos + 2..pos + 2 + len];
+                let footnote_ref = FootnoteReference {
+                    identifier: label,
+                    label: Some(label),
+                    span: Span::new((offset + pos) as u32, (offset + pos + len + 3) as u32),
+                };
+                children.push(Node::FootnoteReference(footnote_ref));
+                pos += len + 3;
+                continue;
+            }
+
+            // Inline HTML comment, possibly spanning lines. An unterminated
+            // `<!--` stays literal (default arm below).
+            if let Some(len) =
+                (bytes[pos] == b'<').then(|| html_comment_len(&content[pos..])).flatten()
+            {
+                let html = Html {
+                    value: &content[pos..pos + len],
+                    span: Span::new((offset + pos) as u32, (offset + pos + len) as u32),
+                };
+                children.push(Node::Html(html));
+                pos += len;
+                continue;
+            }
+
+            // Handle special characters
+            let ch = bytes[pos];
+            match ch {
+                b'\n' => {
+                    // Two or more trailing spaces make a hard break; otherwise
+                    // the line ending is a soft break. Leading whitespace of the
+                    // next line is dropped.
+                    let line = content[..pos].trim_end_matches('\r');
+                    let break_start = line.trim_end_matches([' ', '\t']).len();
+                    let next_line = &content[pos + 1..];
+                    let end = content.len() - next_line.trim_start_matches([' ', '\t']).len();
+                    let break_node = ox_content_ast::Break {
+                        hard: line.ends_with("  "),
+                        span: Span::new((offset + break_start) as u32, (offset + end) as u32),
+                    };
+                    children.push(Node::Break(break_node));
+                    pos = end;
+                }
+                b'\\' if pos + 1 < content.len() && bytes[pos + 1] == b'\n' => {
+                    let break_node = ox_content_ast::Break {
+                        hard: true,
+                        span: Span::new((offset + pos) as u32, (offset + pos + 2) as u32),
+                    };
+                    children.push(Node::Break(break_node));
+                    pos += 2;
+                }
+                b'\\' if content[pos + 1..].starts_with("\r\n") => {
+                    let break_node = ox_content_ast::Break {
+                        hard: true,
+                        span: Span::new((offset + pos) as u32, (offset + pos + 3) as u32),
+                    };
+                    children.push(Node::Break(break_node));
+                    pos += 3;
+                }
+                b'\\' if pos + 1 < content.len() && bytes[pos + 1].is_ascii_punctuation() => {
+                    // Escape sequence: any ASCII punctuation character is taken literally.
+                    // A backslash before anything else is itself literal (default arm).
+                    pos += 1;
+                    let escaped = &content[pos..pos + 1];
+                    let text = Text {
                         value: escaped,
                         span: Span::new((offset + pos - 1) as u32, (offset + pos + 1) as u32),
                     };
@@ -1034,7 +1820,7 @@ impl<'a> Parser<'a> {
                     if found {
                         let inner_content = &content[inner_start..inner_end];
                         let inner_children =
-                            self.parse_inline(inner_content, offset + inner_start)?;
+                            self.parse_inline_with(inner_content, offset + inner_start, autolinks)?;
                         let span =
                             Span::new((offset + pos) as u32, (offset + inner_end + 2) as u32);
                         let delete = ox_content_ast::Delete { children: inner_children, span };
@@ -1049,6 +1835,77 @@ impl<'a> Parser<'a> {
                         pos += 2;
                     }
                 }
+                b'~' | b'^' if self.options.sub_sup && bytes.get(pos + 1) != Some(&ch) => {
+                    if let Some(end) = sub_sup_close(bytes, pos + 1, ch) {
+                        let inner_children = self.parse_inline_with(
+                            &content[pos + 1..end],
+                            offset + pos + 1,
+                            autolinks,
+                        )?;
+                        let span = Span::new((offset + pos) as u32, (offset + end + 1) as u32);
+                        children.push(if ch == b'~' {
+                            Node::Subscript(ox_content_ast::Subscript {
+                                children: inner_children,
+                                span,
+                            })
+                        } else {
+                            Node::Superscript(ox_content_ast::Superscript {
+                                children: inner_children,
+                                span,
+                            })
+                        });
+                        pos = end + 1;
+                    } else {
+                        let text = Text {
+                            value: &content[pos..pos + 1],
+                            span: Span::new((offset + pos) as u32, (offset + pos + 1) as u32),
+                        };
+                        children.push(Node::Text(text));
+                        pos += 1;
+                    }
+                }
+                b'~' | b'^' if self.options.sub_sup => {
+                    // A run of delimiters that is not strikethrough stays literal, so its
+                    // last `~` does not open a subscript.
+                    let run = bytes[pos..].iter().take_while(|&&b| b == ch).count();
+                    let text = Text {
+                        value: &content[pos..pos + run],
+                        span: Span::new((offset + pos) as u32, (offset + pos + run) as u32),
+                    };
+                    children.push(Node::Text(text));
+                    pos += run;
+                }
+                b'=' if self.options.mark && pos + 1 < content.len() && bytes[pos + 1] == b'=' => {
+                    let inner_start = pos + 2;
+                    let mut inner_end = inner_start;
+                    let mut found = false;
+
+                    while inner_end + 1 < content.len() {
+                        if bytes[inner_end] == b'=' && bytes[inner_end + 1] == b'=' {
+                            found = true;
+                            break;
+                        }
+                        inner_end += 1;
+                    }
+
+                    if found {
+                        let inner_content = &content[inner_start..inner_end];
+                        let inner_children =
+                            self.parse_inline_with(inner_content, offset + inner_start, autolinks)?;
+                        let span =
+                            Span::new((offset + pos) as u32, (offset + inner_end + 2) as u32);
+                        let mark = ox_content_ast::Mark { children: inner_children, span };
+                        children.push(Node::Mark(mark));
+                        pos = inner_end + 2;
+                    } else {
+                        let text = Text {
+                            value: &content[pos..pos + 2],
+                            span: Span::new((offset + pos) as u32, (offset + pos + 2) as u32),
+                        };
+                        children.push(Node::Text(text));
+                        pos += 2;
+                    }
+                }
                 b'*' | b'_' => {
                     // Emphasis or Strong
                     let marker = ch;
@@ -1085,7 +1942,7 @@ impl<'a> Parser<'a> {
                         let inner_content = &content[inner_start..inner_end];
                         // Recursively parse inner content
                         let inner_children =
-                            self.parse_inline(inner_content, offset + inner_start)?;
+                            self.parse_inline_with(inner_content, offset + inner_start, autolinks)?;
 
                         let span =
                             Span::new((offset + pos) as u32, (offset + inner_end + count) as u32);
@@ -1184,16 +2041,16 @@ impl<'a> Parser<'a> {
                         }
 
                         if pos < content.len() && bytes[pos] == b')' {
-                            let url = &content[url_start..pos];
+                            let (url, title) = split_link_title(&content[url_start..pos]);
                             pos += 1; // skip )
 
                             // Parse link text as inline content
                             let link_children =
-                                self.parse_inline(link_text, offset + text_start)?;
+                                self.parse_inline_with(link_text, offset + text_start, false)?;
 
                             let link = Link {
                                 url,
-                                title: None,
+                                title,
                                 children: link_children,
                                 span: Span::new(
                                     (offset + link_start) as u32,
@@ -1212,6 +2069,24 @@ impl<'a> Parser<'a> {
                             };
                             children.push(Node::Text(text));
                         }
+                    } else if let Some(((url, title), end)) = (pos < content.len())
+                        .then(|| self.reference_target(content, &content[text_start..pos], pos))
+                        .flatten()
+                    {
+                        // Reference link: [text][label], [text][] or [text]
+                        let link_children = self.parse_inline_with(
+                            &content[text_start..pos],
+                            offset + text_start,
+                            false,
+                        )?;
+                        let link = Link {
+                            url,
+                            title,
+                            children: link_children,
+                            span: Span::new((offset + link_start) as u32, (offset + end) as u32),
+                        };
+                        children.push(Node::Link(link));
+                        pos = end;
                     } else {
                         // Not a link, just a [
                         let text = Text {
@@ -1268,13 +2143,13 @@ impl<'a> Parser<'a> {
                             }
 
                             if pos < content.len() && bytes[pos] == b')' {
-                                let url = &content[url_start..pos];
+                                let (url, title) = split_link_title(&content[url_start..pos]);
                                 pos += 1; // skip )
 
                                 let image = Image {
                                     url,
                                     alt: alt_text,
-                                    title: None,
+                                    title,
                                     span: Span::new(
                                         (offset + image_start) as u32,
                                         (offset + pos) as u32,
@@ -1292,6 +2167,22 @@ impl<'a> Parser<'a> {
                                 };
                                 children.push(Node::Text(text));
                             }
+                        } else if let Some(((url, title), end)) = (pos < content.len())
+                            .then(|| self.reference_target(content, &content[alt_start..pos], pos))
+                            .flatten()
+                        {
+                            // Reference image: ![alt][label], ![alt][] or ![alt]
+                            let image = Image {
+                                url,
+                                alt: &content[alt_start..pos],
+                                title,
+                                span: Span::new(
+                                    (offset + image_start) as u32,
+                                    (offset + end) as u32,
+                                ),
+                            };
+                            children.push(Node::Image(image));
+                            pos = end;
                         } else {
                             // Not an image, just ![
                             let text = Text {
@@ -1328,12 +2219,424 @@ impl<'a> Parser<'a> {
 
         Ok(children)
     }
+
+    /// Builds a link node for a bare URL or email address.
+    fn autolink(&self, text: &'a str, start: usize) -> Node<'a> {
+        let url = if text.contains("://") {
+            text
+        } else if text.starts_with("www.") {
+            self.allocator.alloc_str(&format!("http://{text}"))
+        } else {
+            self.allocator.alloc_str(&format!("mailto:{text}"))
+        };
+        let span = Span::new(start as u32, (start + text.len()) as u32);
+
+        let mut children = self.allocator.new_vec();
+        children.push(Node::Text(Text { value: text, span }));
+        Node::Link(Link { url, title: None, children, span })
+    }
+}
+
+/// Splits a link or image destination into its URL and optional quoted title,
+/// as in `url "title"` or `url 'title'`.
+fn split_link_title(destination: &str) -> (&str, Option<&str>) {
+    let destination = destination.trim();
+    let Some(quote) = destination.chars().last().filter(|c| matches!(c, '"' | '\'')) else {
+        return (destination, None);
+    };
+    let inner = &destination[..destination.len() - 1];
+    let Some(space) = inner.find(char::is_whitespace) else {
+        return (destination, None);
+    };
+    match inner[space..].trim_start().strip_prefix(quote) {
+        Some(title) => (&inner[..space], Some(title)),
+        None => (destination, None),
+    }
+}
+
+/// A link reference definition found by [`link_definition`].
+struct LinkDefinition<'s> {
+    label: &'s str,
+    url: &'s str,
+    title: Option<&'s str>,
+    /// Bytes consumed, including the final newline.
+    len: usize,
+}
+
+/// Parses a link reference definition at the start of `text`:
+/// `[label]: url`, optionally followed by a title in `"..."`, `'...'` or
+/// `(...)`. The URL and the title may each start on the next line.
+///
+/// When a title on a following line is malformed, the definition ends after
+/// the URL line and that line is left for the next block.
+fn link_definition(text: &str) -> Option<LinkDefinition<'_>> {
+    let bytes = text.as_bytes();
+    let indent = text.len() - text.trim_start_matches(' ').len();
+    if indent > 3 || bytes.get(indent) != Some(&b'[') {
+        return None;
+    }
+
+    // Label: no unescaped brackets and no blank line.
+    let label_start = indent + 1;
+    let mut pos = label_start;
+    loop {
+        match bytes.get(pos)? {
+            b'\\' => pos += 2,
+            b'[' => return None,
+            b']' => break,
+            _ => pos += 1,
+        }
+    }
+    let label = text.get(label_start..pos)?;
+    if label.trim().is_empty() || label.lines().any(|line| line.trim().is_empty()) {
+        return None;
+    }
+    pos += 1;
+    if bytes.get(pos) != Some(&b':') {
+        return None;
+    }
+    pos = skip_space_and_newline(text, pos + 1);
+
+    // Destination: `<...>` or a run of non-whitespace with balanced parentheses.
+    let url = if bytes.get(pos) == Some(&b'<') {
+        let len = text[pos + 1..].find(['>', '<', '\n'])?;
+        if bytes[pos + 1 + len] != b'>' {
+            return None;
+        }
+        let url = &text[pos + 1..pos + 1 + len];
+        pos += len + 2;
+        url
+    } else {
+        let start = pos;
+        let mut depth = 0usize;
+        while let Some(&byte) = bytes.get(pos) {
+            match byte {
+                b' ' | b'\t' | b'\n' | b'\r' => break,
+                b'(' => depth += 1,
+                b')' => depth = depth.checked_sub(1)?,
+                b'\\' => pos += 1,
+                _ => {}
+            }
+            pos += 1;
+        }
+        pos = pos.min(text.len());
+        if pos == start || depth > 0 {
+            return None;
+        }
+        &text[start..pos]
+    };
+
+    // Length of the definition without a title, if the URL ends its line.
+    let url_only = line_end(text, pos);
+    let title_start = skip_space_and_newline(text, pos);
+    if title_start == pos && url_only.is_none() {
+        return None;
+    }
+
+    let title = (|| {
+        let close = match bytes.get(title_start)? {
+            b'"' => b'"',
+            b'\'' => b'\'',
+            b'(' => b')',
+            _ => return None,
+        };
+        let mut pos = title_start + 1;
+        loop {
+            match *bytes.get(pos)? {
+                b'\\' => pos += 2,
+                byte if byte == close => break,
+                b'(' if close == b')' => return None,
+                _ => pos += 1,
+            }
+        }
+        let title = &text[title_start + 1..pos];
+        if title.lines().skip(1).any(|line| line.trim().is_empty()) {
+            return None;
+        }
+        line_end(text, pos + 1).map(|len| (title, len))
+    })();
+
+    let (title, len) = match title {
+        Some((title, len)) => (Some(title), len),
+        None => (None, url_only?),
+    };
+    Some(LinkDefinition { label, url, title, len })
+}
+
+/// Returns the length of the run of backticks at the start of `bytes`.
+fn backtick_run(bytes: &[u8]) -> usize {
+    bytes.iter().take_while(|&&b| b == b'`').count()
+}
+
+/// Skips spaces and tabs, including at most one line ending.
+fn skip_space_and_newline(text: &str, pos: usize) -> usize {
+    let rest = &text[pos..];
+    let after_spaces = rest.trim_start_matches([' ', '\t']);
+    let after_newline = after_spaces
+        .strip_prefix("\r\n")
+        .or_else(|| after_spaces.strip_prefix('\n'))
+        .map_or(after_spaces, |next| next.trim_start_matches([' ', '\t']));
+    text.len() - after_newline.len()
+}
+
+/// Returns the position after the line ending at `pos` if only spaces and
+/// tabs remain on the line.
+fn line_end(text: &str, pos: usize) -> Option<usize> {
+    let rest = text[pos..].trim_start_matches([' ', '\t']);
+    if rest.is_empty() {
+        return Some(text.len());
+    }
+    let rest = rest.strip_prefix("\r\n").or_else(|| rest.strip_prefix('\n'))?;
+    Some(text.len() - rest.len())
+}
+
+/// Strips one level of code indentation (four spaces or a tab) from a line.
+fn strip_code_indent(line: &str) -> Option<&str> {
+    line.strip_prefix("    ").or_else(|| line.strip_prefix('\t'))
+}
+
+/// Normalizes a link label for matching: case-folded, inner whitespace collapsed.
+fn normalize_label(label: &str) -> String {
+    label.split_whitespace().collect::<std::vec::Vec<_>>().join(" ").to_lowercase()
+}
+
+/// Returns the length of a footnote label closed by `]`.
+///
+/// Labels are non-empty and contain no whitespace or `[`.
+fn footnote_label_len(text: &str) -> Option<usize> {
+    let len = text.find(']')?;
+    let label = &text[..len];
+    (!label.is_empty() && !label.contains(|c: char| c.is_whitespace() || c == '[')).then_some(len)
+}
+
+/// Returns the index of the `marker` that closes a `~sub~` or `^sup^` span
+/// whose content starts at `start`.
+///
+/// The content must be non-empty and contain no whitespace, and the closing
+/// marker must not be doubled (`~~` belongs to strikethrough).
+fn sub_sup_close(bytes: &[u8], start: usize, marker: u8) -> Option<usize> {
+    let len = bytes[start..].iter().position(|&b| b == marker || b.is_ascii_whitespace())?;
+    let end = start + len;
+    (len > 0 && bytes[end] == marker && bytes.get(end + 1) != Some(&marker)).then_some(end)
+}
+
+/// Returns the byte length of an HTML comment (`<!-- ... -->`) at the start of
+/// `content`, if it is terminated.
+fn html_comment_len(content: &str) -> Option<usize> {
+    let body = content.strip_prefix("<!--")?;
+    body.find("-->").map(|end| end + 7)
+}
+
+/// Returns the byte length of a bare URL (`https://`, `http://`, `www.`) or
+/// email address starting at `pos`, if any.
+///
+/// `local_end` is where the last unmatched email local part ended. No email
+/// starts before it, so each local part is scanned once and text stays linear.
+fn autolink_len(content: &str, pos: usize, local_end: &mut usize) -> Option<usize> {
+    let bytes = content.as_bytes();
+    if !bytes[pos].is_ascii_alphanumeric() || (pos > 0 && bytes[pos - 1].is_ascii_alphanumeric()) {
+        return None;
+    }
+
+    let rest = &content[pos..];
+    let scheme_len = ["https://", "http://", "www."]
+        .iter()
+        .find(|prefix| {
+            rest.len() > prefix.len()
+                && rest.as_bytes()[..prefix.len()].eq_ignore_ascii_case(prefix.as_bytes())
+        })
+        .map(|prefix| prefix.len());
+
+    if let Some(scheme_len) = scheme_len {
+        let end = rest.find(|ch: char| ch.is_whitespace() || ch == '<').unwrap_or(rest.len());
+        let url = trim_autolink_trailing(&rest[..end]);
+        return (url.len() > scheme_len).then_some(url.len());
+    }
+
+    if pos < *local_end {
+        return None;
+    }
+    let local_len = rest
+        .bytes()
+        .take_while(|&b| b.is_ascii_alphanumeric() || matches!(b, b'.' | b'+' | b'-' | b'_'))
+        .count();
+    // Every later start inside this run reaches the same `@` (or none), so a
+    // failure here holds for the whole run.
+    if rest.as_bytes().get(local_len) != Some(&b'@') {
+        *local_end = pos + local_len;
+        return None;
+    }
+    let domain = &rest[local_len + 1..];
+    let domain_len = domain
+        .bytes()
+        .take_while(|&b| b.is_ascii_alphanumeric() || matches!(b, b'.' | b'-' | b'_'))
+        .count();
+    let domain = domain[..domain_len].trim_end_matches('.');
+    if domain.ends_with(['-', '_']) || !domain.contains('.') || domain.starts_with('.') {
+        *local_end = pos + local_len;
+        return None;
+    }
+    Some(local_len + 1 + domain.len())
+}
+
+/// Drops trailing punctuation and unbalanced closing parentheses from a bare URL.
+fn trim_autolink_trailing(mut url: &str) -> &str {
+    // Punctuation trimming never removes parentheses, so count them once.
+    let mut unbalanced = url.matches(')').count().saturating_sub(url.matches('(').count());
+    loop {
+        let trimmed = url.trim_end_matches(['?', '!', '.', ',', ':', '*', '_', '~', '"', '\'']);
+        if unbalanced == 0 || !trimmed.ends_with(')') {
+            return trimmed;
+        }
+        unbalanced -= 1;
+        url = &trimmed[..trimmed.len() - 1];
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_parse_image_title() {
+        let allocator = Allocator::new();
+        let doc = Parser::new(
+            &allocator,
+            "![Chart](/chart.png \"Monthly sales\") [docs](/docs 'The docs') [bare](/x)",
+        )
+        .parse()
+        .unwrap();
+        let Node::Paragraph(p) = &doc.children[0] else {
+            panic!("expected paragraph");
+        };
+        assert!(matches!(
+            &p.children[0],
+            Node::Image(img) if img.url == "/chart.png" && img.title == Some("Monthly sales")
+        ));
+        assert!(matches!(
+            &p.children[2],
+            Node::Link(link) if link.url == "/docs" && link.title == Some("The docs")
+        ));
+        assert!(
+            matches!(&p.children[4], Node::Link(link) if link.url == "/x" && link.title.is_none())
+        );
+    }
+
+    fn definition<'a>(node: &'a Node<'a>) -> &'a Definition<'a> {
+        match node {
+            Node::Definition(definition) => definition,
+            other => panic!("expected definition, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_parse_link_definition_single_line() {
+        let allocator = Allocator::new();
+        let doc = Parser::new(&allocator, "[Docs Home]: /docs \"The docs\"\n").parse().unwrap();
+        assert_eq!(doc.children.len(), 1);
+        let def = definition(&doc.children[0]);
+        assert_eq!(def.identifier, "docs home");
+        assert_eq!(def.label, Some("Docs Home"));
+        assert_eq!(def.url, "/docs");
+        assert_eq!(def.title, Some("The docs"));
+    }
+
+    #[test]
+    fn test_parse_link_definition_title_on_next_line() {
+        let allocator = Allocator::new();
+        let doc = Parser::new(&allocator, "[a]: <https://example.com/a b>\n   'Title A'\n\ntext")
+            .parse()
+            .unwrap();
+        assert_eq!(doc.children.len(), 2);
+        let def = definition(&doc.children[0]);
+        assert_eq!(def.url, "https://example.com/a b");
+        assert_eq!(def.title, Some("Title A"));
+        assert!(matches!(&doc.children[1], Node::Paragraph(_)));
+    }
+
+    #[test]
+    fn test_parse_link_definition_parenthesized_title() {
+        let allocator = Allocator::new();
+        let doc = Parser::new(&allocator, "[b]: /b (Paren title)\n[c]: /c\n").parse().unwrap();
+        assert_eq!(doc.children.len(), 2);
+        assert_eq!(definition(&doc.children[0]).title, Some("Paren title"));
+        let def = definition(&doc.children[1]);
+        assert_eq!((def.url, def.title), ("/c", None));
+    }
+
+    #[test]
+    fn test_parse_link_definition_invalid_title_line() {
+        let allocator = Allocator::new();
+        let doc = Parser::new(&allocator, "[a]: /a\n\"unterminated\n").parse().unwrap();
+        assert_eq!(doc.children.len(), 2);
+        let def = definition(&doc.children[0]);
+        assert_eq!((def.url, def.title), ("/a", None));
+        assert!(matches!(&doc.children[1], Node::Paragraph(_)));
+
+        let doc = Parser::new(&allocator, "[a]: /a \"title\" junk\n").parse().unwrap();
+        assert!(matches!(&doc.children[0], Node::Paragraph(_)));
+    }
+
+    #[test]
+    fn test_parse_reference_links_resolve_titles() {
+        let allocator = Allocator::new();
+        let doc = Parser::new(
+            &allocator,
+            "[Full][docs] [docs][] [Docs] ![Logo][logo] [missing]\n\n\
+             [docs]: /docs \"The docs\"\n[logo]: /logo.png\n  (Our logo)\n",
+        )
+        .parse()
+        .unwrap();
+        let Node::Paragraph(p) = &doc.children[0] else {
+            panic!("expected paragraph");
+        };
+        for index in [0, 2, 4] {
+            assert!(matches!(
+                &p.children[index],
+                Node::Link(link) if link.url == "/docs" && link.title == Some("The docs")
+            ));
+        }
+        assert!(matches!(
+            &p.children[6],
+            Node::Image(img) if img.alt == "Logo" && img.url == "/logo.png" && img.title == Some("Our logo")
+        ));
+        assert!(matches!(&p.children[8], Node::Text(text) if text.value == "["));
+    }
+
+    #[test]
+    fn test_parse_link_definition_cannot_interrupt_paragraph() {
+        let allocator = Allocator::new();
+        let doc = Parser::new(&allocator, "text\n[a]: /a\n\n[a]").parse().unwrap();
+        assert_eq!(doc.children.len(), 2);
+        assert!(matches!(&doc.children[0], Node::Paragraph(_)));
+        let Node::Paragraph(p) = &doc.children[1] else {
+            panic!("expected paragraph");
+        };
+        assert!(matches!(&p.children[0], Node::Text(_)));
+    }
+
+    #[test]
+    fn test_parse_link_definitions_in_nested_blocks_are_global() {
+        let allocator = Allocator::new();
+        let options = ParserOptions { containers: true, ..ParserOptions::default() };
+        let source = "[c] [n]\n\n> [q]: /q\n> > [n]: /n\n\n[q]\n\n::: tip\n[c]: /c\n:::\n";
+        let doc = Parser::with_options(&allocator, source, options).parse().unwrap();
+        let links = |node: &Node| -> std::vec::Vec<std::string::String> {
+            let Node::Paragraph(p) = node else {
+                panic!("expected paragraph, got {node:?}");
+            };
+            p.children
+                .iter()
+                .filter_map(|child| match child {
+                    Node::Link(link) => Some(link.url.to_string()),
+                    _ => None,
+                })
+                .collect()
+        };
+        assert_eq!(links(&doc.children[0]), ["/c", "/n"]);
+        assert_eq!(links(&doc.children[2]), ["/q"]);
+    }
+
     #[test]
     fn test_parse_image() {
         let allocator = Allocator::new();
@@ -1384,6 +2687,37 @@ mod tests {
         assert!(matches!(&doc.children[0], Node::ThematicBreak(_)));
     }
 
+    #[test]
+    fn test_parse_dash_runs() {
+        let allocator = Allocator::new();
+        let kinds = |source| {
+            Parser::new(&allocator, source)
+                .parse()
+                .unwrap()
+                .children
+                .iter()
+                .map(|node| match node {
+                    Node::Heading(h) => format!("h{}", h.depth),
+                    Node::Paragraph(_) => "p".to_string(),
+                    Node::ThematicBreak(_) => "hr".to_string(),
+                    Node::List(_) => "list".to_string(),
+                    other => format!("{other:?}"),
+                })
+                .collect::<std::vec::Vec<_>>()
+        };
+
+        // Setext underline directly below paragraph text
+        assert_eq!(kinds("Title\n---\nBody"), ["h2", "p"]);
+        assert_eq!(kinds("Two\nlines\n="), ["h1"]);
+        // Thematic break when no paragraph precedes
+        assert_eq!(kinds("One\n\n---\n\nTwo"), ["p", "hr", "p"]);
+        assert_eq!(kinds("Text\n- - -"), ["p", "hr"]);
+        assert_eq!(kinds("==="), ["p"]);
+        // `- ` with content is a list item
+        assert_eq!(kinds("Text\n- item"), ["p", "list"]);
+        assert_eq!(kinds("- item\n\n---"), ["list", "hr"]);
+    }
+
     #[test]
     fn test_parse_fenced_code() {
         let allocator = Allocator::new();
@@ -1403,6 +2737,133 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_parse_fenced_code_preserves_unknown_lang() {
+        for lang in ["mermaid", "vue", "diff", "my-dsl"] {
+            let allocator = Allocator::new();
+            let source = format!("```{lang}\ncontent\n```");
+            let doc = Parser::new(&allocator, &source).parse().unwrap();
+            match &doc.children[0] {
+                Node::CodeBlock(code) => assert_eq!(code.lang, Some(lang)),
+                _ => panic!("expected code block"),
+            }
+        }
+    }
+
+    #[test]
+    fn test_parse_fenced_code_info_attributes() {
+        let allocator = Allocator::new();
+        let doc =
+            Parser::new(&allocator, "```ts title=\"foo.ts\" {1,2}\nlet a;\n```").parse().unwrap();
+        match &doc.children[0] {
+            Node::CodeBlock(code) => {
+                assert_eq!(code.meta, Some("title=\"foo.ts\" {1,2}"));
+                let info = code.info();
+                assert_eq!(info.lang, Some("ts"));
+                assert_eq!(info.title, Some("foo.ts"));
+                assert_eq!(info.highlights, vec![(1, 1), (2, 2)]);
+            }
+            _ => panic!("expected code block"),
+        }
+    }
+
+    #[test]
+    fn test_parse_fenced_code_with_shorter_fence_inside() {
+        let allocator = Allocator::new();
+        let source = "````md\n```\nnested\n\n  ``` trailing\n````\nafter";
+        let doc = Parser::new(&allocator, source).parse().unwrap();
+        assert_eq!(doc.children.len(), 2);
+        match &doc.children[0] {
+            Node::CodeBlock(code) => {
+                assert_eq!(code.value, "```\nnested\n\n  ``` trailing\n");
+            }
+            _ => panic!("expected code block"),
+        }
+    }
+
+    #[test]
+    fn test_parse_fenced_code_at_eof_without_newline() {
+        let allocator = Allocator::new();
+        let doc = Parser::new(&allocator, "```\n  indented\n\nlast\n```").parse().unwrap();
+        match &doc.children[0] {
+            Node::CodeBlock(code) => assert_eq!(code.value, "  indented\n\nlast\n"),
+            _ => panic!("expected code block"),
+        }
+
+        let allocator = Allocator::new();
+        let doc = Parser::new(&allocator, "```\nunterminated").parse().unwrap();
+        match &doc.children[0] {
+            Node::CodeBlock(code) => assert_eq!(code.value, "unterminated"),
+            _ => panic!("expected code block"),
+        }
+    }
+
+    #[test]
+    fn test_parse_indented_code() {
+        let allocator = Allocator::new();
+        let options = ParserOptions { indented_code: true, ..ParserOptions::default() };
+        let source = "Intro\n\n    let x = 1;\n\n\tlet y = 2;\n\nAfter\n";
+        let doc = Parser::with_options(&allocator, source, options.clone()).parse().unwrap();
+        assert_eq!(doc.children.len(), 3);
+        match &doc.children[1] {
+            Node::CodeBlock(code) => {
+                assert_eq!(code.lang, None);
+                assert_eq!(code.value, "let x = 1;\n\nlet y = 2;\n");
+                assert_eq!(
+                    &source[code.span.start as usize..code.span.end as usize],
+                    "    let x = 1;\n\n\tlet y = 2;\n"
+                );
+            }
+            other => panic!("expected code block, got {other:?}"),
+        }
+        assert_eq!(paragraph_text(&doc.children[2]), "After");
+
+        // Indented lines continue a paragraph instead of starting code.
+        let doc = Parser::with_options(&allocator, "Text\n    more", options).parse().unwrap();
+        assert_eq!(doc.children.len(), 1);
+        assert!(matches!(&doc.children[0], Node::Paragraph(_)));
+    }
+
+    #[test]
+    fn test_parse_indented_code_disabled_by_default() {
+        let allocator = Allocator::new();
+        let doc = Parser::new(&allocator, "    Indented for readability.\n").parse().unwrap();
+        assert_eq!(doc.children.len(), 1);
+        assert_eq!(paragraph_text(&doc.children[0]), "Indented for readability.");
+    }
+
+    #[test]
+    fn test_parse_indented_continuation_is_not_code_by_default() {
+        let allocator = Allocator::new();
+
+        let doc =
+            Parser::new(&allocator, "1. one\n\n    continued para\n\n2. two\n").parse().unwrap();
+        assert_eq!(doc.children.len(), 3);
+        assert!(matches!(&doc.children[0], Node::List(_)));
+        assert_eq!(paragraph_text(&doc.children[1]), "continued para");
+        assert!(matches!(&doc.children[2], Node::List(_)));
+
+        let options = ParserOptions { containers: true, ..ParserOptions::default() };
+        let doc = Parser::with_options(&allocator, "::: tip\n    indented text\n:::\n", options)
+            .parse()
+            .unwrap();
+        match &doc.children[..] {
+            [Node::Container(container)] => {
+                assert_eq!(container.children.len(), 1);
+                assert_eq!(paragraph_text(&container.children[0]), "indented text");
+            }
+            other => panic!("expected a single container, got {other:?}"),
+        }
+
+        let options = ParserOptions { definition_lists: true, ..ParserOptions::default() };
+        let doc = Parser::with_options(&allocator, "Term\n: def\n\n    more detail\n", options)
+            .parse()
+            .unwrap();
+        assert_eq!(doc.children.len(), 2);
+        assert!(matches!(&doc.children[0], Node::DescriptionList(_)));
+        assert_eq!(paragraph_text(&doc.children[1]), "more detail");
+    }
+
     #[test]
     fn test_parse_inline_code() {
         let allocator = Allocator::new();
@@ -1416,6 +2877,222 @@ mod tests {
         }
     }
 
+    fn first_paragraph_children<'a>(doc: &'a Document<'a>) -> &'a [Node<'a>] {
+        match &doc.children[0] {
+            Node::Paragraph(paragraph) => &paragraph.children,
+            _ => panic!("expected paragraph"),
+        }
+    }
+
+    #[test]
+    fn test_parse_autolink_in_text() {
+        let allocator = Allocator::new();
+        let doc = Parser::with_options(
+            &allocator,
+            "See https://example.com/a_b. or www.example.com, mail me@example.com",
+            ParserOptions::gfm(),
+        )
+        .parse()
+        .unwrap();
+
+        let urls: std::vec::Vec<&str> = first_paragraph_children(&doc)
+            .iter()
+            .filter_map(|node| match node {
+                Node::Link(link) => Some(link.url),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(
+            urls,
+            vec!["https://example.com/a_b", "http://www.example.com", "mailto:me@example.com"]
+        );
+    }
+
+    #[test]
+    fn test_parse_autolink_not_in_code() {
+        let allocator = Allocator::new();
+        let doc = Parser::with_options(
+            &allocator,
+            "`https://example.com` and `me@example.com`",
+            ParserOptions::gfm(),
+        )
+        .parse()
+        .unwrap();
+
+        let children = first_paragraph_children(&doc);
+        assert!(!children.iter().any(|node| matches!(node, Node::Link(_))));
+        assert!(
+            matches!(&children[0], Node::InlineCode(code) if code.value == "https://example.com")
+        );
+
+        let doc =
+            Parser::with_options(&allocator, "```\nhttps://example.com\n```", ParserOptions::gfm())
+                .parse()
+                .unwrap();
+        assert!(
+            matches!(&doc.children[0], Node::CodeBlock(code) if code.value == "https://example.com\n")
+        );
+    }
+
+    #[test]
+    fn test_parse_autolink_not_in_link_text() {
+        let allocator = Allocator::new();
+        let doc = Parser::with_options(
+            &allocator,
+            "[https://example.com](https://example.org)",
+            ParserOptions::gfm(),
+        )
+        .parse()
+        .unwrap();
+
+        let Node::Link(link) = &first_paragraph_children(&doc)[0] else {
+            panic!("expected link");
+        };
+        assert_eq!(link.url, "https://example.org");
+        assert!(
+            matches!(&link.children[0], Node::Text(text) if text.value == "https://example.com")
+        );
+    }
+
+    #[test]
+    fn test_parse_autolink_disabled() {
+        let allocator = Allocator::new();
+        let doc = Parser::new(&allocator, "See https://example.com").parse().unwrap();
+        assert!(!first_paragraph_children(&doc).iter().any(|node| matches!(node, Node::Link(_))));
+    }
+
+    #[test]
+    fn test_parse_autolink_scan_is_linear() {
+        fn parse_time(source: &str) -> std::time::Duration {
+            let allocator = Allocator::new();
+            let start = std::time::Instant::now();
+            Parser::with_options(&allocator, source, ParserOptions::gfm()).parse().unwrap();
+            start.elapsed()
+        }
+
+        for unit in ["a.", "www.)", "a-b+", "a.b@c "] {
+            let small = parse_time(&unit.repeat(10_000));
+            let large = parse_time(&unit.repeat(40_000));
+            // Quadratic scanning would take about 16x as long.
+            assert!(
+                large < small * 8 + std::time::Duration::from_millis(20),
+                "{unit}: {small:?} vs {large:?}"
+            );
+        }
+
+        let source = format!("https://example.com/x{}", ")".repeat(20_000));
+        let allocator = Allocator::new();
+        let doc = Parser::with_options(&allocator, &source, ParserOptions::gfm()).parse().unwrap();
+        assert!(matches!(
+            &first_paragraph_children(&doc)[0],
+            Node::Link(link) if link.url == "https://example.com/x"
+        ));
+    }
+
+    #[test]
+    fn test_parse_autolink_cjk_text() {
+        let options = ParserOptions { sub_sup: true, ..ParserOptions::gfm() };
+        for source in [
+            "a日本語です",
+            "www.日本",
+            "a@日.jp",
+            "日本語の脚注[^1]です。\n\n[^1]: 注釈です",
+            "水はH~2~Oで、2^10^は千二十四です。",
+        ] {
+            let allocator = Allocator::new();
+            assert!(Parser::with_options(&allocator, source, options.clone()).parse().is_ok());
+        }
+
+        let allocator = Allocator::new();
+        let doc = Parser::with_options(
+            &allocator,
+            "詳細は https://example.jp を参照",
+            ParserOptions::gfm(),
+        )
+        .parse()
+        .unwrap();
+        assert!(matches!(
+            &first_paragraph_children(&doc)[1],
+            Node::Link(link) if link.url == "https://example.jp"
+        ));
+    }
+
+    #[test]
+    fn test_parse_mark() {
+        let allocator = Allocator::new();
+        let options = ParserOptions { mark: true, ..ParserOptions::default() };
+        let doc = Parser::with_options(&allocator, "a ==key *point*== b = c ==open", options)
+            .parse()
+            .unwrap();
+        let children = first_paragraph_children(&doc);
+        let Node::Mark(mark) = &children[1] else {
+            panic!("expected mark, got {:?}", children[1]);
+        };
+        assert!(matches!(&mark.children[0], Node::Text(t) if t.value == "key "));
+        assert!(matches!(&mark.children[1], Node::Emphasis(_)));
+        let rest: String = children[2..]
+            .iter()
+            .map(|node| match node {
+                Node::Text(t) => t.value,
+                other => panic!("unexpected node: {other:?}"),
+            })
+            .collect();
+        assert_eq!(rest, " b = c ==open");
+
+        let doc = Parser::new(&allocator, "==plain==").parse().unwrap();
+        assert!(!first_paragraph_children(&doc).iter().any(|node| matches!(node, Node::Mark(_))));
+    }
+
+    #[test]
+    fn test_parse_sub_sup() {
+        let allocator = Allocator::new();
+        let options = ParserOptions { sub_sup: true, ..ParserOptions::gfm() };
+        let doc = Parser::with_options(&allocator, "H~2~O x^2^ ~~gone~~ a ~ b ^c", options)
+            .parse()
+            .unwrap();
+        let children = first_paragraph_children(&doc);
+        let Node::Subscript(sub) = &children[1] else {
+            panic!("expected subscript, got {:?}", children[1]);
+        };
+        assert!(matches!(&sub.children[0], Node::Text(t) if t.value == "2"));
+        let Node::Superscript(sup) = &children[3] else {
+            panic!("expected superscript, got {:?}", children[3]);
+        };
+        assert!(matches!(&sup.children[0], Node::Text(t) if t.value == "2"));
+        assert!(matches!(&children[5], Node::Delete(_)));
+        assert!(children[6..].iter().all(|n| matches!(n, Node::Text(_))));
+
+        // Without strikethrough, `~~` stays literal instead of opening a subscript.
+        let options = ParserOptions { sub_sup: true, ..ParserOptions::default() };
+        let doc = Parser::with_options(&allocator, "~~x~~", options).parse().unwrap();
+        assert!(first_paragraph_children(&doc).iter().all(|n| matches!(n, Node::Text(_))));
+    }
+
+    #[test]
+    fn test_parse_containers() {
+        let allocator = Allocator::new();
+        let options = ParserOptions { containers: true, ..ParserOptions::default() };
+        let source =
+            "Intro\n::: warning Be careful\nOuter\n\n::: details\nInner\n:::\n:::\n\nAfter";
+        let doc = Parser::with_options(&allocator, source, options).parse().unwrap();
+        assert_eq!(doc.children.len(), 3);
+        let Node::Container(warning) = &doc.children[1] else {
+            panic!("expected container, got {:?}", doc.children[1]);
+        };
+        assert_eq!(warning.kind, "warning");
+        assert_eq!(warning.title, Some("Be careful"));
+        assert_eq!(warning.children.len(), 2);
+        let Node::Container(details) = &warning.children[1] else {
+            panic!("expected nested container, got {:?}", warning.children[1]);
+        };
+        assert_eq!(details.kind, "details");
+        assert_eq!(details.title, None);
+        assert!(matches!(&doc.children[2], Node::Paragraph(_)));
+
+        let doc = Parser::new(&allocator, "::: tip\ntext\n:::").parse().unwrap();
+        assert!(doc.children.iter().all(|node| matches!(node, Node::Paragraph(_))));
+    }
+
     #[test]
     fn test_parse_strikethrough() {
         let allocator = Allocator::new();
@@ -1443,6 +3120,149 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_parse_soft_break() {
+        let allocator = Allocator::new();
+        let doc = Parser::new(&allocator, "line 1\n   line 2  \nline 3").parse().unwrap();
+        let Node::Paragraph(p) = &doc.children[0] else {
+            panic!("expected paragraph");
+        };
+        let parts: std::vec::Vec<_> = p
+            .children
+            .iter()
+            .map(|n| match n {
+                Node::Text(t) => t.value.to_string(),
+                Node::Break(b) => format!(
+                    "<{}@{}..{}>",
+                    if b.hard { "hard" } else { "soft" },
+                    b.span.start,
+                    b.span.end
+                ),
+                other => panic!("unexpected node: {other:?}"),
+            })
+            .collect();
+        assert_eq!(parts, ["line 1", "<soft@6..10>", "line 2", "<hard@16..19>", "line 3"]);
+    }
+
+    /// Concatenates the text of a document made of a single plain-text paragraph.
+    fn paragraph_text(node: &Node<'_>) -> String {
+        match node {
+            Node::Paragraph(p) => p
+                .children
+                .iter()
+                .map(|n| match n {
+                    Node::Text(t) => t.value,
+                    other => panic!("unexpected node: {other:?}"),
+                })
+                .collect(),
+            _ => panic!("expected paragraph"),
+        }
+    }
+
+    #[test]
+    fn test_parse_entities_decoded() {
+        let allocator = Allocator::new();
+        let doc =
+            Parser::new(&allocator, "A &amp; B &#x2014; C&#35; &bogus; &amp").parse().unwrap();
+        assert_eq!(paragraph_text(&doc.children[0]), "A & B \u{2014} C# &bogus; &amp");
+    }
+
+    #[test]
+    fn test_parse_html_comments() {
+        let allocator = Allocator::new();
+        let doc = Parser::new(&allocator, "<!--\nblock\n-->\n\nText <!-- a\nb --> end <!-- open")
+            .parse()
+            .unwrap();
+
+        assert_eq!(doc.children.len(), 2);
+        assert!(matches!(&doc.children[0], Node::Html(html) if html.value == "<!--\nblock\n-->\n"));
+        let Node::Paragraph(paragraph) = &doc.children[1] else {
+            panic!("expected paragraph");
+        };
+        let (mut text, mut comments) = (String::new(), std::vec::Vec::new());
+        for node in &paragraph.children {
+            match node {
+                Node::Text(t) => text.push_str(t.value),
+                Node::Html(html) => comments.push(html.value),
+                other => panic!("unexpected node: {other:?}"),
+            }
+        }
+        assert_eq!(text, "Text  end <!-- open");
+        assert_eq!(comments, ["<!-- a\nb -->"]);
+    }
+
+    #[test]
+    fn test_parse_backslash_escapes() {
+        for ch in "!\"#$%&'()*+,-./:;<=>?@[\\]^_`{|}~".chars() {
+            let allocator = Allocator::new();
+            let source = format!("a\\{ch}b");
+            let doc = Parser::new(&allocator, &source).parse().unwrap();
+            assert_eq!(paragraph_text(&doc.children[0]), format!("a{ch}b"), "escaped {ch}");
+        }
+    }
+
+    #[test]
+    fn test_parse_backslash_before_non_punctuation() {
+        for (source, expected) in [("\\a", "\\a"), ("\\é", "\\é"), ("end\\", "end\\")] {
+            let allocator = Allocator::new();
+            let doc = Parser::new(&allocator, source).parse().unwrap();
+            assert_eq!(paragraph_text(&doc.children[0]), expected);
+        }
+    }
+
+    #[test]
+    fn test_parse_hard_break_crlf() {
+        let allocator = Allocator::new();
+        let doc = Parser::new(&allocator, "line 1\\\r\nline 2").parse().unwrap();
+        match &doc.children[0] {
+            Node::Paragraph(p) => {
+                assert!(p.children.iter().any(|n| matches!(n, Node::Break(_))));
+            }
+            _ => panic!("expected paragraph"),
+        }
+    }
+
+    #[test]
+    fn test_parse_footnote_multiline_definition() {
+        let allocator = Allocator::new();
+        let source = "Text.[^note]\n\n[^note]: First line\ncontinued here.\n\n    Second paragraph.\n\nAfter.";
+        let doc = Parser::with_options(&allocator, source, ParserOptions::gfm()).parse().unwrap();
+        assert_eq!(doc.children.len(), 3);
+
+        let Node::Paragraph(paragraph) = &doc.children[0] else {
+            panic!("expected paragraph, got {:?}", &doc.children[0]);
+        };
+        let Node::FootnoteReference(footnote_ref) = &paragraph.children[1] else {
+            panic!("expected footnote reference, got {:?}", &paragraph.children[1]);
+        };
+        assert_eq!(footnote_ref.identifier, "note");
+        assert_eq!(footnote_ref.span, Span::new(5, 12));
+
+        let Node::FootnoteDefinition(definition) = &doc.children[1] else {
+            panic!("expected footnote definition, got {:?}", &doc.children[1]);
+        };
+        assert_eq!(definition.identifier, footnote_ref.identifier);
+        assert_eq!(definition.children.len(), 2);
+        let Node::Paragraph(first) = &definition.children[0] else {
+            panic!("expected paragraph, got {:?}", &definition.children[0]);
+        };
+        assert!(matches!(
+            first.children.as_slice(),
+            [Node::Text(a), Node::Break(_), Node::Text(b)]
+                if a.value == "First line" && b.value == "continued here."
+        ));
+        assert_eq!(paragraph_text(&definition.children[1]), "Second paragraph.");
+        assert_eq!(paragraph_text(&doc.children[2]), "After.");
+    }
+
+    #[test]
+    fn test_parse_footnotes_disabled() {
+        let allocator = Allocator::new();
+        let doc = Parser::new(&allocator, "Text.[^1]\n\n[^1]: Note.").parse().unwrap();
+        assert!(doc.children.iter().all(|node| matches!(node, Node::Paragraph(_))));
+        assert_eq!(paragraph_text(&doc.children[0]), "Text.[^1]");
+    }
+
     #[test]
     fn test_parse_table() {
         let allocator = Allocator::new();
@@ -1458,6 +3278,53 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_parse_table_normalizes_column_count() {
+        let allocator = Allocator::new();
+        let table_md = "| a | b | c |\n|---|---|---|\n| 1 |\n| 1 | 2 | 3 | 4 |";
+        let parser = Parser::with_options(&allocator, table_md, ParserOptions::gfm());
+        let doc = parser.parse().unwrap();
+        let Node::Table(table) = &doc.children[0] else {
+            panic!("expected table, got {:?}", &doc.children[0]);
+        };
+        let widths: std::vec::Vec<usize> =
+            table.children.iter().map(|row| row.children.len()).collect();
+        assert_eq!(widths, [3, 3, 3]);
+
+        // The short row is padded with empty cells.
+        assert_eq!(table.children[1].children[0].children.len(), 1);
+        assert!(table.children[1].children[1].children.is_empty());
+        assert!(table.children[1].children[2].children.is_empty());
+
+        // The long row drops its extra cell.
+        let Node::Text(last) = &table.children[2].children[2].children[0] else {
+            panic!("expected text");
+        };
+        assert_eq!(last.value, "3");
+    }
+
+    #[test]
+    fn test_parse_table_code_span_with_pipe() {
+        let allocator = Allocator::new();
+        let table_md = "| Syntax | Meaning |\n|---|---|\n| `a|b` | either |\n| `open | shut |";
+        let parser = Parser::with_options(&allocator, table_md, ParserOptions::gfm());
+        let doc = parser.parse().unwrap();
+        let Node::Table(table) = &doc.children[0] else {
+            panic!("expected table, got {:?}", &doc.children[0]);
+        };
+        let cell = |row: usize, col: usize| &table.children[row].children[col].children;
+
+        let Node::InlineCode(code) = &cell(1, 0)[0] else { panic!("expected inline code") };
+        assert_eq!(code.value, "a|b");
+        assert!(matches!(&cell(1, 1)[0], Node::Text(t) if t.value == "either"));
+
+        assert_eq!(Parser::parse_table_row_cells("| ``x`|`y`` | \\| |"), ["``x`|`y``", "\\|"]);
+
+        // An unclosed backtick does not swallow the rest of the row.
+        assert!(matches!(&cell(2, 0)[0], Node::Text(t) if t.value == "`open"));
+        assert!(matches!(&cell(2, 1)[0], Node::Text(t) if t.value == "shut"));
+    }
+
     #[test]
     fn test_parse_unordered_list() {
         let allocator = Allocator::new();
@@ -1490,6 +3357,64 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_parse_ordered_list_start() {
+        let allocator = Allocator::new();
+        let parser = Parser::new(&allocator, "3. first\n4. second");
+        let doc = parser.parse().unwrap();
+        assert_eq!(doc.children.len(), 1);
+        match &doc.children[0] {
+            Node::List(list) => {
+                assert!(list.ordered);
+                assert_eq!(list.start, Some(3));
+                assert_eq!(list.delimiter, Some(ListDelimiter::Period));
+                assert_eq!(list.children.len(), 2);
+            }
+            _ => panic!("expected list, got {:?}", &doc.children[0]),
+        }
+    }
+
+    #[test]
+    fn test_parse_ordered_list_delimiter_change() {
+        let allocator = Allocator::new();
+        let parser = Parser::new(&allocator, "1. one\n2. two\n3) three");
+        let doc = parser.parse().unwrap();
+        assert_eq!(doc.children.len(), 2);
+        let Node::List(first) = &doc.children[0] else {
+            panic!("expected list, got {:?}", &doc.children[0]);
+        };
+        assert_eq!(first.delimiter, Some(ListDelimiter::Period));
+        assert_eq!(first.start, Some(1));
+        assert_eq!(first.children.len(), 2);
+        let Node::List(second) = &doc.children[1] else {
+            panic!("expected list, got {:?}", &doc.children[1]);
+        };
+        assert_eq!(second.delimiter, Some(ListDelimiter::Paren));
+        assert_eq!(second.start, Some(3));
+        assert_eq!(second.children.len(), 1);
+    }
+
+    #[test]
+    fn test_parse_nested_list_parent() {
+        let allocator = Allocator::new();
+        let parser = Parser::new(&allocator, "1. a\n2. b\n   - b1\n   - b2\n3. c");
+        let doc = parser.parse().unwrap();
+        assert_eq!(doc.children.len(), 1);
+        let Node::List(list) = &doc.children[0] else {
+            panic!("expected list, got {:?}", &doc.children[0]);
+        };
+        assert_eq!(list.children.len(), 3);
+        assert_eq!(list.children[0].children.len(), 1);
+        assert_eq!(list.children[2].children.len(), 1);
+        let Node::List(nested) = &list.children[1].children[1] else {
+            panic!("expected nested list, got {:?}", &list.children[1].children);
+        };
+        assert!(!nested.ordered);
+        assert_eq!(nested.start, None);
+        assert_eq!(nested.delimiter, None);
+        assert_eq!(nested.children.len(), 2);
+    }
+
     #[test]
     fn test_parse_block_quote() {
         let allocator = Allocator::new();
@@ -1530,4 +3455,125 @@ mod tests {
             _ => panic!("expected block quote, got {:?}", &doc.children[0]),
         }
     }
+
+    #[test]
+    fn test_parse_block_quote_nesting_error_span() {
+        let allocator = Allocator::new();
+        let options = ParserOptions { max_nesting_depth: 2, ..ParserOptions::default() };
+        let source = "intro\n\n> > > > deep";
+        let err = Parser::with_options(&allocator, source, options).parse().unwrap_err();
+        assert!(matches!(err, ParseError::NestingTooDeep { max_depth: 2, .. }));
+        // The fourth `>` is where nesting exceeds the limit.
+        assert_eq!(err.span().start, 13);
+        assert_eq!(&source[err.span().start as usize..], "> deep");
+    }
+
+    #[test]
+    fn test_parse_recover_collects_errors() {
+        let allocator = Allocator::new();
+        let options =
+            ParserOptions { max_nesting_depth: 1, recover: true, ..ParserOptions::default() };
+        let source = "# Title\n\n> > > first\n\n> > > second\n\nafter";
+        let (doc, errors) = Parser::with_options(&allocator, source, options).parse_with_errors();
+
+        assert_eq!(errors.len(), 2);
+        assert!(errors.iter().all(|e| matches!(e, ParseError::NestingTooDeep { .. })));
+        assert_eq!(&source[errors[0].span().start as usize..][..7], "> first");
+        assert_eq!(&source[errors[1].span().start as usize..][..8], "> second");
+
+        assert_eq!(doc.children.len(), 4);
+        assert!(matches!(&doc.children[0], Node::Heading(_)));
+        let Node::BlockQuote(outer) = &doc.children[1] else {
+            panic!("expected block quote, got {:?}", &doc.children[1]);
+        };
+        let Node::BlockQuote(inner) = &outer.children[0] else {
+            panic!("expected nested block quote, got {:?}", &outer.children[0]);
+        };
+        assert_eq!(paragraph_text(&inner.children[0]), "> first");
+        assert!(matches!(&doc.children[2], Node::BlockQuote(_)));
+        assert_eq!(paragraph_text(&doc.children[3]), "after");
+    }
+
+    #[test]
+    fn test_parse_without_recover_stops_at_first_error() {
+        let allocator = Allocator::new();
+        let options = ParserOptions { max_nesting_depth: 1, ..ParserOptions::default() };
+        let source = "# Title\n\n> > > first\n\nafter";
+
+        let (doc, errors) =
+            Parser::with_options(&allocator, source, options.clone()).parse_with_errors();
+        assert_eq!(errors.len(), 1);
+        assert_eq!(doc.children.len(), 1);
+        assert!(Parser::with_options(&allocator, source, options).parse().is_err());
+    }
+
+    fn definition_list_options() -> ParserOptions {
+        ParserOptions { definition_lists: true, ..ParserOptions::default() }
+    }
+
+    #[test]
+    fn test_parse_definition_list_single() {
+        let allocator = Allocator::new();
+        let parser =
+            Parser::with_options(&allocator, "Apple\n: A fruit", definition_list_options());
+        let doc = parser.parse().unwrap();
+        assert_eq!(doc.children.len(), 1);
+        match &doc.children[0] {
+            Node::DescriptionList(dl) => {
+                assert_eq!(dl.children.len(), 2);
+                assert!(matches!(&dl.children[0], Node::DescriptionTerm(_)));
+                match &dl.children[1] {
+                    Node::DescriptionDetails(dd) => match &dd.children[0] {
+                        Node::Text(text) => assert_eq!(text.value, "A fruit"),
+                        other => panic!("expected text, got {other:?}"),
+                    },
+                    other => panic!("expected details, got {other:?}"),
+                }
+            }
+            _ => panic!("expected definition list, got {:?}", &doc.children[0]),
+        }
+    }
+
+    #[test]
+    fn test_parse_definition_list_multiple() {
+        let allocator = Allocator::new();
+        let source = "Apple\n: A fruit\n: A company\n\nOrange\n: A color";
+        let parser = Parser::with_options(&allocator, source, definition_list_options());
+        let doc = parser.parse().unwrap();
+        assert_eq!(doc.children.len(), 1);
+        match &doc.children[0] {
+            Node::DescriptionList(dl) => {
+                let kinds: std::vec::Vec<&str> = dl
+                    .children
+                    .iter()
+                    .map(|node| match node {
+                        Node::DescriptionTerm(_) => "dt",
+                        Node::DescriptionDetails(_) => "dd",
+                        _ => "other",
+                    })
+                    .collect();
+                assert_eq!(kinds, ["dt", "dd", "dd", "dt", "dd"]);
+            }
+            _ => panic!("expected definition list, got {:?}", &doc.children[0]),
+        }
+    }
+
+    #[test]
+    fn test_parse_definition_list_disabled() {
+        let allocator = Allocator::new();
+        let doc = Parser::new(&allocator, "Apple\n: A fruit").parse().unwrap();
+        assert_eq!(doc.children.len(), 1);
+        assert!(matches!(&doc.children[0], Node::Paragraph(_)));
+    }
+
+    #[test]
+    fn test_parse_definition_list_ends_paragraph() {
+        let allocator = Allocator::new();
+        let source = "Intro text\nApple\n: A fruit";
+        let parser = Parser::with_options(&allocator, source, definition_list_options());
+        let doc = parser.parse().unwrap();
+        assert_eq!(doc.children.len(), 2);
+        assert!(matches!(&doc.children[0], Node::Paragraph(_)));
+        assert!(matches!(&doc.children[1], Node::DescriptionList(_)));
+    }
 }
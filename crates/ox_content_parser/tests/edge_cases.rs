@@ -1,5 +1,5 @@
 use ox_content_allocator::Allocator;
-use ox_content_ast::{AlignKind, Node};
+use ox_content_ast::{AlignKind, ListDelimiter, Node};
 use ox_content_parser::{Parser, ParserOptions};
 
 fn parse_with_options<'a>(
@@ -54,6 +54,30 @@ fn blank_input_yields_empty_document() {
     assert!(doc.children.is_empty());
 }
 
+#[test]
+fn whitespace_only_input_yields_empty_document() {
+    let allocator = Allocator::new();
+    for source in ["   ", " \n\t\n  ", "\r\n \r\n", "\u{feff}  \n"] {
+        let doc = parse_with_options(&allocator, source, ParserOptions::default());
+        assert!(doc.children.is_empty(), "{source:?}: {:?}", doc.children);
+    }
+}
+
+#[test]
+fn leading_bom_is_not_part_of_first_block() {
+    let allocator = Allocator::new();
+    let doc = parse_with_options(&allocator, "\u{feff}# Title\n\nBody\n", ParserOptions::default());
+    assert_eq!(doc.children.len(), 2);
+    match &doc.children[0] {
+        Node::Heading(heading) => {
+            assert_eq!(heading.depth, 1);
+            assert_eq!(first_text_in_nodes(heading.children.iter()), Some("Title"));
+            assert_eq!(heading.span.start, 3);
+        }
+        other => panic!("expected heading, got {other:?}"),
+    }
+}
+
 #[test]
 fn heading_trims_closing_hashes() {
     let allocator = Allocator::new();
@@ -134,6 +158,7 @@ fn ordered_list_start_and_parenthesis_marker_are_preserved() {
         Node::List(list) => {
             assert!(list.ordered);
             assert_eq!(list.start, Some(3));
+            assert_eq!(list.delimiter, Some(ListDelimiter::Paren));
             assert_eq!(list.children.len(), 2);
         }
         other => panic!("expected list, got {other:?}"),
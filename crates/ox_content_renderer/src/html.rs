@@ -1,21 +1,26 @@
 //! HTML renderer implementation.
 
+use std::borrow::Cow;
 use std::collections::BTreeMap;
 
 use ox_content_ast::{
-    BlockQuote, Break, CodeBlock, Definition, Delete, Document, Emphasis, FootnoteDefinition,
-    FootnoteReference, Heading, Html, Image, InlineCode, Link, List, ListItem, Node, Paragraph,
-    Strong, Table, TableCell, TableRow, Text, ThematicBreak, Visit,
+    walk_node, BlockQuote, Break, CodeBlock, Container, Definition, Delete, DescriptionDetails,
+    DescriptionList, DescriptionTerm, Document, Emphasis, FootnoteDefinition, FootnoteReference,
+    Heading, Html, Image, InlineCode, Link, List, ListItem, Mark, Node, Paragraph, Strong,
+    Subscript, Superscript, Table, TableCell, TableRow, Text, ThematicBreak, Visit,
 };
 
-use crate::render::{RenderResult, Renderer};
+use crate::render::{RenderHook, RenderResult, Renderer};
+use crate::slug::{SlugStrategy, Slugger};
 
 /// HTML renderer options.
 #[derive(Debug, Clone)]
 pub struct HtmlRendererOptions {
-    /// Use XHTML-style self-closing tags (e.g., `<br />`).
+    /// Use XHTML-style self-closing tags (`<br />`, `<hr />`, `<img />`, `<input />`).
+    ///
+    /// Also applies to `<br>` in `hard_break` and `soft_break`.
     pub xhtml: bool,
-    /// Add soft breaks between inline elements.
+    /// Output for soft line breaks within a paragraph (e.g. `"\n"` or `"<br>\n"`).
     pub soft_break: String,
     /// Add hard breaks.
     pub hard_break: String,
@@ -38,6 +43,53 @@ pub struct HtmlRendererOptions {
     pub code_annotation_syntax: CodeAnnotationSyntax,
     /// Enable line numbers for all code blocks by default.
     pub code_annotation_default_line_numbers: bool,
+    /// Add `loading="lazy"` to `<img>` tags.
+    pub image_lazy_loading: bool,
+    /// Add `decoding="async"` to `<img>` tags.
+    pub image_async_decoding: bool,
+    /// Add an `id` and a `#` permalink anchor to headings.
+    pub heading_anchors: bool,
+    /// How heading ids are derived from heading text.
+    ///
+    /// Use the same strategy when building a table of contents so its links match.
+    pub slug_strategy: SlugStrategy,
+    /// Prefix for generated ids (headings, footnotes, and their back-references).
+    ///
+    /// Use a distinct prefix per document when several are concatenated into one page.
+    pub id_prefix: Option<String>,
+    /// Drop the final newline of code block content so `</code>` follows the last line.
+    pub trim_code_trailing_newline: bool,
+    /// Wrap tables in a horizontally scrollable `<div class="table-wrapper">`.
+    pub wrap_tables: bool,
+    /// Add `role`/`aria-label` attributes to callouts and code blocks.
+    pub aria_attributes: bool,
+    /// Wrap code blocks in `<div class="code-block-wrapper">` with a
+    /// `<button class="copy-code">` for client-side copy scripts to hook into.
+    /// Mermaid blocks are left unwrapped since they render as diagrams.
+    pub copy_button: bool,
+    /// Shift heading levels by this amount (e.g. `1` renders `#` as `<h2>`), clamped to 1..=6.
+    pub heading_offset: i8,
+    /// Drop HTML comments (`<!-- ... -->`) instead of passing them through.
+    pub strip_comments: bool,
+    /// Render an image with a title that stands alone in a paragraph as
+    /// `<figure>` with the title as `<figcaption>`.
+    pub figures: bool,
+    /// Add `rel="noopener noreferrer"` to external (`http://`/`https://`) links.
+    pub external_rel: bool,
+    /// Open external (`http://`/`https://`) links in a new tab with `target="_blank"`.
+    pub external_target_blank: bool,
+    /// How link and image URLs are percent-encoded.
+    pub url_encode: UrlEncode,
+    /// Add `translate="no"` to `<code>` elements so browser and CDN
+    /// auto-translation leaves code intact.
+    pub code_translate_no: bool,
+    /// With `code_translate_no`, also add the `notranslate` class, which some
+    /// translation services check instead of the attribute.
+    pub code_notranslate_class: bool,
+    /// Element `(tag, class)` wrapping the whole rendered document, such as
+    /// `("div", "prose")`. An empty class omits the `class` attribute. A tag
+    /// rejected by [`is_valid_tag_name`] is ignored.
+    pub container: Option<(String, String)>,
 }
 
 impl HtmlRendererOptions {
@@ -57,6 +109,24 @@ impl HtmlRendererOptions {
             code_annotation_meta_key: "annotate".to_string(),
             code_annotation_syntax: CodeAnnotationSyntax::Attribute,
             code_annotation_default_line_numbers: false,
+            image_lazy_loading: false,
+            image_async_decoding: false,
+            heading_anchors: false,
+            slug_strategy: SlugStrategy::PreserveUnicode,
+            id_prefix: None,
+            trim_code_trailing_newline: true,
+            wrap_tables: false,
+            aria_attributes: false,
+            copy_button: false,
+            heading_offset: 0,
+            strip_comments: false,
+            figures: false,
+            external_rel: true,
+            external_target_blank: true,
+            url_encode: UrlEncode::Minimal,
+            code_translate_no: false,
+            code_notranslate_class: false,
+            container: None,
         }
     }
 }
@@ -67,6 +137,56 @@ impl Default for HtmlRendererOptions {
     }
 }
 
+/// Percent-encoding policy for link `href` and image `src` URLs.
+///
+/// Every policy escapes `&` and keeps the attribute well-formed; existing
+/// `%XX` escapes are never re-encoded.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum UrlEncode {
+    /// Encode only spaces, `<`, `>` and `"`.
+    #[default]
+    Minimal,
+    /// Encode every byte outside the URI character set, including non-ASCII
+    /// characters and `%` signs that do not start an escape.
+    Strict,
+    /// Write URLs as given, only escaping HTML-significant characters.
+    None,
+}
+
+impl UrlEncode {
+    /// Returns the replacement for `byte` at `idx` in `url`, if it needs one.
+    fn escape(self, url: &[u8], idx: usize) -> Option<Cow<'static, str>> {
+        let byte = url[idx];
+        let escaped = match (self, byte) {
+            (_, b'&') => "&amp;",
+            (Self::None, b'"') => "&quot;",
+            (Self::None, b'<') => "&lt;",
+            (Self::None, b'>') => "&gt;",
+            (Self::None, _) => return None,
+            (_, b'<') => "%3C",
+            (_, b'>') => "%3E",
+            (_, b'"') => "%22",
+            (_, b' ') => "%20",
+            (Self::Minimal, _) => return None,
+            (Self::Strict, b'%') => {
+                let is_escape = url
+                    .get(idx + 1..idx + 3)
+                    .is_some_and(|hex| hex.iter().all(u8::is_ascii_hexdigit));
+                return (!is_escape).then_some(Cow::Borrowed("%25"));
+            }
+            (Self::Strict, _) if is_uri_byte(byte) => return None,
+            (Self::Strict, _) => return Some(Cow::Owned(format!("%{byte:02X}"))),
+        };
+        Some(Cow::Borrowed(escaped))
+    }
+}
+
+/// Returns whether `byte` may appear unencoded in a URI (RFC 3986 unreserved
+/// and reserved characters).
+fn is_uri_byte(byte: u8) -> bool {
+    byte.is_ascii_alphanumeric() || b"-._~:/?#[]@!$&'()*+,;=".contains(&byte)
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum CodeAnnotationSyntax {
     Attribute,
@@ -707,41 +827,132 @@ fn parse_vitepress_inline_annotations(value: &str) -> Vec<CodeLineRenderState> {
     lines
 }
 
+/// Recursively collects plain text from inline nodes.
+fn collect_text(nodes: &[Node<'_>], text: &mut String) {
+    for node in nodes {
+        match node {
+            Node::Text(t) => text.push_str(t.value),
+            Node::InlineCode(c) => text.push_str(c.value),
+            Node::Break(_) => text.push(' '),
+            Node::Emphasis(e) => collect_text(&e.children, text),
+            Node::Strong(s) => collect_text(&s.children, text),
+            Node::Delete(d) => collect_text(&d.children, text),
+            Node::Mark(m) => collect_text(&m.children, text),
+            Node::Subscript(s) => collect_text(&s.children, text),
+            Node::Superscript(s) => collect_text(&s.children, text),
+            Node::Link(l) => collect_text(&l.children, text),
+            _ => {}
+        }
+    }
+}
+
+/// Returns whether `tag` is a plain element name: `[A-Za-z][A-Za-z0-9-]*`.
+#[must_use]
+pub fn is_valid_tag_name(tag: &str) -> bool {
+    let mut bytes = tag.bytes();
+    bytes.next().is_some_and(|b| b.is_ascii_alphabetic())
+        && bytes.all(|b| b.is_ascii_alphanumeric() || b == b'-')
+}
+
+/// Removes HTML comments; an unterminated comment runs to the end of `html`.
+fn strip_html_comments(html: &str) -> Cow<'_, str> {
+    if !html.contains("<!--") {
+        return Cow::Borrowed(html);
+    }
+
+    let mut result = String::with_capacity(html.len());
+    let mut rest = html;
+    while let Some(start) = rest.find("<!--") {
+        result.push_str(&rest[..start]);
+        rest = rest[start + 4..].find("-->").map_or("", |end| &rest[start + 4 + end + 3..]);
+    }
+    result.push_str(rest);
+    Cow::Owned(result)
+}
+
 /// HTML renderer.
 pub struct HtmlRenderer {
     options: HtmlRendererOptions,
     output: String,
+    /// Whether inline content (paragraph, heading, cell) is being rendered.
+    in_inline: bool,
+    /// Hooks consulted, in order, before the default rendering of each node.
+    hooks: Vec<Box<dyn RenderHook>>,
+    /// Heading ids handed out in the current document.
+    slugger: Slugger,
 }
 
 impl HtmlRenderer {
     /// Creates a new HTML renderer with default options.
     #[must_use]
     pub fn new() -> Self {
-        Self { options: HtmlRendererOptions::new(), output: String::new() }
+        Self::with_options(HtmlRendererOptions::new())
     }
 
     /// Creates a new HTML renderer with the specified options.
     #[must_use]
     pub fn with_options(options: HtmlRendererOptions) -> Self {
-        Self { options, output: String::new() }
+        let slugger = Slugger::new(options.slug_strategy);
+        Self { options, output: String::new(), in_inline: false, hooks: Vec::new(), slugger }
+    }
+
+    /// Registers a hook that can take over rendering of individual nodes.
+    ///
+    /// Hooks run in registration order; the first to return `true` handles the node.
+    pub fn add_hook(&mut self, hook: impl RenderHook + 'static) -> &mut Self {
+        self.hooks.push(Box::new(hook));
+        self
     }
 
     /// Renders a document to HTML string.
     #[must_use]
     pub fn render(&mut self, document: &Document<'_>) -> String {
         self.output.clear();
+        self.slugger = Slugger::new(self.options.slug_strategy);
         let estimated_len = (document.span.len() as usize).saturating_mul(3) / 2;
         if self.output.capacity() < estimated_len {
             self.output.reserve(estimated_len - self.output.capacity());
         }
+        let container = self.options.container.take();
+        let wrapper = container.as_ref().filter(|(tag, _)| is_valid_tag_name(tag));
+        if let Some((tag, class)) = wrapper {
+            self.write("<");
+            self.write(tag);
+            if !class.is_empty() {
+                self.write(" class=\"");
+                self.write_escaped(class);
+                self.write("\"");
+            }
+            self.write(">\n");
+        }
         self.visit_document(document);
+        if let Some((tag, _)) = wrapper {
+            self.write("</");
+            self.write(tag);
+            self.write(">\n");
+        }
+        self.options.container = container;
         std::mem::take(&mut self.output)
     }
 
+    /// Visits the inline children of a paragraph, heading, or similar node.
+    fn visit_inline_children<'a>(&mut self, children: &[Node<'a>]) {
+        let outer = std::mem::replace(&mut self.in_inline, true);
+        for child in children {
+            self.visit_node(child);
+        }
+        self.in_inline = outer;
+    }
+
     fn write(&mut self, s: &str) {
         self.output.push_str(s);
     }
 
+    /// Closes a void element (`>` or ` />` in XHTML mode).
+    fn write_void_end(&mut self) {
+        self.write(if self.options.xhtml { " />" } else { ">" });
+    }
+
     fn write_escaped(&mut self, s: &str) {
         let bytes = s.as_bytes();
         let mut start = 0;
@@ -770,25 +981,25 @@ impl HtmlRenderer {
         }
     }
 
+    /// Writes a generated id, prepending the configured id prefix.
+    fn write_id(&mut self, id: &str) {
+        if let Some(prefix) = self.options.id_prefix.clone() {
+            self.write_escaped(&prefix);
+        }
+        self.write_escaped(id);
+    }
+
     fn write_url_escaped(&mut self, s: &str) {
+        let policy = self.options.url_encode;
         let bytes = s.as_bytes();
         let mut start = 0;
 
-        for (idx, byte) in bytes.iter().copied().enumerate() {
-            let escaped = match byte {
-                b'&' => Some("&amp;"),
-                b'<' => Some("%3C"),
-                b'>' => Some("%3E"),
-                b'"' => Some("%22"),
-                b' ' => Some("%20"),
-                _ => None,
-            };
-
-            if let Some(escaped) = escaped {
+        for idx in 0..bytes.len() {
+            if let Some(escaped) = policy.escape(bytes, idx) {
                 if start < idx {
                     self.output.push_str(&s[start..idx]);
                 }
-                self.output.push_str(escaped);
+                self.output.push_str(&escaped);
                 start = idx + 1;
             }
         }
@@ -816,6 +1027,8 @@ impl HtmlRenderer {
                     renderer.write_escaped(&text.value[skip_chars..]);
                     skip_chars = 0;
                 }
+                // The line ending after a marker on its own line.
+                Node::Break(break_node) if !break_node.hard && renderer.output.is_empty() => {}
                 _ => renderer.visit_node(child),
             }
         }
@@ -852,7 +1065,13 @@ impl HtmlRenderer {
 
         self.write("<blockquote class=\"ox-callout ox-callout--");
         self.write(kind.class_name());
-        self.write("\">\n");
+        self.write("\"");
+        if self.options.aria_attributes {
+            self.write(" role=\"note\" aria-label=\"");
+            self.write(kind.label());
+            self.write("\"");
+        }
+        self.write(">\n");
         self.write("<p class=\"ox-callout-title\">");
         self.write(kind.label());
         self.write("</p>\n");
@@ -873,6 +1092,73 @@ impl HtmlRenderer {
         true
     }
 
+    /// Writes a code block's `<pre><code>` element.
+    fn write_code_block(&mut self, code_block: &CodeBlock<'_>) {
+        if !self.options.code_annotations {
+            self.write("<pre");
+            self.write_code_block_aria();
+            self.write(">");
+            self.write_code_open(normalize_code_block_language(code_block.lang));
+            self.write_escaped(self.code_block_value(code_block));
+            self.write("</code></pre>\n");
+            return;
+        }
+
+        let state = self.build_code_block_state(code_block);
+        let block_classes = state.block_classes();
+
+        self.write("<pre");
+        if !block_classes.is_empty() {
+            self.write(" class=\"");
+            self.write(&block_classes.join(" "));
+            self.write("\"");
+        }
+        if let Some(title) = state.title.as_deref() {
+            self.write(" data-code-title=\"");
+            self.write_escaped(title);
+            self.write("\"");
+        }
+        if let Some(start) = state.line_numbers_start {
+            self.write(" data-line-numbers=\"true\" data-line-number-start=\"");
+            self.write(&start.to_string());
+            self.write("\"");
+        }
+        self.write_code_block_aria();
+        self.write(">");
+        self.write_code_open(state.language.as_deref());
+        if state.needs_line_wrappers() {
+            self.write_code_lines(&state);
+        } else {
+            self.write_escaped(self.code_block_value(code_block));
+        }
+        self.write("</code></pre>\n");
+    }
+
+    /// Writes a `<code>` start tag with the language class and translation opt-out.
+    fn write_code_open(&mut self, lang: Option<&str>) {
+        self.write("<code");
+        let notranslate = self.options.code_translate_no && self.options.code_notranslate_class;
+        match (lang, notranslate) {
+            (Some(lang), _) => {
+                self.write(" class=\"language-");
+                self.write_escaped(lang);
+                self.write(if notranslate { " notranslate\"" } else { "\"" });
+            }
+            (None, true) => self.write(" class=\"notranslate\""),
+            (None, false) => {}
+        }
+        if self.options.code_translate_no {
+            self.write(" translate=\"no\"");
+        }
+        self.write(">");
+    }
+
+    fn write_code_block_aria(&mut self) {
+        if self.options.aria_attributes {
+            self.write(" role=\"region\" aria-label=\"code\"");
+        }
+    }
+
     fn build_code_block_state(&self, code_block: &CodeBlock<'_>) -> CodeBlockRenderState {
         let info = normalize_code_block_info(code_block.lang, code_block.meta);
         let syntax = self.options.code_annotation_syntax;
@@ -944,9 +1230,23 @@ impl HtmlRenderer {
             }
         }
 
+        if self.options.code_annotations && title.is_none() {
+            title = code_block.info().title.map(str::to_string);
+        }
+
         CodeBlockRenderState { language: info.language, title, line_numbers_start, lines }
     }
 
+    /// Returns the code block content to render, honoring `trim_code_trailing_newline`.
+    fn code_block_value<'a>(&self, code_block: &CodeBlock<'a>) -> &'a str {
+        let value = code_block.value;
+        if self.options.trim_code_trailing_newline {
+            value.strip_suffix('\n').unwrap_or(value)
+        } else {
+            value
+        }
+    }
+
     fn write_code_lines(&mut self, state: &CodeBlockRenderState) {
         let has_focus = state.has_focus();
 
@@ -1125,16 +1425,35 @@ impl Renderer for HtmlRenderer {
 }
 
 impl<'a> Visit<'a> for HtmlRenderer {
+    fn visit_node(&mut self, node: &Node<'a>) {
+        if self.hooks.iter().any(|hook| hook.render(node, &mut self.output)) {
+            return;
+        }
+        walk_node(self, node);
+    }
+
     fn visit_paragraph(&mut self, paragraph: &Paragraph<'a>) {
-        self.write("<p>");
-        for child in &paragraph.children {
-            self.visit_node(child);
+        if self.options.figures {
+            if let [node @ Node::Image(image)] = paragraph.children.as_slice() {
+                if let Some(title) = image.title {
+                    self.write("<figure>");
+                    self.visit_node(node);
+                    self.write("<figcaption>");
+                    self.write_escaped(title);
+                    self.write("</figcaption></figure>\n");
+                    return;
+                }
+            }
         }
+
+        self.write("<p>");
+        self.visit_inline_children(&paragraph.children);
         self.write("</p>\n");
     }
 
     fn visit_heading(&mut self, heading: &Heading<'a>) {
-        let tag = match heading.depth {
+        let depth = (i16::from(heading.depth) + i16::from(self.options.heading_offset)).clamp(1, 6);
+        let tag = match depth {
             1 => "h1",
             2 => "h2",
             3 => "h3",
@@ -1142,11 +1461,27 @@ impl<'a> Visit<'a> for HtmlRenderer {
             5 => "h5",
             _ => "h6",
         };
+        let slug = if self.options.heading_anchors {
+            let mut text = String::new();
+            collect_text(&heading.children, &mut text);
+            Some(self.slugger.slug(&text))
+        } else {
+            None
+        };
+
         self.write("<");
         self.write(tag);
+        if let Some(slug) = slug.as_deref() {
+            self.write(" id=\"");
+            self.write_id(slug);
+            self.write("\"");
+        }
         self.write(">");
-        for child in &heading.children {
-            self.visit_node(child);
+        self.visit_inline_children(&heading.children);
+        if let Some(slug) = slug.as_deref() {
+            self.write(" <a class=\"header-anchor\" href=\"#");
+            self.write_id(slug);
+            self.write("\" aria-label=\"Permalink\">#</a>");
         }
         self.write("</");
         self.write(tag);
@@ -1154,11 +1489,9 @@ impl<'a> Visit<'a> for HtmlRenderer {
     }
 
     fn visit_thematic_break(&mut self, _thematic_break: &ThematicBreak) {
-        if self.options.xhtml {
-            self.write("<hr />\n");
-        } else {
-            self.write("<hr>\n");
-        }
+        self.write("<hr");
+        self.write_void_end();
+        self.write("\n");
     }
 
     fn visit_block_quote(&mut self, block_quote: &BlockQuote<'a>) {
@@ -1202,14 +1535,19 @@ impl<'a> Visit<'a> for HtmlRenderer {
     }
 
     fn visit_list_item(&mut self, list_item: &ListItem<'a>) {
+        if self.hooks.iter().any(|hook| hook.render_list_item(list_item, &mut self.output)) {
+            return;
+        }
         self.write("<li>");
 
         if let Some(checked) = list_item.checked {
             if checked {
-                self.write("<input type=\"checkbox\" checked disabled> ");
+                self.write("<input type=\"checkbox\" checked disabled");
             } else {
-                self.write("<input type=\"checkbox\" disabled> ");
+                self.write("<input type=\"checkbox\" disabled");
             }
+            self.write_void_end();
+            self.write(" ");
         }
 
         for child in &list_item.children {
@@ -1220,63 +1558,43 @@ impl<'a> Visit<'a> for HtmlRenderer {
     }
 
     fn visit_code_block(&mut self, code_block: &CodeBlock<'a>) {
-        if !self.options.code_annotations {
-            self.write("<pre><code");
-            if let Some(lang) = normalize_code_block_language(code_block.lang) {
-                self.write(" class=\"language-");
-                self.write_escaped(lang);
-                self.write("\"");
-            }
-            self.write(">");
-            self.write_escaped(code_block.value);
-            self.write("</code></pre>\n");
-            return;
+        let copy_button = self.options.copy_button
+            && normalize_code_block_language(code_block.lang) != Some("mermaid");
+        if copy_button {
+            self.write("<div class=\"code-block-wrapper\">\n");
         }
-
-        let state = self.build_code_block_state(code_block);
-        let block_classes = state.block_classes();
-
-        self.write("<pre");
-        if !block_classes.is_empty() {
-            self.write(" class=\"");
-            self.write(&block_classes.join(" "));
-            self.write("\"");
+        self.write_code_block(code_block);
+        if copy_button {
+            self.write("<button type=\"button\" class=\"copy-code\" aria-label=\"Copy code\">");
+            self.write("Copy</button>\n</div>\n");
         }
-        if let Some(title) = state.title.as_deref() {
-            self.write(" data-code-title=\"");
-            self.write_escaped(title);
-            self.write("\"");
-        }
-        if let Some(start) = state.line_numbers_start {
-            self.write(" data-line-numbers=\"true\" data-line-number-start=\"");
-            self.write(&start.to_string());
-            self.write("\"");
-        }
-        self.write("><code");
-        if let Some(lang) = state.language.as_deref() {
-            self.write(" class=\"language-");
-            self.write_escaped(lang);
-            self.write("\"");
-        }
-        self.write(">");
-        if state.needs_line_wrappers() {
-            self.write_code_lines(&state);
-        } else {
-            self.write_escaped(code_block.value);
-        }
-        self.write("</code></pre>\n");
     }
 
     fn visit_html(&mut self, html: &Html<'a>) {
+        let value = if self.options.strip_comments {
+            let stripped = strip_html_comments(html.value);
+            if stripped.trim().is_empty() {
+                return;
+            }
+            stripped
+        } else {
+            Cow::Borrowed(html.value)
+        };
+
         if self.options.sanitize {
-            self.write_escaped(html.value);
+            self.write_escaped(&value);
         } else {
-            self.write(html.value);
+            self.write(&value);
+        }
+        if !self.in_inline {
+            self.write("\n");
         }
-        self.write("\n");
     }
 
     fn visit_table(&mut self, table: &Table<'a>) {
+        if self.options.wrap_tables {
+            self.write("<div class=\"table-wrapper\" style=\"overflow-x:auto\">\n");
+        }
         self.write("<table>\n");
         for (i, row) in table.children.iter().enumerate() {
             if i == 0 {
@@ -1293,6 +1611,52 @@ impl<'a> Visit<'a> for HtmlRenderer {
             self.write("</tbody>\n");
         }
         self.write("</table>\n");
+        if self.options.wrap_tables {
+            self.write("</div>\n");
+        }
+    }
+
+    fn visit_description_list(&mut self, description_list: &DescriptionList<'a>) {
+        self.write("<dl>\n");
+        for child in &description_list.children {
+            self.visit_node(child);
+        }
+        self.write("</dl>\n");
+    }
+
+    fn visit_container(&mut self, container: &Container<'a>) {
+        if container.kind == "details" {
+            self.write("<details class=\"custom-block details\">\n<summary>");
+            self.write_escaped(container.title.unwrap_or("Details"));
+            self.write("</summary>\n");
+        } else {
+            self.write("<div class=\"custom-block ");
+            self.write(container.kind);
+            self.write("\">\n<p class=\"custom-block-title\">");
+            match container.title {
+                Some(title) => self.write_escaped(title),
+                None => self.write(&container.kind.to_ascii_uppercase()),
+            }
+            self.write("</p>\n");
+        }
+        for child in &container.children {
+            self.visit_node(child);
+        }
+        self.write(if container.kind == "details" { "</details>\n" } else { "</div>\n" });
+    }
+
+    fn visit_description_term(&mut self, description_term: &DescriptionTerm<'a>) {
+        self.write("<dt>");
+        self.visit_inline_children(&description_term.children);
+        self.write("</dt>\n");
+    }
+
+    fn visit_description_details(&mut self, description_details: &DescriptionDetails<'a>) {
+        self.write("<dd>");
+        for child in &description_details.children {
+            self.visit_node(child);
+        }
+        self.write("</dd>\n");
     }
 
     fn visit_text(&mut self, text: &Text<'a>) {
@@ -1316,13 +1680,20 @@ impl<'a> Visit<'a> for HtmlRenderer {
     }
 
     fn visit_inline_code(&mut self, inline_code: &InlineCode<'a>) {
-        self.write("<code>");
+        self.write_code_open(None);
         self.write_escaped(inline_code.value);
         self.write("</code>");
     }
 
-    fn visit_break(&mut self, _break_node: &Break) {
-        self.output.push_str(self.options.hard_break.as_str());
+    fn visit_break(&mut self, break_node: &Break) {
+        let markup =
+            if break_node.hard { &self.options.hard_break } else { &self.options.soft_break };
+        if self.options.xhtml && markup.contains("<br>") {
+            let markup = markup.replace("<br>", "<br />");
+            self.write(&markup);
+        } else {
+            self.output.push_str(markup);
+        }
     }
 
     fn visit_link(&mut self, link: &Link<'a>) {
@@ -1334,15 +1705,20 @@ impl<'a> Visit<'a> for HtmlRenderer {
             self.write_url_escaped(link.url);
         }
         self.write("\"");
-        // Add target="_blank" for external links (http:// or https://)
-        if link.url.starts_with("http://") || link.url.starts_with("https://") {
-            self.write(" target=\"_blank\" rel=\"noopener noreferrer\"");
-        }
+        // Attributes are written in a fixed order: href, title, target, rel.
         if let Some(title) = link.title {
             self.write(" title=\"");
             self.write_escaped(title);
             self.write("\"");
         }
+        if link.url.starts_with("http://") || link.url.starts_with("https://") {
+            if self.options.external_target_blank {
+                self.write(" target=\"_blank\"");
+            }
+            if self.options.external_rel {
+                self.write(" rel=\"noopener noreferrer\"");
+            }
+        }
         self.write(">");
         for child in &link.children {
             self.visit_node(child);
@@ -1351,6 +1727,7 @@ impl<'a> Visit<'a> for HtmlRenderer {
     }
 
     fn visit_image(&mut self, image: &Image<'a>) {
+        // Attributes are written in a fixed order: src, alt, title, loading, decoding.
         self.write("<img src=\"");
         self.write_url_escaped(image.url);
         self.write("\" alt=\"");
@@ -1361,11 +1738,13 @@ impl<'a> Visit<'a> for HtmlRenderer {
             self.write_escaped(title);
             self.write("\"");
         }
-        if self.options.xhtml {
-            self.write(" />");
-        } else {
-            self.write(">");
+        if self.options.image_lazy_loading {
+            self.write(" loading=\"lazy\"");
+        }
+        if self.options.image_async_decoding {
+            self.write(" decoding=\"async\"");
         }
+        self.write_void_end();
     }
 
     fn visit_delete(&mut self, delete: &Delete<'a>) {
@@ -1376,10 +1755,36 @@ impl<'a> Visit<'a> for HtmlRenderer {
         self.write("</del>");
     }
 
+    fn visit_mark(&mut self, mark: &Mark<'a>) {
+        self.write("<mark>");
+        for child in &mark.children {
+            self.visit_node(child);
+        }
+        self.write("</mark>");
+    }
+
+    fn visit_subscript(&mut self, subscript: &Subscript<'a>) {
+        self.write("<sub>");
+        for child in &subscript.children {
+            self.visit_node(child);
+        }
+        self.write("</sub>");
+    }
+
+    fn visit_superscript(&mut self, superscript: &Superscript<'a>) {
+        self.write("<sup>");
+        for child in &superscript.children {
+            self.visit_node(child);
+        }
+        self.write("</sup>");
+    }
+
     fn visit_footnote_reference(&mut self, footnote_ref: &FootnoteReference<'a>) {
-        self.write("<sup><a href=\"#fn-");
+        self.write("<sup><a href=\"#");
+        self.write_id("fn-");
         self.write_escaped(footnote_ref.identifier);
-        self.write("\" id=\"fnref-");
+        self.write("\" id=\"");
+        self.write_id("fnref-");
         self.write_escaped(footnote_ref.identifier);
         self.write("\">");
         self.write_escaped(footnote_ref.identifier);
@@ -1391,13 +1796,15 @@ impl<'a> Visit<'a> for HtmlRenderer {
     }
 
     fn visit_footnote_definition(&mut self, footnote_def: &FootnoteDefinition<'a>) {
-        self.write("<div id=\"fn-");
+        self.write("<div id=\"");
+        self.write_id("fn-");
         self.write_escaped(footnote_def.identifier);
         self.write("\" class=\"footnote\">\n");
         for child in &footnote_def.children {
             self.visit_node(child);
         }
-        self.write("<a href=\"#fnref-");
+        self.write("<a href=\"#");
+        self.write_id("fnref-");
         self.write_escaped(footnote_def.identifier);
         self.write("\">↩</a>\n</div>\n");
     }
@@ -1410,9 +1817,15 @@ impl HtmlRenderer {
         is_header: bool,
         align: &ox_content_allocator::Vec<'_, ox_content_ast::AlignKind>,
     ) {
+        if self.hooks.iter().any(|hook| hook.render_table_row(row, &mut self.output)) {
+            return;
+        }
         self.write("<tr>\n");
         let tag = if is_header { "th" } else { "td" };
         for (idx, cell) in row.children.iter().enumerate() {
+            if self.hooks.iter().any(|hook| hook.render_table_cell(cell, &mut self.output)) {
+                continue;
+            }
             self.write("<");
             self.write(tag);
             match align.get(idx).copied().unwrap_or(ox_content_ast::AlignKind::None) {
@@ -1431,9 +1844,7 @@ impl HtmlRenderer {
     }
 
     fn visit_table_cell(&mut self, cell: &TableCell<'_>) {
-        for child in &cell.children {
-            self.visit_node(child);
-        }
+        self.visit_inline_children(&cell.children);
     }
 }
 
@@ -1461,6 +1872,131 @@ mod tests {
         assert_eq!(html, "<h1>Hello</h1>\n");
     }
 
+    #[test]
+    fn test_render_heading_anchor() {
+        let allocator = Allocator::new();
+        let doc = Parser::new(&allocator, "## Getting *Started* `now`").parse().unwrap();
+        let mut renderer = HtmlRenderer::with_options(HtmlRendererOptions {
+            heading_anchors: true,
+            ..Default::default()
+        });
+        let html = renderer.render(&doc);
+        assert_eq!(
+            html,
+            "<h2 id=\"getting-started-now\">Getting <em>Started</em> <code>now</code> \
+             <a class=\"header-anchor\" href=\"#getting-started-now\" aria-label=\"Permalink\">#</a></h2>\n"
+        );
+    }
+
+    #[test]
+    fn test_render_heading_anchor_includes_inline_code() {
+        let allocator = Allocator::new();
+        let doc = Parser::new(&allocator, "## Using `foo()`").parse().unwrap();
+        let mut renderer = HtmlRenderer::with_options(HtmlRendererOptions {
+            heading_anchors: true,
+            ..Default::default()
+        });
+        let html = renderer.render(&doc);
+        assert_eq!(
+            html,
+            "<h2 id=\"using-foo\">Using <code>foo()</code> \
+             <a class=\"header-anchor\" href=\"#using-foo\" aria-label=\"Permalink\">#</a></h2>\n"
+        );
+    }
+
+    #[test]
+    fn test_render_heading_anchor_decodes_entities() {
+        let allocator = Allocator::new();
+        let doc = Parser::new(&allocator, "## A &amp; B").parse().unwrap();
+        let mut renderer = HtmlRenderer::with_options(HtmlRendererOptions {
+            heading_anchors: true,
+            ..Default::default()
+        });
+        let html = renderer.render(&doc);
+        assert!(html.starts_with("<h2 id=\"a-b\">A &amp; B "), "{html}");
+    }
+
+    #[test]
+    fn test_render_heading_anchor_duplicate_ids() {
+        let allocator = Allocator::new();
+        let doc = Parser::new(&allocator, "## Usage\n\n## Usage\n\n## Usage").parse().unwrap();
+        let mut renderer = HtmlRenderer::with_options(HtmlRendererOptions {
+            heading_anchors: true,
+            ..Default::default()
+        });
+        let html = renderer.render(&doc);
+        assert_eq!(collect_ids(&html), ["usage", "usage-1", "usage-2"]);
+        // Each render starts a fresh set of ids.
+        assert_eq!(collect_ids(&renderer.render(&doc)), ["usage", "usage-1", "usage-2"]);
+    }
+
+    #[test]
+    fn test_render_heading_anchor_slug_strategy() {
+        let allocator = Allocator::new();
+        let doc = Parser::new(&allocator, "## はじめに & Setup").parse().unwrap();
+        let render = |slug_strategy| {
+            HtmlRenderer::with_options(HtmlRendererOptions {
+                heading_anchors: true,
+                slug_strategy,
+                ..Default::default()
+            })
+            .render(&doc)
+        };
+        assert!(render(SlugStrategy::PreserveUnicode).starts_with("<h2 id=\"はじめに-setup\">"));
+        assert!(render(SlugStrategy::GitHub).starts_with("<h2 id=\"はじめに--setup\">"));
+        assert!(render(SlugStrategy::Ascii).starts_with("<h2 id=\"setup\">"));
+    }
+
+    fn render_with_prefix(prefix: &str) -> String {
+        let allocator = Allocator::new();
+        let mut doc = Parser::new(&allocator, "## Intro\n\nSee note").parse().unwrap();
+
+        let mut ref_children = allocator.new_vec();
+        ref_children.push(Node::FootnoteReference(FootnoteReference {
+            identifier: "1",
+            label: None,
+            span: ox_content_ast::Span::empty(),
+        }));
+        doc.children.push(Node::Paragraph(Paragraph {
+            children: ref_children,
+            span: ox_content_ast::Span::empty(),
+        }));
+        doc.children.push(Node::FootnoteDefinition(FootnoteDefinition {
+            identifier: "1",
+            label: None,
+            children: allocator.new_vec(),
+            span: ox_content_ast::Span::empty(),
+        }));
+
+        let mut renderer = HtmlRenderer::with_options(HtmlRendererOptions {
+            heading_anchors: true,
+            id_prefix: Some(prefix.to_string()),
+            ..Default::default()
+        });
+        renderer.render(&doc)
+    }
+
+    fn collect_ids(html: &str) -> Vec<&str> {
+        html.split("id=\"").skip(1).filter_map(|rest| rest.split('"').next()).collect()
+    }
+
+    #[test]
+    fn test_render_id_prefix() {
+        let first = render_with_prefix("a-");
+        let second = render_with_prefix("b-");
+
+        assert!(first.contains("<h2 id=\"a-intro\">"));
+        assert!(first.contains("href=\"#a-intro\""));
+        assert!(first.contains("<sup><a href=\"#a-fn-1\" id=\"a-fnref-1\">"));
+        assert!(first.contains("<div id=\"a-fn-1\" class=\"footnote\">"));
+        assert!(first.contains("<a href=\"#a-fnref-1\">"));
+
+        let first_ids = collect_ids(&first);
+        let second_ids = collect_ids(&second);
+        assert_eq!(first_ids.len(), 3);
+        assert!(first_ids.iter().all(|id| !second_ids.contains(id)));
+    }
+
     #[test]
     fn test_render_block_quote() {
         let allocator = Allocator::new();
@@ -1507,6 +2043,88 @@ mod tests {
         assert!(!html.contains("[!NOTE]"));
     }
 
+    #[test]
+    fn test_render_aria_attributes() {
+        let allocator = Allocator::new();
+        let doc = Parser::new(&allocator, "> [!NOTE]\n> Read this.\n\n```rust\nfn main() {}\n```")
+            .parse()
+            .unwrap();
+
+        let html = HtmlRenderer::new().render(&doc);
+        assert!(!html.contains("role="));
+        assert!(!html.contains("aria-label="));
+
+        let options = HtmlRendererOptions { aria_attributes: true, ..Default::default() };
+        let html = HtmlRenderer::with_options(options).render(&doc);
+        assert!(html.contains(
+            "<blockquote class=\"ox-callout ox-callout--note\" role=\"note\" aria-label=\"Note\">"
+        ));
+        assert!(html
+            .contains("<pre role=\"region\" aria-label=\"code\"><code class=\"language-rust\">"));
+    }
+
+    #[test]
+    fn test_render_heading_offset() {
+        let allocator = Allocator::new();
+        let doc = Parser::new(&allocator, "# Title\n\n##### Deep").parse().unwrap();
+
+        let options = HtmlRendererOptions { heading_offset: 1, ..Default::default() };
+        let html = HtmlRenderer::with_options(options).render(&doc);
+        assert!(html.contains("<h2>Title</h2>"));
+        assert!(html.contains("<h6>Deep</h6>"));
+
+        let options = HtmlRendererOptions { heading_offset: 3, ..Default::default() };
+        let html = HtmlRenderer::with_options(options).render(&doc);
+        assert!(html.contains("<h4>Title</h4>"));
+        assert!(html.contains("<h6>Deep</h6>"));
+
+        let options = HtmlRendererOptions { heading_offset: -2, ..Default::default() };
+        let html = HtmlRenderer::with_options(options).render(&doc);
+        assert!(html.contains("<h1>Title</h1>"));
+        assert!(html.contains("<h3>Deep</h3>"));
+    }
+
+    #[test]
+    fn test_render_html_comments() {
+        let allocator = Allocator::new();
+        let doc = Parser::new(&allocator, "<!--\nnote\n-->\n\nText <!-- hidden --> here.")
+            .parse()
+            .unwrap();
+
+        let html = HtmlRenderer::new().render(&doc);
+        assert!(html.starts_with("<!--\nnote\n-->\n"));
+        assert!(html.contains("<p>Text <!-- hidden --> here.</p>"));
+        assert!(!html.contains("&lt;!--"));
+
+        let options = HtmlRendererOptions { strip_comments: true, ..Default::default() };
+        let html = HtmlRenderer::with_options(options).render(&doc);
+        assert_eq!(html, "<p>Text  here.</p>\n");
+    }
+
+    #[test]
+    fn test_render_figures() {
+        let allocator = Allocator::new();
+        let doc = Parser::new(
+            &allocator,
+            "![Chart](/chart.png \"Sales & costs\")\n\nSee ![icon](/i.png \"Icon\") inline.",
+        )
+        .parse()
+        .unwrap();
+
+        let options = HtmlRendererOptions { figures: true, ..Default::default() };
+        let html = HtmlRenderer::with_options(options).render(&doc);
+        assert!(html.starts_with(
+            "<figure><img src=\"/chart.png\" alt=\"Chart\" title=\"Sales &amp; costs\">\
+             <figcaption>Sales &amp; costs</figcaption></figure>\n"
+        ));
+        assert!(
+            html.contains("<p>See <img src=\"/i.png\" alt=\"icon\" title=\"Icon\"> inline.</p>")
+        );
+
+        let html = HtmlRenderer::new().render(&doc);
+        assert!(!html.contains("<figure>"));
+    }
+
     #[test]
     fn test_render_code_block() {
         let allocator = Allocator::new();
@@ -1516,6 +2134,41 @@ mod tests {
         assert!(html.contains("<pre><code class=\"language-rust\">"));
     }
 
+    #[test]
+    fn test_render_code_block_mermaid_lang() {
+        let allocator = Allocator::new();
+        let doc = Parser::new(&allocator, "```mermaid\ngraph TD\n```").parse().unwrap();
+        let mut renderer = HtmlRenderer::new();
+        let html = renderer.render(&doc);
+        assert!(html.contains("<pre><code class=\"language-mermaid\">graph TD</code></pre>"));
+
+        let options = HtmlRendererOptions { code_annotations: true, ..Default::default() };
+        let mut renderer = HtmlRenderer::with_options(options);
+        let html = renderer.render(&doc);
+        assert!(html.contains("<code class=\"language-mermaid\">"));
+    }
+
+    #[test]
+    fn test_render_code_block_trailing_newline() {
+        let allocator = Allocator::new();
+        let with_newline = Parser::new(&allocator, "```\nfoo\n```").parse().unwrap();
+        let mut without_newline = Parser::new(&allocator, "```\nfoo\n```").parse().unwrap();
+        let Node::CodeBlock(code_block) = &mut without_newline.children[0] else {
+            panic!("expected code block");
+        };
+        code_block.value = "foo";
+
+        let html = HtmlRenderer::new().render(&with_newline);
+        assert_eq!(html, "<pre><code>foo</code></pre>\n");
+        assert_eq!(HtmlRenderer::new().render(&without_newline), html);
+
+        let mut renderer = HtmlRenderer::with_options(HtmlRendererOptions {
+            trim_code_trailing_newline: false,
+            ..Default::default()
+        });
+        assert_eq!(renderer.render(&with_newline), "<pre><code>foo\n</code></pre>\n");
+    }
+
     #[test]
     fn test_render_code_block_with_annotations() {
         let allocator = Allocator::new();
@@ -1667,6 +2320,53 @@ mod tests {
         assert!(html.contains("<td>body</td>"));
     }
 
+    #[test]
+    fn test_render_table_wrapper() {
+        let allocator = Allocator::new();
+        let parser_options = ox_content_parser::ParserOptions::gfm();
+        let doc = Parser::with_options(&allocator, "| head |\n| --- |\n| body |", parser_options)
+            .parse()
+            .unwrap();
+
+        let mut renderer = HtmlRenderer::new();
+        let html = renderer.render(&doc);
+        assert!(html.starts_with("<table>"));
+        assert!(!html.contains("table-wrapper"));
+
+        let options = HtmlRendererOptions { wrap_tables: true, ..Default::default() };
+        let mut renderer = HtmlRenderer::with_options(options);
+        let html = renderer.render(&doc);
+        assert!(
+            html.starts_with("<div class=\"table-wrapper\" style=\"overflow-x:auto\">\n<table>")
+        );
+        assert!(html.ends_with("</table>\n</div>\n"));
+    }
+
+    #[test]
+    fn test_render_code_block_copy_button() {
+        let allocator = Allocator::new();
+        let doc = Parser::new(&allocator, "```rust\nfn main() {}\n```").parse().unwrap();
+
+        let mut renderer = HtmlRenderer::new();
+        let html = renderer.render(&doc);
+        assert_eq!(html, "<pre><code class=\"language-rust\">fn main() {}</code></pre>\n");
+
+        let options = HtmlRendererOptions { copy_button: true, ..Default::default() };
+        let mut renderer = HtmlRenderer::with_options(options);
+        let html = renderer.render(&doc);
+        assert_eq!(
+            html,
+            "<div class=\"code-block-wrapper\">\n\
+             <pre><code class=\"language-rust\">fn main() {}</code></pre>\n\
+             <button type=\"button\" class=\"copy-code\" aria-label=\"Copy code\">Copy</button>\n\
+             </div>\n"
+        );
+
+        let doc = Parser::new(&allocator, "```mermaid\ngraph TD\n```").parse().unwrap();
+        let html = renderer.render(&doc);
+        assert_eq!(html, "<pre><code class=\"language-mermaid\">graph TD</code></pre>\n");
+    }
+
     #[test]
     fn test_render_table_no_gfm() {
         let allocator = Allocator::new();
@@ -1721,6 +2421,156 @@ mod tests {
         assert_eq!(html, "<p><del>done</del></p>\n");
     }
 
+    #[test]
+    fn test_render_mark() {
+        let allocator = Allocator::new();
+        let options = ox_content_parser::ParserOptions { mark: true, ..Default::default() };
+        let doc = Parser::with_options(&allocator, "a ==b== = c", options).parse().unwrap();
+        let html = HtmlRenderer::new().render(&doc);
+        assert_eq!(html, "<p>a <mark>b</mark> = c</p>\n");
+    }
+
+    #[test]
+    fn test_render_sub_sup() {
+        let allocator = Allocator::new();
+        let options = ox_content_parser::ParserOptions {
+            sub_sup: true,
+            ..ox_content_parser::ParserOptions::gfm()
+        };
+        let doc =
+            Parser::with_options(&allocator, "H~2~O, x^2^, ~~old~~", options).parse().unwrap();
+        let html = HtmlRenderer::new().render(&doc);
+        assert_eq!(html, "<p>H<sub>2</sub>O, x<sup>2</sup>, <del>old</del></p>\n");
+    }
+
+    #[test]
+    fn test_render_hook_overrides_code_block() {
+        let allocator = Allocator::new();
+        let doc = Parser::new(&allocator, "Intro\n\n```rust\nfn main() {}\n```").parse().unwrap();
+
+        let mut renderer = HtmlRenderer::new();
+        renderer.add_hook(|node: &Node<'_>, output: &mut String| {
+            let Node::CodeBlock(code) = node else {
+                return false;
+            };
+            output.push_str("<div class=\"custom-code\" data-lang=\"");
+            output.push_str(code.lang.unwrap_or(""));
+            output.push_str("\">");
+            output.push_str(code.value.trim_end());
+            output.push_str("</div>\n");
+            true
+        });
+        let html = renderer.render(&doc);
+
+        assert_eq!(
+            html,
+            "<p>Intro</p>\n<div class=\"custom-code\" data-lang=\"rust\">fn main() {}</div>\n"
+        );
+    }
+
+    #[test]
+    fn test_render_hooks_see_list_items_table_cells_and_figures() {
+        struct Marker;
+
+        impl RenderHook for Marker {
+            fn render(&self, node: &Node<'_>, output: &mut String) -> bool {
+                let Node::Image(image) = node else {
+                    return false;
+                };
+                output.push_str("[image:");
+                output.push_str(image.url);
+                output.push(']');
+                true
+            }
+
+            fn render_list_item(&self, _list_item: &ListItem<'_>, output: &mut String) -> bool {
+                output.push_str("<li>item</li>\n");
+                true
+            }
+
+            fn render_table_cell(&self, _cell: &TableCell<'_>, output: &mut String) -> bool {
+                output.push_str("<td>cell</td>\n");
+                true
+            }
+        }
+
+        let allocator = Allocator::new();
+        let source = "- a\n\n| A |\n| - |\n| b |\n\n![Alt](cat.png \"Cat\")";
+        let doc = Parser::with_options(&allocator, source, ox_content_parser::ParserOptions::gfm())
+            .parse()
+            .unwrap();
+        let options = HtmlRendererOptions { figures: true, ..Default::default() };
+        let mut renderer = HtmlRenderer::with_options(options);
+        renderer.add_hook(Marker);
+        let html = renderer.render(&doc);
+
+        assert!(html.contains("<ul>\n<li>item</li>\n</ul>"));
+        assert!(html.contains("<tr>\n<td>cell</td>\n</tr>"));
+        assert!(html.contains("<figure>[image:cat.png]<figcaption>Cat</figcaption></figure>"));
+    }
+
+    #[test]
+    fn test_render_containers() {
+        let allocator = Allocator::new();
+        let options = ox_content_parser::ParserOptions { containers: true, ..Default::default() };
+        let source = "::: tip\nUse it.\n:::\n\n::: warning Mind <this>\nCareful.\n:::\n\n::: details Click me\nHidden.\n:::";
+        let doc = Parser::with_options(&allocator, source, options).parse().unwrap();
+        let html = HtmlRenderer::new().render(&doc);
+        assert_eq!(
+            html,
+            "<div class=\"custom-block tip\">\n<p class=\"custom-block-title\">TIP</p>\n<p>Use it.</p>\n</div>\n\
+             <div class=\"custom-block warning\">\n<p class=\"custom-block-title\">Mind &lt;this&gt;</p>\n<p>Careful.</p>\n</div>\n\
+             <details class=\"custom-block details\">\n<summary>Click me</summary>\n<p>Hidden.</p>\n</details>\n"
+        );
+    }
+
+    #[test]
+    fn test_render_code_block_title_attribute() {
+        let allocator = Allocator::new();
+        let doc = Parser::new(&allocator, "```ts title=\"foo.ts\" {1}\nconst a = 1;\n```")
+            .parse()
+            .unwrap();
+        let mut renderer = HtmlRenderer::with_options(HtmlRendererOptions {
+            code_annotations: true,
+            code_annotation_syntax: CodeAnnotationSyntax::VitePress,
+            ..Default::default()
+        });
+        let html = renderer.render(&doc);
+
+        assert!(html.contains("data-code-title=\"foo.ts\""));
+        assert!(html.contains("ox-code-line--highlight"));
+        assert!(html.contains("class=\"language-ts\""));
+    }
+
+    #[test]
+    fn test_render_definition_list() {
+        let allocator = Allocator::new();
+        let parser_options =
+            ox_content_parser::ParserOptions { definition_lists: true, ..Default::default() };
+        let doc = Parser::with_options(&allocator, "Apple\n: A *red* fruit", parser_options)
+            .parse()
+            .unwrap();
+        let mut renderer = HtmlRenderer::new();
+        let html = renderer.render(&doc);
+        assert_eq!(html, "<dl>\n<dt>Apple</dt>\n<dd>A <em>red</em> fruit</dd>\n</dl>\n");
+    }
+
+    #[test]
+    fn test_render_definition_list_multiple_definitions() {
+        let allocator = Allocator::new();
+        let parser_options =
+            ox_content_parser::ParserOptions { definition_lists: true, ..Default::default() };
+        let source = "Apple\n: A fruit\n: A company\n\nOrange\n: A color";
+        let doc = Parser::with_options(&allocator, source, parser_options).parse().unwrap();
+        let mut renderer = HtmlRenderer::new();
+        let html = renderer.render(&doc);
+        assert_eq!(
+            html,
+            "<dl>\n<dt>Apple</dt>\n<dd>A fruit</dd>\n<dd>A company</dd>\n\
+             <dt>Orange</dt>\n<dd>A color</dd>\n</dl>\n"
+        );
+    }
+
     #[test]
     fn test_render_hard_break() {
         let allocator = Allocator::new();
@@ -1749,6 +2599,140 @@ mod tests {
         assert!(html.contains("<img src=\"/logo.svg\" alt=\"Logo\" />"));
     }
 
+    #[test]
+    fn test_render_void_elements_and_attribute_order() {
+        let allocator = Allocator::new();
+        let source = "[Docs](https://example.com \"Home\") ![Logo](/logo.svg \"Brand\")  \nnext\n\n---\n\n- [x] done\n";
+        let doc = Parser::with_options(&allocator, source, ox_content_parser::ParserOptions::gfm())
+            .parse()
+            .unwrap();
+
+        let html = HtmlRenderer::new().render(&doc);
+        assert_eq!(
+            html,
+            "<p><a href=\"https://example.com\" title=\"Home\" target=\"_blank\" \
+             rel=\"noopener noreferrer\">Docs</a> <img src=\"/logo.svg\" alt=\"Logo\" \
+             title=\"Brand\"><br>\nnext</p>\n<hr>\n<ul>\n<li><input type=\"checkbox\" \
+             checked disabled> <p>done</p>\n</li>\n</ul>\n"
+        );
+
+        let options = HtmlRendererOptions { xhtml: true, ..Default::default() };
+        let html = HtmlRenderer::with_options(options).render(&doc);
+        assert_eq!(
+            html,
+            "<p><a href=\"https://example.com\" title=\"Home\" target=\"_blank\" \
+             rel=\"noopener noreferrer\">Docs</a> <img src=\"/logo.svg\" alt=\"Logo\" \
+             title=\"Brand\" /><br />\nnext</p>\n<hr />\n<ul>\n<li><input type=\"checkbox\" \
+             checked disabled /> <p>done</p>\n</li>\n</ul>\n"
+        );
+    }
+
+    #[test]
+    fn test_render_code_translate_no() {
+        let allocator = Allocator::new();
+        let doc = Parser::new(
+            &allocator,
+            "Run `cargo build`.\n\n```rust\nfn main() {}\n```\n\n```\nplain\n```",
+        )
+        .parse()
+        .unwrap();
+
+        let html = HtmlRenderer::new().render(&doc);
+        assert!(!html.contains("translate"));
+
+        let mut options = HtmlRendererOptions { code_translate_no: true, ..Default::default() };
+        let html = HtmlRenderer::with_options(options.clone()).render(&doc);
+        assert!(html.contains("<code translate=\"no\">cargo build</code>"));
+        assert!(html.contains("<pre><code class=\"language-rust\" translate=\"no\">"));
+        assert!(html.contains("<pre><code translate=\"no\">plain"));
+
+        options.code_notranslate_class = true;
+        let html = HtmlRenderer::with_options(options).render(&doc);
+        assert!(html.contains("<code class=\"notranslate\" translate=\"no\">cargo build</code>"));
+        assert!(html.contains("<code class=\"language-rust notranslate\" translate=\"no\">"));
+        assert!(html.contains("<pre><code class=\"notranslate\" translate=\"no\">plain"));
+    }
+
+    #[test]
+    fn test_render_container() {
+        let allocator = Allocator::new();
+        let doc = Parser::new(&allocator, "# Title\n\nBody").parse().unwrap();
+
+        let html = HtmlRenderer::new().render(&doc);
+        assert_eq!(html, "<h1>Title</h1>\n<p>Body</p>\n");
+
+        let mut renderer = HtmlRenderer::with_options(HtmlRendererOptions {
+            container: Some(("div".to_string(), "prose".to_string())),
+            ..Default::default()
+        });
+        let html = renderer.render(&doc);
+        assert_eq!(html, "<div class=\"prose\">\n<h1>Title</h1>\n<p>Body</p>\n</div>\n");
+        assert_eq!(renderer.render(&doc), html);
+
+        let html = HtmlRenderer::with_options(HtmlRendererOptions {
+            container: Some(("article".to_string(), String::new())),
+            ..Default::default()
+        })
+        .render(&doc);
+        assert_eq!(html, "<article>\n<h1>Title</h1>\n<p>Body</p>\n</article>\n");
+
+        for tag in ["", "1div", "div class=\"x\"", "div><script>", "my_tag"] {
+            assert!(!is_valid_tag_name(tag), "{tag}");
+            let html = HtmlRenderer::with_options(HtmlRendererOptions {
+                container: Some((tag.to_string(), "prose".to_string())),
+                ..Default::default()
+            })
+            .render(&doc);
+            assert_eq!(html, "<h1>Title</h1>\n<p>Body</p>\n");
+        }
+        assert!(is_valid_tag_name("my-element2"));
+    }
+
+    #[test]
+    fn test_render_url_encode_policies() {
+        let allocator = Allocator::new();
+        let doc = Parser::new(&allocator, "[a](/a%20b/100%/caf\u{e9}?x=1&y=<2>)").parse().unwrap();
+        let href = |url_encode| {
+            let html = HtmlRenderer::with_options(HtmlRendererOptions {
+                url_encode,
+                ..Default::default()
+            })
+            .render(&doc);
+            html.split('"').nth(1).unwrap().to_string()
+        };
+
+        assert_eq!(href(UrlEncode::Minimal), "/a%20b/100%/caf\u{e9}?x=1&amp;y=%3C2%3E");
+        assert_eq!(href(UrlEncode::Strict), "/a%20b/100%25/caf%C3%A9?x=1&amp;y=%3C2%3E");
+        assert_eq!(href(UrlEncode::None), "/a%20b/100%/caf\u{e9}?x=1&amp;y=&lt;2&gt;");
+    }
+
+    #[test]
+    fn test_render_image_lazy_async() {
+        let allocator = Allocator::new();
+        let doc = Parser::new(&allocator, "![Logo](/logo.svg)").parse().unwrap();
+
+        let html = HtmlRenderer::new().render(&doc);
+        assert!(!html.contains("loading="));
+        assert!(!html.contains("decoding="));
+
+        let mut renderer = HtmlRenderer::with_options(HtmlRendererOptions {
+            image_lazy_loading: true,
+            image_async_decoding: true,
+            ..Default::default()
+        });
+        let html = renderer.render(&doc);
+        assert!(html
+            .contains("<img src=\"/logo.svg\" alt=\"Logo\" loading=\"lazy\" decoding=\"async\">"));
+
+        let mut renderer = HtmlRenderer::with_options(HtmlRendererOptions {
+            image_lazy_loading: true,
+            ..Default::default()
+        });
+        let html = renderer.render(&doc);
+        assert!(html.contains("loading=\"lazy\""));
+        assert!(!html.contains("decoding="));
+    }
+
     #[test]
     fn test_convert_md_link_from_index_file() {
         // When the source is an index file (api/index.md), relative links like ./docs.md
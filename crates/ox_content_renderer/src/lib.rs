@@ -21,6 +21,10 @@
 
 mod html;
 mod render;
+mod slug;
 
-pub use html::{CodeAnnotationSyntax, HtmlRenderer, HtmlRendererOptions};
-pub use render::{RenderError, RenderResult, Renderer};
+pub use html::{
+    is_valid_tag_name, CodeAnnotationSyntax, HtmlRenderer, HtmlRendererOptions, UrlEncode,
+};
+pub use render::{RenderError, RenderHook, RenderResult, Renderer};
+pub use slug::{slugify, SlugStrategy, Slugger};
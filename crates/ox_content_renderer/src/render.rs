@@ -1,6 +1,6 @@
 //! Renderer trait and utilities.
 
-use ox_content_ast::Document;
+use ox_content_ast::{Document, ListItem, Node, TableCell, TableRow};
 use thiserror::Error;
 
 /// Result type for rendering operations.
@@ -26,3 +26,39 @@ pub trait Renderer {
     /// Renders a document to the output format.
     fn render(&mut self, document: &Document<'_>) -> RenderResult<Self::Output>;
 }
+
+/// Hook consulted before a renderer's default handling of each node.
+///
+/// Lets downstream crates customize how particular nodes render without
+/// forking the renderer. Any `Fn(&Node, &mut String) -> bool` closure is a hook.
+pub trait RenderHook {
+    /// Optionally renders `node` by appending to `output`.
+    ///
+    /// Returns `true` if the node was handled, which skips the default output
+    /// (and any later hooks) for it.
+    fn render(&self, node: &Node<'_>, output: &mut String) -> bool;
+
+    /// Optionally renders a list item, which its list holds directly rather than as a [`Node`].
+    fn render_list_item(&self, _list_item: &ListItem<'_>, _output: &mut String) -> bool {
+        false
+    }
+
+    /// Optionally renders a table row, including its `<tr>` tags.
+    fn render_table_row(&self, _row: &TableRow<'_>, _output: &mut String) -> bool {
+        false
+    }
+
+    /// Optionally renders a table cell, including its `<th>`/`<td>` tags.
+    fn render_table_cell(&self, _cell: &TableCell<'_>, _output: &mut String) -> bool {
+        false
+    }
+}
+
+impl<F> RenderHook for F
+where
+    F: Fn(&Node<'_>, &mut String) -> bool,
+{
+    fn render(&self, node: &Node<'_>, output: &mut String) -> bool {
+        self(node, output)
+    }
+}
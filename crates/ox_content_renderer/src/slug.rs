@@ -0,0 +1,141 @@
+//! Heading slug generation shared by heading anchors and TOC extraction.
+
+use std::collections::HashMap;
+
+/// How heading text is turned into an anchor slug.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum SlugStrategy {
+    /// ASCII letters and digits only. Common Latin accents are folded
+    /// (`café` becomes `cafe`); text with no ASCII left, such as CJK, gets a
+    /// stable hash-based slug (`section-1a2b3c4d`).
+    Ascii,
+    /// Unicode letters and digits are kept; other characters separate words.
+    #[default]
+    PreserveUnicode,
+    /// GitHub's rules: punctuation is removed and each space becomes `-`
+    /// (so `A & B` is `a--b`); Unicode letters, digits and `_` are kept.
+    GitHub,
+}
+
+/// Converts heading text to a URL-friendly slug.
+#[must_use]
+pub fn slugify(text: &str, strategy: SlugStrategy) -> String {
+    match strategy {
+        SlugStrategy::Ascii => {
+            let folded: String = text.to_lowercase().chars().flat_map(fold_latin).collect();
+            let slug = join_words(&folded, |c| c.is_ascii_alphanumeric());
+            if slug.is_empty() && !text.trim().is_empty() {
+                format!("section-{:08x}", fnv1a(text.trim()))
+            } else {
+                slug
+            }
+        }
+        SlugStrategy::PreserveUnicode => join_words(&text.to_lowercase(), char::is_alphanumeric),
+        SlugStrategy::GitHub => text
+            .trim()
+            .to_lowercase()
+            .chars()
+            .filter(|&c| c.is_alphanumeric() || matches!(c, ' ' | '-' | '_'))
+            .map(|c| if c == ' ' { '-' } else { c })
+            .collect(),
+    }
+}
+
+/// Hands out unique slugs for one document.
+///
+/// Repeated slugs get `-1`, `-2`, ... suffixes in document order, as on
+/// GitHub, so anchors and TOC links built with separate `Slugger`s agree.
+#[derive(Debug, Default)]
+pub struct Slugger {
+    strategy: SlugStrategy,
+    seen: HashMap<String, usize>,
+}
+
+impl Slugger {
+    /// Creates a slugger using `strategy`.
+    #[must_use]
+    pub fn new(strategy: SlugStrategy) -> Self {
+        Self { strategy, seen: HashMap::new() }
+    }
+
+    /// Returns the slug for `text`, suffixed if it was already handed out.
+    pub fn slug(&mut self, text: &str) -> String {
+        let base = slugify(text, self.strategy);
+        let mut count = self.seen.get(&base).copied().unwrap_or(0);
+        let mut slug = base.clone();
+        while self.seen.contains_key(&slug) {
+            slug = format!("{base}-{count}");
+            count += 1;
+        }
+        self.seen.insert(base, count.max(1));
+        self.seen.entry(slug.clone()).or_insert(1);
+        slug
+    }
+}
+
+/// Joins runs of characters accepted by `keep` (and `-`) with single hyphens.
+fn join_words(text: &str, keep: impl Fn(char) -> bool) -> String {
+    text.chars()
+        .map(|c| if keep(c) || c == '-' { c } else { ' ' })
+        .collect::<String>()
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join("-")
+}
+
+/// Folds a lowercase Latin-1 letter with a diacritic to its ASCII base letters.
+fn fold_latin(c: char) -> impl Iterator<Item = char> {
+    let folded = match c {
+        'à'..='å' => "a",
+        'æ' => "ae",
+        'ç' => "c",
+        'è'..='ë' => "e",
+        'ì'..='ï' => "i",
+        'ñ' => "n",
+        'ò'..='ö' | 'ø' => "o",
+        'ù'..='ü' => "u",
+        'ý' | 'ÿ' => "y",
+        'ß' => "ss",
+        _ => "",
+    };
+    let original = folded.is_empty().then_some(c);
+    folded.chars().chain(original)
+}
+
+/// 32-bit FNV-1a hash, used for stable fallback slugs.
+fn fnv1a(text: &str) -> u32 {
+    text.bytes().fold(0x811c_9dc5, |hash, byte| (hash ^ u32::from(byte)).wrapping_mul(0x0100_0193))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_slugify_strategies() {
+        let text = "Café & Crème: Intro";
+        assert_eq!(slugify(text, SlugStrategy::Ascii), "cafe-creme-intro");
+        assert_eq!(slugify(text, SlugStrategy::PreserveUnicode), "café-crème-intro");
+        assert_eq!(slugify(text, SlugStrategy::GitHub), "café--crème-intro");
+    }
+
+    #[test]
+    fn test_slugify_japanese_heading() {
+        let text = "はじめに 設定";
+        assert_eq!(slugify(text, SlugStrategy::PreserveUnicode), "はじめに-設定");
+        assert_eq!(slugify(text, SlugStrategy::GitHub), "はじめに-設定");
+
+        let ascii = slugify(text, SlugStrategy::Ascii);
+        assert!(ascii.starts_with("section-") && ascii.len() == "section-".len() + 8);
+        assert_eq!(ascii, slugify(text, SlugStrategy::Ascii));
+        assert_ne!(ascii, slugify("別の見出し", SlugStrategy::Ascii));
+    }
+
+    #[test]
+    fn test_slugger_suffixes_duplicates() {
+        let mut slugger = Slugger::new(SlugStrategy::default());
+        let slugs: Vec<_> =
+            ["Usage", "Usage", "Usage-1", "Usage"].iter().map(|t| slugger.slug(t)).collect();
+        assert_eq!(slugs, ["usage", "usage-1", "usage-1-1", "usage-2"]);
+    }
+}
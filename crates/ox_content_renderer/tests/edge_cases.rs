@@ -25,6 +25,41 @@ fn external_links_get_security_attributes() {
     assert!(html.contains("rel=\"noopener noreferrer\""));
 }
 
+#[test]
+fn external_links_rel_only_stay_in_same_tab() {
+    let options = HtmlRendererOptions { external_target_blank: false, ..Default::default() };
+    let html = render("[site](https://example.com)", ParserOptions::default(), options);
+
+    assert!(html.contains("<a href=\"https://example.com\" rel=\"noopener noreferrer\">"));
+    assert!(!html.contains("target=\"_blank\""));
+}
+
+#[test]
+fn external_links_rel_and_target_are_independent() {
+    let options = HtmlRendererOptions { external_rel: false, ..Default::default() };
+    let html = render("[site](https://example.com)", ParserOptions::default(), options);
+    assert!(html.contains("<a href=\"https://example.com\" target=\"_blank\">"));
+
+    let options = HtmlRendererOptions {
+        external_rel: false,
+        external_target_blank: false,
+        ..Default::default()
+    };
+    let html = render("[site](https://example.com)", ParserOptions::default(), options);
+    assert!(html.contains("<a href=\"https://example.com\">"));
+}
+
+#[test]
+fn soft_breaks_use_soft_break_option() {
+    let source = "line 1\nline 2  \nline 3";
+    let html = render(source, ParserOptions::default(), HtmlRendererOptions::default());
+    assert_eq!(html, "<p>line 1\nline 2<br>\nline 3</p>\n");
+
+    let options = HtmlRendererOptions { soft_break: "<br>\n".to_string(), ..Default::default() };
+    let html = render(source, ParserOptions::default(), options);
+    assert_eq!(html, "<p>line 1<br>\nline 2<br>\nline 3</p>\n");
+}
+
 #[test]
 fn relative_links_do_not_get_external_attributes() {
     let html =
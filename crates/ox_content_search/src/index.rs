@@ -4,7 +4,37 @@ use std::collections::HashMap;
 
 use serde::{Deserialize, Serialize};
 
-use crate::tokenizer::tokenize;
+use crate::tokenizer::{normalize, tokenize, TokenFilter};
+
+/// Schema version written into serialized indexes.
+///
+/// Bump this whenever the serialized layout changes in a way older readers
+/// cannot understand.
+pub const INDEX_VERSION: u32 = 2;
+
+/// Error returned when loading a serialized index.
+#[derive(Debug, thiserror::Error)]
+pub enum SearchIndexError {
+    /// The input is not a valid serialized index.
+    #[error("invalid search index: {0}")]
+    Json(#[from] serde_json::Error),
+    /// The index was written with an incompatible schema version.
+    #[error("incompatible search index version {found} (expected {expected}); rebuild the index")]
+    VersionMismatch {
+        /// Version found in the serialized index (0 if absent).
+        found: u32,
+        /// Version supported by this build.
+        expected: u32,
+    },
+}
+
+/// Minimal view of a serialized index used to check the schema version
+/// before deserializing the rest.
+#[derive(Deserialize)]
+struct VersionProbe {
+    #[serde(default)]
+    version: u32,
+}
 
 /// A searchable document in the index.
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -19,6 +49,10 @@ pub struct SearchDocument {
     pub body: String,
     /// Headings in the document.
     pub headings: Vec<String>,
+    /// Byte offset in `body` where the section under each heading starts
+    /// (parallel to `headings`).
+    #[serde(default)]
+    pub heading_offsets: Vec<usize>,
     /// Code snippets (optional).
     #[serde(default)]
     pub code: Vec<String>,
@@ -31,8 +65,17 @@ pub struct Posting {
     pub doc_idx: usize,
     /// Term frequency in this document.
     pub tf: u32,
-    /// Field where term was found (for boosting).
+    /// Highest-weighted field the term was found in (for boosting).
     pub field: Field,
+    /// Term frequency in each field, indexed by [`Field::index`].
+    pub field_tf: [u32; 4],
+    /// Indices of the document's headings that contain the term.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub headings: Vec<usize>,
+    /// Indices of the body sections (one per heading, see
+    /// [`SearchDocument::heading_offsets`]) that contain the term.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub sections: Vec<usize>,
 }
 
 /// Document fields with different boost weights.
@@ -49,6 +92,15 @@ pub enum Field {
 }
 
 impl Field {
+    /// All fields, from highest to lowest weight.
+    pub const ALL: [Self; 4] = [Self::Title, Self::Heading, Self::Body, Self::Code];
+
+    /// Returns this field's position in [`Field::ALL`] and [`Posting::field_tf`].
+    #[must_use]
+    pub const fn index(self) -> usize {
+        self as usize
+    }
+
     /// Returns the boost factor for this field.
     #[must_use]
     pub fn boost(self) -> f64 {
@@ -59,11 +111,25 @@ impl Field {
             Self::Code => 0.5,
         }
     }
+
+    /// Returns the field name used by `field:term` queries.
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Title => "title",
+            Self::Heading => "heading",
+            Self::Body => "body",
+            Self::Code => "code",
+        }
+    }
 }
 
 /// The main search index structure.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SearchIndex {
+    /// Schema version of the serialized index (see [`INDEX_VERSION`]).
+    #[serde(default)]
+    pub version: u32,
     /// All indexed documents.
     pub documents: Vec<SearchDocument>,
     /// Inverted index: term -> list of postings.
@@ -74,6 +140,23 @@ pub struct SearchIndex {
     pub avg_dl: f64,
     /// Total number of documents.
     pub doc_count: usize,
+    /// Whether diacritics were folded at index time (queries must match).
+    #[serde(default)]
+    pub fold_diacritics: bool,
+    /// Minimum indexed term length in characters (single CJK characters are always indexed).
+    #[serde(default = "default_min_term_length")]
+    pub min_term_length: usize,
+    /// Whether purely numeric terms were indexed.
+    #[serde(default = "default_index_numbers")]
+    pub index_numbers: bool,
+}
+
+fn default_min_term_length() -> usize {
+    TokenFilter::default().min_term_length
+}
+
+fn default_index_numbers() -> bool {
+    TokenFilter::default().index_numbers
 }
 
 impl SearchIndex {
@@ -90,8 +173,31 @@ impl SearchIndex {
     }
 
     /// Deserializes an index from JSON.
-    pub fn from_json(json: &str) -> Result<Self, serde_json::Error> {
-        serde_json::from_str(json)
+    ///
+    /// Fails with [`SearchIndexError::VersionMismatch`] if the index was
+    /// written with a different [`INDEX_VERSION`].
+    pub fn from_json(json: &str) -> Result<Self, SearchIndexError> {
+        Self::from_bytes(json.as_bytes())
+    }
+
+    /// Deserializes an index from JSON bytes.
+    ///
+    /// Fails with [`SearchIndexError::VersionMismatch`] if the index was
+    /// written with a different [`INDEX_VERSION`].
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, SearchIndexError> {
+        let probe: VersionProbe = serde_json::from_slice(bytes)?;
+        if probe.version != INDEX_VERSION {
+            return Err(SearchIndexError::VersionMismatch {
+                found: probe.version,
+                expected: INDEX_VERSION,
+            });
+        }
+        Ok(serde_json::from_slice(bytes)?)
+    }
+
+    /// Returns the token filter the index was built with.
+    pub(crate) const fn token_filter(&self) -> TokenFilter {
+        TokenFilter { min_term_length: self.min_term_length, index_numbers: self.index_numbers }
     }
 
     /// Returns the number of documents in the index.
@@ -111,6 +217,8 @@ impl SearchIndex {
 #[derive(Debug, Default)]
 pub struct SearchIndexBuilder {
     documents: Vec<SearchDocument>,
+    fold_diacritics: bool,
+    filter: TokenFilter,
 }
 
 impl SearchIndexBuilder {
@@ -120,6 +228,26 @@ impl SearchIndexBuilder {
         Self::default()
     }
 
+    /// Enables Unicode normalization and diacritic folding (`café` matches `cafe`).
+    pub fn fold_diacritics(&mut self, enabled: bool) -> &mut Self {
+        self.fold_diacritics = enabled;
+        self
+    }
+
+    /// Sets the minimum term length in characters (default: 2).
+    ///
+    /// Shorter tokens are not indexed. Single CJK characters are always indexed.
+    pub fn min_term_length(&mut self, length: usize) -> &mut Self {
+        self.filter.min_term_length = length;
+        self
+    }
+
+    /// Sets whether purely numeric tokens such as `2024` are indexed (default: true).
+    pub fn index_numbers(&mut self, enabled: bool) -> &mut Self {
+        self.filter.index_numbers = enabled;
+        self
+    }
+
     /// Adds a document to the index.
     pub fn add_document(&mut self, doc: SearchDocument) -> &mut Self {
         self.documents.push(doc);
@@ -134,6 +262,7 @@ impl SearchIndexBuilder {
             url: url.to_string(),
             body: body.to_string(),
             headings: Vec::new(),
+            heading_offsets: Vec::new(),
             code: Vec::new(),
         });
         self
@@ -145,52 +274,77 @@ impl SearchIndexBuilder {
         let mut index: HashMap<String, Vec<Posting>> = HashMap::new();
         let mut df: HashMap<String, usize> = HashMap::new();
         let mut total_length = 0usize;
+        let fold = self.fold_diacritics;
+        let filter = self.filter;
 
         for (doc_idx, doc) in self.documents.iter().enumerate() {
-            let mut doc_terms: HashMap<String, (u32, Field)> = HashMap::new();
+            let mut doc_terms: HashMap<String, [u32; 4]> = HashMap::new();
+            // Heading and body-section indices each term occurs in.
+            let mut heading_indices: HashMap<String, Vec<usize>> = HashMap::new();
+            let mut section_indices: HashMap<String, Vec<usize>> = HashMap::new();
 
             // Index title
-            for token in tokenize(&doc.title) {
-                doc_terms
-                    .entry(token)
-                    .and_modify(|(count, _)| *count += 1)
-                    .or_insert((1, Field::Title));
+            for token in tokenize(&normalize(&doc.title, fold), filter) {
+                doc_terms.entry(token).or_default()[Field::Title.index()] += 1;
             }
 
             // Index headings
-            for heading in &doc.headings {
-                for token in tokenize(heading) {
-                    doc_terms
-                        .entry(token)
-                        .and_modify(|(count, _)| *count += 1)
-                        .or_insert((1, Field::Heading));
+            for (i, heading) in doc.headings.iter().enumerate() {
+                for token in tokenize(&normalize(heading, fold), filter) {
+                    let indices = heading_indices.entry(token.clone()).or_default();
+                    if indices.last() != Some(&i) {
+                        indices.push(i);
+                    }
+                    doc_terms.entry(token).or_default()[Field::Heading.index()] += 1;
                 }
             }
 
             // Index body
-            let body_tokens = tokenize(&doc.body);
+            let body_tokens = tokenize(&normalize(&doc.body, fold), filter);
             total_length += body_tokens.len();
             for token in body_tokens {
-                doc_terms
-                    .entry(token)
-                    .and_modify(|(count, _)| *count += 1)
-                    .or_insert((1, Field::Body));
+                doc_terms.entry(token).or_default()[Field::Body.index()] += 1;
+            }
+
+            // Record which section under a heading each body term occurs in
+            for (i, &start) in doc.heading_offsets.iter().enumerate().take(doc.headings.len()) {
+                let end = doc.heading_offsets.get(i + 1).copied().unwrap_or(doc.body.len());
+                let Some(section) = doc.body.get(start..end.max(start)) else {
+                    continue;
+                };
+                for token in tokenize(&normalize(section, fold), filter) {
+                    let indices = section_indices.entry(token).or_default();
+                    if indices.last() != Some(&i) {
+                        indices.push(i);
+                    }
+                }
             }
 
             // Index code
             for code in &doc.code {
-                for token in tokenize(code) {
-                    doc_terms
-                        .entry(token)
-                        .and_modify(|(count, _)| *count += 1)
-                        .or_insert((1, Field::Code));
+                for token in tokenize(&normalize(code, fold), filter) {
+                    doc_terms.entry(token).or_default()[Field::Code.index()] += 1;
                 }
             }
 
             // Update document frequency and inverted index
-            for (term, (tf, field)) in doc_terms {
+            for (term, field_tf) in doc_terms {
+                let tf = field_tf.iter().sum();
+                let field = Field::ALL
+                    .into_iter()
+                    .find(|field| field_tf[field.index()] > 0)
+                    .unwrap_or(Field::Body);
                 *df.entry(term.clone()).or_insert(0) += 1;
-                index.entry(term).or_default().push(Posting { doc_idx, tf, field });
+                let headings = heading_indices.remove(&term).unwrap_or_default();
+                let sections = section_indices.remove(&term).unwrap_or_default();
+                index.entry(term).or_default().push(Posting {
+                    doc_idx,
+                    tf,
+                    field,
+                    field_tf,
+                    headings,
+                    sections,
+                });
             }
         }
 
@@ -198,7 +352,17 @@ impl SearchIndexBuilder {
         #[allow(clippy::cast_precision_loss)]
         let avg_dl = if doc_count > 0 { total_length as f64 / doc_count as f64 } else { 0.0 };
 
-        SearchIndex { documents: self.documents, index, df, avg_dl, doc_count }
+        SearchIndex {
+            version: INDEX_VERSION,
+            documents: self.documents,
+            index,
+            df,
+            avg_dl,
+            doc_count,
+            fold_diacritics: fold,
+            min_term_length: filter.min_term_length,
+            index_numbers: filter.index_numbers,
+        }
     }
 }
 
@@ -237,4 +401,104 @@ mod tests {
         assert_eq!(restored.len(), 1);
         assert_eq!(restored.documents[0].title, "Test");
     }
+
+    #[test]
+    fn test_postings_record_heading_sections() {
+        let mut builder = SearchIndexBuilder::new();
+        builder.add_document(SearchDocument {
+            id: "1".to_string(),
+            title: "Guide".to_string(),
+            url: "/guide".to_string(),
+            body: "Run setup first. Call the api, then setup again.".to_string(),
+            headings: vec!["Setup".to_string(), "Usage".to_string()],
+            heading_offsets: vec![0, 17],
+            code: Vec::new(),
+        });
+
+        let index = builder.build();
+        let posting = |term: &str| &index.index[term][0];
+        assert_eq!(posting("setup").headings, [0]);
+        assert_eq!(posting("setup").sections, [0, 1]);
+        assert_eq!(posting("api").headings, Vec::<usize>::new());
+        assert_eq!(posting("api").sections, [1]);
+
+        let restored = SearchIndex::from_json(&index.to_json()).unwrap();
+        assert_eq!(restored.index["api"][0].sections, [1]);
+    }
+
+    #[test]
+    fn test_fold_diacritics_recorded() {
+        let mut builder = SearchIndexBuilder::new();
+        builder.fold_diacritics(true).add_simple("1", "Café", "/cafe", "Naïve approach");
+
+        let index = builder.build();
+        assert!(index.fold_diacritics);
+        assert!(index.index.contains_key("cafe"));
+        assert!(index.index.contains_key("naive"));
+
+        let restored = SearchIndex::from_json(&index.to_json()).unwrap();
+        assert!(restored.fold_diacritics);
+    }
+
+    #[test]
+    fn test_term_filters() {
+        let body = "Go 1.22 adds range over int in 2024";
+
+        let mut builder = SearchIndexBuilder::new();
+        builder.add_simple("1", "Release", "/release", body);
+        let index = builder.build();
+        for term in ["go", "22", "2024"] {
+            assert!(index.index.contains_key(term), "{term}");
+        }
+
+        let mut builder = SearchIndexBuilder::new();
+        builder
+            .min_term_length(3)
+            .index_numbers(false)
+            .add_simple("1", "Release", "/release", body);
+        let index = builder.build();
+        for term in ["go", "22", "2024"] {
+            assert!(!index.index.contains_key(term), "{term}");
+        }
+        assert!(index.index.contains_key("range"));
+
+        let restored = SearchIndex::from_json(&index.to_json()).unwrap();
+        assert_eq!((restored.min_term_length, restored.index_numbers), (3, false));
+    }
+
+    #[test]
+    fn test_version_roundtrip() {
+        let mut builder = SearchIndexBuilder::new();
+        builder.add_simple("1", "Test", "/test", "Test content");
+
+        let index = builder.build();
+        assert_eq!(index.version, INDEX_VERSION);
+
+        let restored = SearchIndex::from_bytes(index.to_json().as_bytes()).unwrap();
+        assert_eq!(restored.version, INDEX_VERSION);
+        assert_eq!(restored.len(), 1);
+    }
+
+    #[test]
+    fn test_version_mismatch() {
+        let mut builder = SearchIndexBuilder::new();
+        builder.add_simple("1", "Test", "/test", "Test content");
+
+        let mut index = builder.build();
+        index.version = INDEX_VERSION + 1;
+        let err = SearchIndex::from_json(&index.to_json()).unwrap_err();
+        assert!(matches!(
+            err,
+            SearchIndexError::VersionMismatch { found, expected }
+                if found == INDEX_VERSION + 1 && expected == INDEX_VERSION
+        ));
+        assert!(err.to_string().contains("incompatible search index version"));
+
+        // Indexes written before versioning have no `version` field.
+        let legacy = r#"{"documents":[],"index":{},"df":{},"avg_dl":0.0,"doc_count":0}"#;
+        assert!(matches!(
+            SearchIndex::from_json(legacy),
+            Err(SearchIndexError::VersionMismatch { found: 0, .. })
+        ));
+    }
 }
@@ -1,6 +1,9 @@
 //! Document indexer using the Visitor pattern.
 
-use ox_content_ast::{walk_document, CodeBlock, Document, Heading, InlineCode, Node, Text, Visit};
+use ox_content_ast::{
+    decode_entity, walk_document, Break, CodeBlock, Document, Heading, InlineCode, Node, Text,
+    Visit,
+};
 
 use crate::index::SearchDocument;
 
@@ -11,6 +14,8 @@ pub struct DocumentIndexer {
     title: Option<String>,
     /// All headings in the document.
     headings: Vec<String>,
+    /// Body offset where the section under each heading starts.
+    heading_offsets: Vec<usize>,
     /// Body text content.
     body: String,
     /// Code snippets.
@@ -33,6 +38,127 @@ impl DocumentIndexer {
         walk_document(self, doc);
     }
 
+    /// Extracts searchable content from a rendered HTML page.
+    ///
+    /// `<h1>`–`<h6>` become headings (the first `<h1>` is the title), `<pre>` and
+    /// `<code>` become code snippets, and the remaining text becomes the body.
+    /// Tags, comments, and `<script>`/`<style>`/`<title>` contents are dropped.
+    pub fn extract_html(&mut self, html: &str) {
+        let mut rest = html;
+        let mut pending = String::new();
+        let mut code = String::new();
+        let mut code_depth = 0usize;
+        let mut heading_depth = None;
+        let mut skip_until: Option<String> = None;
+
+        while !rest.is_empty() {
+            let text_end = rest.find('<').unwrap_or(rest.len());
+            let text = &rest[..text_end];
+            rest = &rest[text_end..];
+
+            if skip_until.is_none() && !text.is_empty() {
+                let text = decode_html_entities(text);
+                if heading_depth.is_some() {
+                    self.current_heading.push_str(&text);
+                } else if code_depth > 0 {
+                    code.push_str(&text);
+                } else {
+                    pending.push_str(&text);
+                }
+            }
+            if rest.is_empty() {
+                break;
+            }
+
+            if let Some(comment) = rest.strip_prefix("<!--") {
+                rest = comment.find("-->").map_or("", |end| &comment[end + 3..]);
+                continue;
+            }
+            let Some((tag, after)) = parse_html_tag(rest) else {
+                // A `<` that does not start a tag is plain text.
+                if skip_until.is_none() {
+                    pending.push('<');
+                }
+                rest = &rest[1..];
+                continue;
+            };
+            rest = after;
+
+            if let Some(name) = &skip_until {
+                if tag.closing && tag.name == *name {
+                    skip_until = None;
+                }
+                continue;
+            }
+
+            match tag.name.as_str() {
+                "script" | "style" | "title" if !tag.closing => skip_until = Some(tag.name),
+                "h1" | "h2" | "h3" | "h4" | "h5" | "h6" => {
+                    if tag.closing {
+                        if let Some(depth) = heading_depth.take() {
+                            self.push_html_heading(depth);
+                        }
+                    } else {
+                        self.push_body_text(&pending);
+                        pending.clear();
+                        self.current_heading.clear();
+                        heading_depth = Some(tag.name.as_bytes()[1] - b'0');
+                    }
+                }
+                "pre" | "code" if heading_depth.is_none() => {
+                    if tag.closing {
+                        code_depth = code_depth.saturating_sub(1);
+                        if code_depth == 0 {
+                            if !code.trim().is_empty() {
+                                self.code.push(code.clone());
+                            }
+                            code.clear();
+                        }
+                    } else {
+                        if code_depth == 0 {
+                            self.push_body_text(&pending);
+                            pending.clear();
+                        }
+                        code_depth += 1;
+                    }
+                }
+                name if heading_depth.is_none() && code_depth == 0 && !is_inline_tag(name) => {
+                    self.push_body_text(&pending);
+                    pending.clear();
+                }
+                _ => {}
+            }
+        }
+
+        if let Some(depth) = heading_depth {
+            self.push_html_heading(depth);
+        }
+        self.push_body_text(&pending);
+    }
+
+    /// Finishes a heading collected from HTML.
+    fn push_html_heading(&mut self, depth: u8) {
+        let heading_text = collapse_whitespace(&std::mem::take(&mut self.current_heading));
+        if !heading_text.is_empty() {
+            if depth == 1 && self.title.is_none() {
+                self.title = Some(heading_text.clone());
+            }
+            self.headings.push(heading_text);
+            self.heading_offsets.push(self.body.len());
+        }
+    }
+
+    /// Appends a run of HTML text to the body, collapsing whitespace.
+    fn push_body_text(&mut self, text: &str) {
+        let text = collapse_whitespace(text);
+        if !text.is_empty() {
+            if !self.body.is_empty() {
+                self.body.push(' ');
+            }
+            self.body.push_str(&text);
+        }
+    }
+
     /// Creates a `SearchDocument` from the extracted content.
     #[must_use]
     pub fn into_search_document(self, id: String, url: String) -> SearchDocument {
@@ -42,6 +168,7 @@ impl DocumentIndexer {
             url,
             body: self.body,
             headings: self.headings,
+            heading_offsets: self.heading_offsets,
             code: self.code,
         }
     }
@@ -64,6 +191,12 @@ impl DocumentIndexer {
         &self.headings
     }
 
+    /// Returns the body offset where the section under each heading starts.
+    #[must_use]
+    pub fn heading_offsets(&self) -> &[usize] {
+        &self.heading_offsets
+    }
+
     /// Returns the extracted code snippets.
     #[must_use]
     pub fn code(&self) -> &[String] {
@@ -71,6 +204,97 @@ impl DocumentIndexer {
     }
 }
 
+/// A start or end tag found while scanning HTML.
+struct HtmlTag {
+    /// Lowercased tag name.
+    name: String,
+    /// Whether this is an end tag (`</name>`).
+    closing: bool,
+}
+
+/// Parses the tag at the start of `input` (which begins with `<`).
+///
+/// Returns the tag and the input after its closing `>`, or `None` if `input`
+/// does not start with a tag.
+fn parse_html_tag(input: &str) -> Option<(HtmlTag, &str)> {
+    let body = &input[1..];
+    let (closing, body) = match body.strip_prefix('/') {
+        Some(body) => (true, body),
+        None => (false, body),
+    };
+    if body.starts_with('!') {
+        // Doctype or CDATA: skip it without a name.
+        let end = body.find('>')?;
+        return Some((HtmlTag { name: String::new(), closing }, &body[end + 1..]));
+    }
+    if !body.starts_with(|c: char| c.is_ascii_alphabetic()) {
+        return None;
+    }
+
+    let name_end = body.find(|c: char| !c.is_ascii_alphanumeric()).unwrap_or(body.len());
+    let name = body[..name_end].to_ascii_lowercase();
+
+    let mut quote = None;
+    for (i, c) in body[name_end..].char_indices() {
+        match (quote, c) {
+            (None, '"' | '\'') => quote = Some(c),
+            (Some(q), c) if c == q => quote = None,
+            (None, '>') => {
+                return Some((HtmlTag { name, closing }, &body[name_end + i + 1..]));
+            }
+            _ => {}
+        }
+    }
+    None
+}
+
+/// Returns true for tags that do not separate words in the body text.
+fn is_inline_tag(name: &str) -> bool {
+    matches!(
+        name,
+        "a" | "abbr"
+            | "b"
+            | "del"
+            | "em"
+            | "i"
+            | "ins"
+            | "kbd"
+            | "mark"
+            | "s"
+            | "small"
+            | "span"
+            | "strong"
+            | "sub"
+            | "sup"
+            | "u"
+    )
+}
+
+/// Decodes the character references commonly emitted by HTML renderers.
+fn decode_html_entities(text: &str) -> String {
+    if !text.contains('&') {
+        return text.to_string();
+    }
+
+    let mut result = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(start) = rest.find('&') {
+        result.push_str(&rest[..start]);
+        rest = &rest[start..];
+
+        let (ch, len) = decode_entity(rest).unwrap_or(('&', 1));
+        result.push(ch);
+        rest = &rest[len..];
+    }
+    result.push_str(rest);
+    result
+}
+
+/// Collapses whitespace runs into single spaces and trims the ends.
+fn collapse_whitespace(text: &str) -> String {
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
 impl<'a> Visit<'a> for DocumentIndexer {
     fn visit_heading(&mut self, heading: &Heading<'a>) {
         self.in_heading = true;
@@ -90,6 +314,7 @@ impl<'a> Visit<'a> for DocumentIndexer {
                 self.title = Some(heading_text.clone());
             }
             self.headings.push(heading_text);
+            self.heading_offsets.push(self.body.len());
         }
     }
 
@@ -115,6 +340,12 @@ impl<'a> Visit<'a> for DocumentIndexer {
         }
     }
 
+    fn visit_break(&mut self, _break_node: &Break) {
+        if self.in_heading {
+            self.current_heading.push(' ');
+        }
+    }
+
     fn visit_code_block(&mut self, code_block: &CodeBlock<'a>) {
         self.code.push(code_block.value.to_string());
     }
@@ -124,6 +355,7 @@ impl<'a> Visit<'a> for DocumentIndexer {
             Node::Text(n) => self.visit_text(n),
             Node::Heading(n) => self.visit_heading(n),
             Node::InlineCode(n) => self.visit_inline_code(n),
+            Node::Break(n) => self.visit_break(n),
             Node::CodeBlock(n) => self.visit_code_block(n),
             Node::Paragraph(n) => {
                 for child in &n.children {
@@ -156,6 +388,26 @@ impl<'a> Visit<'a> for DocumentIndexer {
                     }
                 }
             }
+            Node::DescriptionList(n) => {
+                for child in &n.children {
+                    self.visit_node(child);
+                }
+            }
+            Node::DescriptionTerm(n) => {
+                for child in &n.children {
+                    self.visit_node(child);
+                }
+            }
+            Node::DescriptionDetails(n) => {
+                for child in &n.children {
+                    self.visit_node(child);
+                }
+            }
+            Node::Container(n) => {
+                for child in &n.children {
+                    self.visit_node(child);
+                }
+            }
             Node::Emphasis(n) => {
                 for child in &n.children {
                     self.visit_node(child);
@@ -176,6 +428,21 @@ impl<'a> Visit<'a> for DocumentIndexer {
                     self.visit_node(child);
                 }
             }
+            Node::Mark(n) => {
+                for child in &n.children {
+                    self.visit_node(child);
+                }
+            }
+            Node::Subscript(n) => {
+                for child in &n.children {
+                    self.visit_node(child);
+                }
+            }
+            Node::Superscript(n) => {
+                for child in &n.children {
+                    self.visit_node(child);
+                }
+            }
             Node::FootnoteDefinition(n) => {
                 for child in &n.children {
                     self.visit_node(child);
@@ -214,4 +481,40 @@ mod tests {
 
         assert_eq!(indexer.title(), Some("Test Title"));
     }
+
+    #[test]
+    fn test_extract_html() {
+        let html = r#"<!DOCTYPE html>
+<html>
+<head><title>Ignored</title><style>body { color: red; }</style></head>
+<body>
+  <h1 id="intro">Getting <em>Started</em></h1>
+  <p>Install the <a href="/pkg">package</a> &amp; run it.</p>
+  <!-- a comment -->
+  <h2>Usage <code>run()</code></h2>
+  <pre><code class="language-rust">fn main() {
+    println!("a &lt; b");
+}
+</code></pre>
+  <p>Call <code>run</code> once.</p>
+  <script>console.log("skip");</script>
+</body>
+</html>"#;
+
+        let mut indexer = DocumentIndexer::new();
+        indexer.extract_html(html);
+
+        assert_eq!(indexer.title(), Some("Getting Started"));
+        assert_eq!(indexer.headings(), ["Getting Started", "Usage run()"]);
+        assert_eq!(
+            indexer.code(),
+            ["fn main() {\n    println!(\"a < b\");\n}\n".to_string(), "run".to_string()]
+        );
+        assert_eq!(indexer.body(), "Install the package & run it. Call once.");
+        assert_eq!(indexer.heading_offsets(), [0, 29]);
+
+        let doc = indexer.into_search_document("guide".to_string(), "/guide".to_string());
+        assert_eq!(doc.title, "Getting Started");
+        assert_eq!(doc.headings.len(), 2);
+    }
 }
@@ -8,6 +8,8 @@
 //! - TF-IDF based scoring for relevance ranking
 //! - Multi-field search (title, body, headings, code)
 //! - Prefix matching for autocomplete
+//! - Optional Unicode normalization and diacritic folding
+//! - Configurable minimum term length and numeric-token indexing
 //! - Serializable index for build-time generation
 //!
 //! # Example
@@ -32,6 +34,9 @@ mod indexer;
 mod query;
 mod tokenizer;
 
-pub use index::{Field, Posting, SearchDocument, SearchIndex, SearchIndexBuilder};
+pub use index::{
+    Field, Posting, SearchDocument, SearchIndex, SearchIndexBuilder, SearchIndexError,
+    INDEX_VERSION,
+};
 pub use indexer::DocumentIndexer;
 pub use query::{SearchOptions, SearchResult};
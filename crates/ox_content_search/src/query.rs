@@ -1,11 +1,18 @@
 //! Search query engine with BM25 scoring.
+//!
+//! Queries accept a small operator grammar: `+term` requires a term,
+//! `-term` excludes documents containing it, and `field:term` (with field
+//! `title`, `heading`, `body`, or `code`) limits a term to one field.
+//!
+//! Body matches in the section under a heading that also matches the query
+//! are boosted, so the "Installation" section ranks first for `installation`.
 
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
 use serde::{Deserialize, Serialize};
 
-use crate::index::SearchIndex;
-use crate::tokenizer::tokenize_query;
+use crate::index::{Field, Posting, SearchDocument, SearchIndex};
+use crate::tokenizer::{normalize, tokenize, tokenize_query};
 
 /// Search options.
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -22,6 +29,11 @@ pub struct SearchOptions {
     /// Minimum score threshold (0.0 - 1.0).
     #[serde(default)]
     pub threshold: f64,
+    /// Collapse near-duplicate results: a result whose title and snippet
+    /// share more than this fraction of words (0.0 - 1.0) with a
+    /// higher-ranked result is dropped. `None` keeps every result.
+    #[serde(default)]
+    pub dedupe: Option<f64>,
 }
 
 fn default_limit() -> usize {
@@ -34,7 +46,7 @@ fn default_prefix() -> bool {
 
 impl Default for SearchOptions {
     fn default() -> Self {
-        Self { limit: 10, prefix: true, fuzzy: false, threshold: 0.0 }
+        Self { limit: 10, prefix: true, fuzzy: false, threshold: 0.0, dedupe: None }
     }
 }
 
@@ -51,6 +63,11 @@ pub struct SearchResult {
     pub score: f64,
     /// Matched terms.
     pub matches: Vec<String>,
+    /// Matched terms with each field they occur in, ordered by field.
+    ///
+    /// A term found in several fields appears once per field.
+    #[serde(default)]
+    pub field_matches: Vec<(Field, String)>,
     /// Content snippet with highlights.
     pub snippet: String,
 }
@@ -59,6 +76,68 @@ pub struct SearchResult {
 const K1: f64 = 1.2;
 const B: f64 = 0.75;
 
+/// Multiplier for body matches in a section whose heading matches the query.
+const SECTION_BOOST: f64 = 2.0;
+
+/// How a query term constrains the result set.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum TermOperator {
+    /// Contributes to the score but is not required.
+    Optional,
+    /// Documents must contain the term (`+term`).
+    Required,
+    /// Documents containing the term are dropped (`-term`).
+    Excluded,
+}
+
+/// A single term of a parsed query.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct QueryTerm {
+    token: String,
+    operator: TermOperator,
+    field: Option<Field>,
+}
+
+/// Parses a raw query into terms, applying `+`, `-` and `field:` operators.
+fn parse_query(query: &str, fold_diacritics: bool) -> Vec<QueryTerm> {
+    let mut terms = Vec::new();
+
+    for word in query.split_whitespace() {
+        let (operator, rest) = if let Some(rest) = word.strip_prefix('+') {
+            (TermOperator::Required, rest)
+        } else if let Some(rest) = word.strip_prefix('-') {
+            (TermOperator::Excluded, rest)
+        } else {
+            (TermOperator::Optional, word)
+        };
+
+        let (field, text) = match rest.split_once(':') {
+            Some((name, text)) => match parse_field(name) {
+                Some(field) => (Some(field), text),
+                None => (None, rest),
+            },
+            None => (None, rest),
+        };
+
+        for token in tokenize_query(&normalize(text, fold_diacritics)) {
+            terms.push(QueryTerm { token, operator, field });
+        }
+    }
+
+    terms
+}
+
+/// Maps a `field:` prefix to an index field.
+fn parse_field(name: &str) -> Option<Field> {
+    match name.to_ascii_lowercase().as_str() {
+        "title" => Some(Field::Title),
+        "heading" | "headings" => Some(Field::Heading),
+        "body" => Some(Field::Body),
+        "code" => Some(Field::Code),
+        _ => None,
+    }
+}
+
 impl SearchIndex {
     /// Searches the index with the given query.
     #[must_use]
@@ -67,74 +146,153 @@ impl SearchIndex {
             return Vec::new();
         }
 
-        let tokens = tokenize_query(query);
-        if tokens.is_empty() {
+        let mut terms = parse_query(query, self.fold_diacritics);
+        // Drop terms the index never stores, keeping a final term that may be a prefix.
+        let filter = self.token_filter();
+        let last = terms.len().saturating_sub(1);
+        let mut position = 0;
+        terms.retain(|term| {
+            let keep = filter.accepts(&term.token) || (options.prefix && position == last);
+            position += 1;
+            keep
+        });
+        let last_scored = terms.iter().rposition(|term| term.operator != TermOperator::Excluded);
+        let Some(last_scored) = last_scored else {
             return Vec::new();
+        };
+
+        let matching_terms: Vec<Vec<String>> = terms
+            .iter()
+            .enumerate()
+            .map(|(i, term)| {
+                self.find_matching_terms(&term.token, i == last_scored && options.prefix)
+            })
+            .collect();
+        let scored_terms: HashSet<&str> = terms
+            .iter()
+            .zip(&matching_terms)
+            .filter(|(term, _)| term.operator != TermOperator::Excluded)
+            .flat_map(|(_, matches)| matches.iter().map(String::as_str))
+            .collect();
+
+        // Sections whose heading contains a scored term, by document.
+        let mut matching_sections: HashMap<usize, HashSet<usize>> = HashMap::new();
+        for term in &scored_terms {
+            for posting in self.index.get(*term).into_iter().flatten() {
+                if !posting.headings.is_empty() {
+                    matching_sections.entry(posting.doc_idx).or_default().extend(&posting.headings);
+                }
+            }
         }
 
         // Calculate scores for each document
         let mut doc_scores: HashMap<usize, (f64, Vec<String>)> = HashMap::new();
+        let mut required_docs: Vec<HashSet<usize>> = Vec::new();
+        let mut excluded_docs: HashSet<usize> = HashSet::new();
+
+        for (query_term, matching_terms) in terms.iter().zip(&matching_terms) {
+            if query_term.operator == TermOperator::Excluded {
+                for term in matching_terms {
+                    excluded_docs.extend(
+                        self.field_postings(term, query_term.field).map(|posting| posting.doc_idx),
+                    );
+                }
+                continue;
+            }
 
-        for (i, token) in tokens.iter().enumerate() {
-            let is_last = i == tokens.len() - 1;
-
-            // Get matching terms (exact or prefix)
-            let matching_terms = self.find_matching_terms(token, is_last && options.prefix);
+            let mut matched_docs = HashSet::new();
 
             for term in matching_terms {
-                if let Some(postings) = self.index.get(&term) {
-                    let df = self.df.get(&term).copied().unwrap_or(1);
-                    let idf = self.compute_idf(df);
-
-                    for posting in postings {
-                        let doc = &self.documents[posting.doc_idx];
-                        #[allow(clippy::cast_precision_loss)]
-                        let doc_len = doc.body.len() as f64;
-                        let tf = f64::from(posting.tf);
-
-                        // BM25 score with field boost
-                        let score = idf
-                            * ((tf * (K1 + 1.0))
-                                / K1.mul_add(1.0 - B + B * doc_len / self.avg_dl, tf))
-                            * posting.field.boost();
-
-                        let entry = doc_scores.entry(posting.doc_idx).or_insert((0.0, Vec::new()));
-                        entry.0 += score;
-                        if !entry.1.contains(&term) {
-                            entry.1.push(term.clone());
-                        }
+                let df = self.df.get(term).copied().unwrap_or(1);
+                let idf = self.compute_idf(df);
+
+                for posting in self.field_postings(term, query_term.field) {
+                    let doc = &self.documents[posting.doc_idx];
+                    #[allow(clippy::cast_precision_loss)]
+                    let doc_len = doc.body.len() as f64;
+                    // A field-scoped term only counts its occurrences in that field.
+                    let (tf, field) =
+                        query_term.field.map_or((posting.tf, posting.field), |field| {
+                            (posting.field_tf[field.index()], field)
+                        });
+                    let tf = f64::from(tf);
+
+                    // BM25 score with field boost
+                    let mut score = idf
+                        * ((tf * (K1 + 1.0)) / K1.mul_add(1.0 - B + B * doc_len / self.avg_dl, tf))
+                        * field.boost();
+                    if field == Field::Body
+                        && matching_sections.get(&posting.doc_idx).is_some_and(|sections| {
+                            posting.sections.iter().any(|section| sections.contains(section))
+                        })
+                    {
+                        score *= SECTION_BOOST;
+                    }
+
+                    matched_docs.insert(posting.doc_idx);
+                    let entry = doc_scores.entry(posting.doc_idx).or_insert((0.0, Vec::new()));
+                    entry.0 += score;
+                    if !entry.1.contains(term) {
+                        entry.1.push(term.clone());
                     }
                 }
             }
+
+            if query_term.operator == TermOperator::Required {
+                required_docs.push(matched_docs);
+            }
         }
 
-        // Convert to results and sort by score
-        let mut results: Vec<SearchResult> = doc_scores
-            .into_iter()
-            .filter(|(_, (score, _))| *score >= options.threshold)
-            .map(|(doc_idx, (score, matches))| {
-                let doc = &self.documents[doc_idx];
-                let snippet = self.generate_snippet(&doc.body, &matches, 150);
-                SearchResult {
-                    id: doc.id.clone(),
-                    title: doc.title.clone(),
-                    url: doc.url.clone(),
-                    score,
-                    matches,
-                    snippet,
+        doc_scores.retain(|doc_idx, _| {
+            !excluded_docs.contains(doc_idx)
+                && required_docs.iter().all(|docs| docs.contains(doc_idx))
+        });
+
+        // Sort by score descending, ties in index order, and limit results
+        let mut scored: Vec<(usize, (f64, Vec<String>))> =
+            doc_scores.into_iter().filter(|(_, (score, _))| *score >= options.threshold).collect();
+        scored.sort_by(|(a_idx, (a, _)), (b_idx, (b, _))| {
+            b.partial_cmp(a).unwrap_or(std::cmp::Ordering::Equal).then(a_idx.cmp(b_idx))
+        });
+
+        let mut results = Vec::new();
+        let mut kept_words: Vec<HashSet<String>> = Vec::new();
+        for (doc_idx, (score, matches)) in scored {
+            if results.len() >= options.limit {
+                break;
+            }
+            let doc = &self.documents[doc_idx];
+            let snippet = self.generate_snippet(&doc.body, &matches, 150);
+
+            // Results arrive best-first, so the kept copy is the highest-scoring one.
+            if let Some(max_similarity) = options.dedupe {
+                let words = self.result_words(&doc.title, &snippet);
+                if kept_words.iter().any(|kept| jaccard(kept, &words) > max_similarity) {
+                    continue;
                 }
-            })
-            .collect();
-
-        // Sort by score descending
-        results.sort_by(|a, b| b.score.partial_cmp(&a.score).unwrap_or(std::cmp::Ordering::Equal));
-
-        // Limit results
-        results.truncate(options.limit);
+                kept_words.push(words);
+            }
 
+            results.push(SearchResult {
+                id: doc.id.clone(),
+                title: doc.title.clone(),
+                url: doc.url.clone(),
+                score,
+                field_matches: self.field_matches(doc, &matches),
+                matches,
+                snippet,
+            });
+        }
         results
     }
 
+    /// Returns the distinct words of a result's title and snippet, used to
+    /// compare results for de-duplication.
+    fn result_words(&self, title: &str, snippet: &str) -> HashSet<String> {
+        let text = format!("{title} {snippet}");
+        tokenize(&normalize(&text, self.fold_diacritics), self.token_filter()).into_iter().collect()
+    }
+
     /// Computes IDF (Inverse Document Frequency).
     #[allow(clippy::cast_precision_loss)]
     fn compute_idf(&self, df: usize) -> f64 {
@@ -143,6 +301,45 @@ impl SearchIndex {
         ((n - df + 0.5) / (df + 0.5)).ln_1p()
     }
 
+    /// Returns the postings of `term`, limited to `field` when given.
+    fn field_postings<'a>(
+        &'a self,
+        term: &str,
+        field: Option<Field>,
+    ) -> impl Iterator<Item = &'a Posting> + 'a {
+        self.index
+            .get(term)
+            .into_iter()
+            .flatten()
+            .filter(move |posting| field.is_none_or(|field| posting.field_tf[field.index()] > 0))
+    }
+
+    /// Returns each `(field, term)` pair where a matched term occurs in the
+    /// document, ordered by field and then by term.
+    fn field_matches(&self, doc: &SearchDocument, terms: &[String]) -> Vec<(Field, String)> {
+        let fold = self.fold_diacritics;
+        let filter = self.token_filter();
+        let fields = [
+            (Field::Title, std::slice::from_ref(&doc.title)),
+            (Field::Heading, doc.headings.as_slice()),
+            (Field::Body, std::slice::from_ref(&doc.body)),
+            (Field::Code, doc.code.as_slice()),
+        ];
+
+        let mut matches = Vec::new();
+        for (field, texts) in fields {
+            let tokens: HashSet<String> =
+                texts.iter().flat_map(|text| tokenize(&normalize(text, fold), filter)).collect();
+            matches.extend(
+                terms
+                    .iter()
+                    .filter(|term| tokens.contains(*term))
+                    .map(|term| (field, term.clone())),
+            );
+        }
+        matches
+    }
+
     /// Finds terms matching the query term (exact or prefix).
     fn find_matching_terms(&self, token: &str, prefix_match: bool) -> Vec<String> {
         if prefix_match && token.len() >= 2 {
@@ -209,6 +406,16 @@ impl SearchIndex {
     }
 }
 
+/// Jaccard similarity of two word sets: shared words over all words.
+#[allow(clippy::cast_precision_loss)]
+fn jaccard(a: &HashSet<String>, b: &HashSet<String>) -> f64 {
+    let union = a.union(b).count();
+    if union == 0 {
+        return 1.0;
+    }
+    a.intersection(b).count() as f64 / union as f64
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -287,4 +494,187 @@ mod tests {
         let results = index.search("test", &options);
         assert_eq!(results.len(), 5);
     }
+
+    #[test]
+    fn test_search_reports_field_matches() {
+        let mut builder = SearchIndexBuilder::new();
+        builder.add_simple("1", "Routing", "/routing", "Nested routing with layouts.");
+        let index = builder.build();
+        let options = SearchOptions { prefix: false, ..Default::default() };
+
+        let results = index.search("routing layouts", &options);
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].matches, vec!["routing", "layouts"]);
+        assert_eq!(
+            results[0].field_matches,
+            [
+                (Field::Title, "routing".to_string()),
+                (Field::Body, "routing".to_string()),
+                (Field::Body, "layouts".to_string()),
+            ]
+        );
+    }
+
+    fn operator_index() -> SearchIndex {
+        let mut builder = SearchIndexBuilder::new();
+        builder.add_simple("1", "Introduction", "/intro", "Rust basics and the guide overview.");
+        builder.add_simple("2", "Install", "/install", "Install rust with rustup.");
+        builder.add_simple("3", "Plugins", "/plugins", "An introduction to writing plugins.");
+        builder.build()
+    }
+
+    fn search_ids(index: &SearchIndex, query: &str) -> Vec<String> {
+        let options = SearchOptions { prefix: false, ..Default::default() };
+        let mut ids: Vec<String> =
+            index.search(query, &options).into_iter().map(|r| r.id).collect();
+        ids.sort();
+        ids
+    }
+
+    #[test]
+    fn test_search_excluded_term() {
+        let index = operator_index();
+        assert_eq!(search_ids(&index, "rust"), vec!["1", "2"]);
+        assert_eq!(search_ids(&index, "rust -rustup"), vec!["1"]);
+        assert!(search_ids(&index, "-rust").is_empty());
+    }
+
+    #[test]
+    fn test_search_required_term() {
+        let index = operator_index();
+        assert_eq!(search_ids(&index, "guide install"), vec!["1", "2"]);
+        assert_eq!(search_ids(&index, "guide +install"), vec!["2"]);
+    }
+
+    #[test]
+    fn test_search_field_scope() {
+        let index = operator_index();
+        assert_eq!(search_ids(&index, "introduction"), vec!["1", "3"]);
+        assert_eq!(search_ids(&index, "title:introduction"), vec!["1"]);
+        assert_eq!(search_ids(&index, "body:introduction"), vec!["3"]);
+    }
+
+    #[test]
+    fn test_search_field_scope_term_in_title_and_body() {
+        let mut builder = SearchIndexBuilder::new();
+        builder.add_simple("both", "Routing", "/both", "Routing maps URLs to pages.");
+        builder.add_simple("body", "Pages", "/body", "Routing is file based.");
+        builder.add_simple("title", "Routing", "/title", "Maps URLs to pages.");
+        let index = builder.build();
+
+        assert_eq!(search_ids(&index, "body:routing"), vec!["body", "both"]);
+        assert_eq!(search_ids(&index, "title:routing"), vec!["both", "title"]);
+        assert_eq!(search_ids(&index, "pages -body:routing"), vec!["title"]);
+        assert_eq!(search_ids(&index, "pages -title:routing"), vec!["body"]);
+
+        let results = index.search("routing", &SearchOptions::default());
+        let both = results.iter().find(|result| result.id == "both").unwrap();
+        assert!(both.field_matches.contains(&(Field::Title, "routing".to_string())));
+        assert!(both.field_matches.contains(&(Field::Body, "routing".to_string())));
+    }
+
+    fn sectioned_document(id: &str, installation: &str, usage: &str) -> SearchDocument {
+        let body = format!("{installation} {usage}");
+        SearchDocument {
+            id: id.to_string(),
+            title: "Guide".to_string(),
+            url: format!("/{id}"),
+            headings: vec!["Installation".to_string(), "Usage".to_string()],
+            heading_offsets: vec![0, installation.len() + 1],
+            body,
+            code: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_search_boosts_matches_under_matching_heading() {
+        let mut builder = SearchIndexBuilder::new();
+        builder.add_document(sectioned_document("elsewhere", "Call the api.", "Run the setup."));
+        builder.add_document(sectioned_document("section", "Run the setup.", "Call the api."));
+        let index = builder.build();
+        let options = SearchOptions { prefix: false, ..Default::default() };
+
+        let results = index.search("installation setup", &options);
+        assert_eq!(results.len(), 2);
+        assert_eq!(results[0].id, "section");
+        assert!(results[0].score > results[1].score);
+
+        // Without a matching heading both body matches score the same.
+        let results = index.search("setup", &options);
+        assert!((results[0].score - results[1].score).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_search_ignores_terms_the_index_filters() {
+        let mut builder = SearchIndexBuilder::new();
+        builder.min_term_length(3).index_numbers(false);
+        builder.add_simple("1", "Release", "/release", "Go adds range over int in 2024.");
+        let index = builder.build();
+        let options = SearchOptions { prefix: false, ..Default::default() };
+
+        let results = index.search("+2024 +go +range", &options);
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].matches, vec!["range"]);
+    }
+
+    fn accented_index(fold: bool) -> SearchIndex {
+        let mut builder = SearchIndexBuilder::new();
+        builder.fold_diacritics(fold);
+        builder.add_simple("1", "Café", "/cafe", "A naïve implementation.");
+        builder.add_simple("2", "Naive Bayes", "/naive", "Classifier for the cafe menu.");
+        builder.build()
+    }
+
+    #[test]
+    fn test_search_fold_diacritics() {
+        let index = accented_index(true);
+        let options = SearchOptions { prefix: false, ..Default::default() };
+
+        for query in ["cafe", "café", "naive", "naïve"] {
+            let results = index.search(query, &options);
+            assert_eq!(results.len(), 2, "query {query}");
+        }
+    }
+
+    #[test]
+    fn test_search_without_fold_diacritics() {
+        let index = accented_index(false);
+        let options = SearchOptions { prefix: false, ..Default::default() };
+
+        let ids = |query: &str| {
+            index.search(query, &options).into_iter().map(|r| r.id).collect::<Vec<_>>()
+        };
+        assert_eq!(ids("café"), vec!["1"]);
+        assert_eq!(ids("cafe"), vec!["2"]);
+        assert_eq!(ids("naïve"), vec!["1"]);
+        assert_eq!(ids("naive"), vec!["2"]);
+    }
+
+    #[test]
+    fn test_search_dedupe_near_duplicates() {
+        let mut builder = SearchIndexBuilder::new();
+        builder.add_simple(
+            "v1",
+            "Configuration",
+            "/v1/config",
+            "Set the output directory with the outDir option in your config file.",
+        );
+        builder.add_simple(
+            "v2",
+            "Configuration",
+            "/v2/config",
+            "Set the output directory with the outDir option in your config file!",
+        );
+        builder.add_simple("api", "API", "/api", "The config object exposes every option.");
+        let index = builder.build();
+
+        let options = SearchOptions { prefix: false, ..Default::default() };
+        assert_eq!(index.search("config", &options).len(), 3);
+
+        let options = SearchOptions { prefix: false, dedupe: Some(0.8), ..Default::default() };
+        let results = index.search("config", &options);
+        let ids: Vec<&str> = results.iter().map(|r| r.id.as_str()).collect();
+        // v1 and v2 tie on score; the one indexed first is kept.
+        assert_eq!(ids, ["api", "v1"]);
+    }
 }
@@ -1,45 +1,72 @@
 //! Text tokenization for search indexing.
 
+use std::borrow::Cow;
+
+use unicode_normalization::UnicodeNormalization;
+
+/// Index-time token filtering settings.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TokenFilter {
+    /// Minimum token length in characters. Single CJK characters are always kept.
+    pub min_term_length: usize,
+    /// Whether purely numeric tokens (`2024`, `42`) are kept.
+    pub index_numbers: bool,
+}
+
+impl Default for TokenFilter {
+    fn default() -> Self {
+        Self { min_term_length: 2, index_numbers: true }
+    }
+}
+
+impl TokenFilter {
+    /// Returns true if a lowercased token passes the length and number filters.
+    pub fn accepts(self, token: &str) -> bool {
+        let mut chars = token.chars();
+        if let (Some(c), None) = (chars.next(), chars.next()) {
+            if is_cjk_char(c) {
+                return true;
+            }
+        }
+        token.chars().count() >= self.min_term_length
+            && (self.index_numbers || !token.chars().all(char::is_numeric))
+    }
+}
+
 /// Tokenizes text into searchable terms.
 ///
 /// This tokenizer:
 /// - Converts to lowercase
 /// - Splits on whitespace and punctuation
-/// - Filters out stopwords
+/// - Filters out stopwords and tokens rejected by `filter`
 /// - Handles CJK characters (Japanese, Chinese, Korean)
-pub fn tokenize(text: &str) -> Vec<String> {
+pub fn tokenize(text: &str, filter: TokenFilter) -> Vec<String> {
     let mut tokens = Vec::new();
     let mut current_token = String::new();
+    let flush = |current_token: &mut String, tokens: &mut Vec<String>| {
+        if !current_token.is_empty() {
+            let token = current_token.to_lowercase();
+            if !is_stopword(&token) && filter.accepts(&token) {
+                tokens.push(token);
+            }
+            current_token.clear();
+        }
+    };
 
     for c in text.chars() {
         if is_cjk_char(c) {
             // CJK characters are treated as individual tokens
-            if !current_token.is_empty() {
-                let token = current_token.to_lowercase();
-                if !is_stopword(&token) && token.len() >= 2 {
-                    tokens.push(token);
-                }
-                current_token.clear();
-            }
+            flush(&mut current_token, &mut tokens);
             tokens.push(c.to_string());
         } else if c.is_alphanumeric() || c == '_' {
             current_token.push(c);
-        } else if !current_token.is_empty() {
-            let token = current_token.to_lowercase();
-            if !is_stopword(&token) && token.len() >= 2 {
-                tokens.push(token);
-            }
-            current_token.clear();
+        } else {
+            flush(&mut current_token, &mut tokens);
         }
     }
 
     // Don't forget the last token
-    if !current_token.is_empty() {
-        let token = current_token.to_lowercase();
-        if !is_stopword(&token) && token.len() >= 2 {
-            tokens.push(token);
-        }
-    }
+    flush(&mut current_token, &mut tokens);
 
     tokens
 }
@@ -71,6 +98,38 @@ pub fn tokenize_query(text: &str) -> Vec<String> {
     tokens
 }
 
+/// Folds diacritics so that `café` and `cafe` produce the same tokens.
+///
+/// Applies NFKD normalization, drops combining diacritical marks and
+/// recomposes the remainder (so kana voicing marks survive). Casing is left
+/// to the tokenizer.
+pub fn fold_diacritics(text: &str) -> Cow<'_, str> {
+    if text.is_ascii() {
+        return Cow::Borrowed(text);
+    }
+    Cow::Owned(text.nfkd().filter(|&c| !is_diacritic_mark(c)).nfc().collect())
+}
+
+/// Returns the text to tokenize, folding diacritics when enabled.
+pub fn normalize(text: &str, fold: bool) -> Cow<'_, str> {
+    if fold {
+        fold_diacritics(text)
+    } else {
+        Cow::Borrowed(text)
+    }
+}
+
+/// Checks if a character is a combining diacritical mark.
+fn is_diacritic_mark(c: char) -> bool {
+    matches!(c,
+        '\u{0300}'..='\u{036F}' |   // Combining Diacritical Marks
+        '\u{1AB0}'..='\u{1AFF}' |   // Combining Diacritical Marks Extended
+        '\u{1DC0}'..='\u{1DFF}' |   // Combining Diacritical Marks Supplement
+        '\u{20D0}'..='\u{20FF}' |   // Combining Diacritical Marks for Symbols
+        '\u{FE20}'..='\u{FE2F}'     // Combining Half Marks
+    )
+}
+
 /// Checks if a character is a CJK character.
 fn is_cjk_char(c: char) -> bool {
     matches!(c,
@@ -158,25 +217,49 @@ mod tests {
 
     #[test]
     fn test_tokenize_english() {
-        let tokens = tokenize("Hello, World! This is a test.");
+        let tokens = tokenize("Hello, World! This is a test.", TokenFilter::default());
         assert_eq!(tokens, vec!["hello", "world", "test"]);
     }
 
     #[test]
     fn test_tokenize_japanese() {
-        let tokens = tokenize("これはテストです");
+        let tokens = tokenize("これはテストです", TokenFilter::default());
         assert_eq!(tokens, vec!["こ", "れ", "は", "テ", "ス", "ト", "で", "す"]);
     }
 
     #[test]
     fn test_tokenize_mixed() {
-        let tokens = tokenize("Rustで検索エンジン");
+        let tokens = tokenize("Rustで検索エンジン", TokenFilter::default());
         assert_eq!(tokens, vec!["rust", "で", "検", "索", "エ", "ン", "ジ", "ン"]);
     }
 
+    #[test]
+    fn test_fold_diacritics() {
+        assert_eq!(
+            tokenize(&normalize("Café naïve", true), TokenFilter::default()),
+            vec!["cafe", "naive"]
+        );
+        assert_eq!(
+            tokenize(&normalize("Café naïve", false), TokenFilter::default()),
+            vec!["café", "naïve"]
+        );
+        assert_eq!(fold_diacritics("テストです"), "テストです");
+    }
+
+    #[test]
+    fn test_token_filter() {
+        let text = "Go v2 in 2024: 日本 x";
+        assert_eq!(tokenize(text, TokenFilter::default()), vec!["go", "v2", "2024", "日", "本"]);
+
+        let filter = TokenFilter { min_term_length: 3, index_numbers: false };
+        assert_eq!(tokenize(text, filter), vec!["日", "本"]);
+        assert!(filter.accepts("rust"));
+        assert!(!filter.accepts("2024"));
+    }
+
     #[test]
     fn test_tokenize_code() {
-        let tokens = tokenize("function_name variable_name");
+        let tokens = tokenize("function_name variable_name", TokenFilter::default());
         assert_eq!(tokens, vec!["function_name", "variable_name"]);
     }
 }
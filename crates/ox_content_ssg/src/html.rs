@@ -1,7 +1,7 @@
 //! HTML page generation for SSG.
 
 use askama::Template;
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Deserializer, Serialize};
 
 // =============================================================================
 // Theme Configuration Types
@@ -120,6 +120,17 @@ pub struct ThemeEmbed {
     pub footer: Option<String>,
 }
 
+/// Not-found (404) page configuration.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct ThemeNotFound {
+    /// Page heading (default: "Page not found").
+    pub title: Option<String>,
+    /// Message shown below the heading.
+    pub message: Option<String>,
+    /// Text of the link back to the home page (default: "Back to home").
+    pub home_text: Option<String>,
+}
+
 /// Complete theme configuration.
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct ThemeConfig {
@@ -146,6 +157,25 @@ pub struct ThemeConfig {
     pub css: Option<String>,
     /// Additional custom JavaScript.
     pub js: Option<String>,
+    /// Whether to include print-optimized styles (default: true).
+    pub print: Option<bool>,
+    /// Not-found (404) page configuration.
+    pub not_found: Option<ThemeNotFound>,
+    /// Where the page outline renders: "right", "left", or "none" (default).
+    pub outline_position: Option<String>,
+    /// Inclusive range of heading depths shown in the outline (default: `(2, 3)`).
+    pub outline_level: Option<(u8, u8)>,
+    /// Favicon URL (relative URLs are prefixed with `base`, like the logo).
+    pub favicon: Option<String>,
+    /// `theme-color` for light mode (e.g. `"#ffffff"`).
+    pub theme_color_light: Option<String>,
+    /// `theme-color` for dark mode.
+    pub theme_color_dark: Option<String>,
+    /// Emit schema.org JSON-LD structured data, such as a `BreadcrumbList`
+    /// for the page's navigation trail, in `<head>` (default: false).
+    ///
+    /// Requires [`SsgConfig::site_url`], since its URLs must be absolute.
+    pub json_ld: Option<bool>,
 }
 
 // =============================================================================
@@ -243,8 +273,11 @@ pub struct NavItem {
     pub title: String,
     /// URL path.
     pub path: String,
-    /// Full href.
+    /// Full href. `http(s)` hrefs are marked as external links.
     pub href: String,
+    /// Optional icon: an Iconify name (`prefix:name`), an image URL or path, or text/emoji.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub icon: Option<String>,
 }
 
 /// Navigation group for SSG.
@@ -268,7 +301,7 @@ pub struct TocEntry {
 }
 
 /// Page data for SSG.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct PageData {
     /// Page title.
     pub title: String,
@@ -282,15 +315,80 @@ pub struct PageData {
     pub path: String,
     /// Entry page configuration (if layout: entry).
     pub entry_page: Option<EntryPageConfig>,
+    /// Page-specific content to inject into `<head>`, after the theme embed head.
+    ///
+    /// This is raw HTML and is inserted without escaping. Only pass trusted
+    /// content (e.g. from your own frontmatter), never user-supplied input.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub head: Option<String>,
+    /// Page author, emitted as `<meta name="author">` and `article:author`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub author: Option<String>,
+    /// Publication date (ISO 8601), emitted as `article:published_time`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub published: Option<String>,
+    /// Last modification date (ISO 8601), emitted as `article:modified_time`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub modified: Option<String>,
+    /// Page chrome around the content; defaults to [`PageLayout::Doc`].
+    /// Unknown layout names deserialize as the default.
+    #[serde(
+        default,
+        deserialize_with = "deserialize_layout",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub layout: Option<PageLayout>,
+    /// Translations of this page as `(locale, href)` pairs, including the page
+    /// itself, emitted as `<link rel="alternate" hreflang>` tags. The entry for
+    /// [`SsgConfig::default_locale`] is also advertised as `x-default`.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub alternates: Vec<(String, String)>,
+}
+
+/// Page chrome around the content.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum PageLayout {
+    /// Sidebar and outline.
+    #[default]
+    Doc,
+    /// Outline only, without the sidebar.
+    Page,
+    /// Neither sidebar nor outline, for full-width content.
+    Full,
+}
+
+impl PageLayout {
+    /// Parses a layout name: `"doc"`, `"page"` or `"full"`.
+    ///
+    /// Returns `None` for other names; callers fall back to the default layout.
+    pub fn parse(name: &str) -> Option<Self> {
+        match name {
+            "doc" => Some(Self::Doc),
+            "page" => Some(Self::Page),
+            "full" => Some(Self::Full),
+            _ => None,
+        }
+    }
+}
+
+/// Reads a layout name, treating unknown names as the default layout.
+fn deserialize_layout<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Option<PageLayout>, D::Error> {
+    Ok(Option::<String>::deserialize(deserializer)?.and_then(|name| PageLayout::parse(&name)))
 }
 
 /// SSG configuration.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct SsgConfig {
     /// Site name.
     pub site_name: String,
     /// Base URL path.
     pub base: String,
+    /// Site origin (e.g. `"https://example.com"`), used where absolute URLs are required.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub site_url: Option<String>,
     /// OG image URL.
     pub og_image: Option<String>,
     /// Theme configuration.
@@ -301,6 +399,67 @@ pub struct SsgConfig {
     /// All available locales (for generating locale switcher and hreflang tags).
     #[serde(skip_serializing_if = "Option::is_none")]
     pub available_locales: Option<Vec<LocaleInfo>>,
+    /// Site default locale; its entry in [`PageData::alternates`] is advertised
+    /// as `x-default`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub default_locale: Option<String>,
+    /// Translatable UI chrome strings (English by default).
+    #[serde(default)]
+    pub ui_strings: UiStrings,
+}
+
+/// UI chrome strings rendered by the page template.
+///
+/// Missing fields fall back to the English defaults when deserialized.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct UiStrings {
+    /// Skip link text (default: "Skip to content").
+    pub skip_to_content: String,
+    /// Label of the header menu toggle (default: "Toggle menu").
+    pub toggle_menu: String,
+    /// Search button text and label (default: "Search").
+    pub search: String,
+    /// Search input placeholder (default: "Search documentation...").
+    pub search_placeholder: String,
+    /// Search footer hint for arrow keys (default: "to navigate").
+    pub search_navigate: String,
+    /// Search footer hint for Enter (default: "to select").
+    pub search_select: String,
+    /// Search footer hint for Esc (default: "to close").
+    pub search_close: String,
+    /// Label of the header theme toggle (default: "Toggle theme").
+    pub toggle_theme: String,
+    /// Mobile footer menu label (default: "Menu").
+    pub menu: String,
+    /// Mobile footer theme label (default: "Theme").
+    pub theme: String,
+    /// Outline heading (default: "On this page").
+    pub on_this_page: String,
+    /// Code block copy button label (default: "Copy").
+    pub copy_code: String,
+    /// Copy button label after copying (default: "Copied").
+    pub copied: String,
+}
+
+impl Default for UiStrings {
+    fn default() -> Self {
+        Self {
+            skip_to_content: "Skip to content".to_string(),
+            toggle_menu: "Toggle menu".to_string(),
+            search: "Search".to_string(),
+            search_placeholder: "Search documentation...".to_string(),
+            search_navigate: "to navigate".to_string(),
+            search_select: "to select".to_string(),
+            search_close: "to close".to_string(),
+            toggle_theme: "Toggle theme".to_string(),
+            menu: "Menu".to_string(),
+            theme: "Theme".to_string(),
+            on_this_page: "On this page".to_string(),
+            copy_code: "Copy".to_string(),
+            copied: "Copied".to_string(),
+        }
+    }
 }
 
 /// Locale information for the locale switcher.
@@ -322,8 +481,22 @@ pub struct LocaleInfo {
 #[derive(Template)]
 #[template(path = "nav.html")]
 struct NavTemplate<'a> {
-    nav_groups: &'a [NavGroup],
-    current_path: &'a str,
+    nav_groups: &'a [NavGroupView<'a>],
+}
+
+/// Navigation group for nav template.
+struct NavGroupView<'a> {
+    title: &'a str,
+    items: Vec<NavItemView<'a>>,
+}
+
+/// Navigation link for nav template.
+struct NavItemView<'a> {
+    title: &'a str,
+    href: &'a str,
+    active: bool,
+    external: bool,
+    icon_html: Option<String>,
 }
 
 /// Social links template (desktop header).
@@ -352,6 +525,24 @@ struct FooterTemplate<'a> {
     copyright: Option<&'a str>,
 }
 
+/// Page outline (table of contents) template.
+#[derive(Template)]
+#[template(path = "outline.html")]
+struct OutlineTemplate<'a> {
+    title: &'a str,
+    entries: &'a [&'a TocEntry],
+}
+
+/// Not-found page content template.
+#[derive(Template)]
+#[template(path = "not_found.html")]
+struct NotFoundTemplate<'a> {
+    title: &'a str,
+    message: &'a str,
+    home_text: &'a str,
+    base: &'a str,
+}
+
 /// Hero action for entry template.
 pub struct HeroActionView {
     pub href: String,
@@ -391,12 +582,25 @@ struct EntryTemplate<'a> {
 #[derive(Template)]
 #[template(path = "page.html")]
 struct PageTemplate<'a> {
+    lang: &'a str,
+    dir: &'a str,
+    ui: &'a UiStrings,
     site_name: &'a str,
     document_title: &'a str,
     description: Option<&'a str>,
     og_image: Option<&'a str>,
+    author: Option<&'a str>,
+    published: Option<&'a str>,
+    modified: Option<&'a str>,
+    favicon: Option<&'a str>,
+    theme_color_light: Option<&'a str>,
+    theme_color_dark: Option<&'a str>,
     css: &'a str,
     embed_head: &'a str,
+    alternates: &'a [(String, String)],
+    x_default_href: Option<&'a str>,
+    page_head: &'a str,
+    json_ld: &'a str,
     body_class: &'a str,
     embed_header_before: &'a str,
     embed_header_after: &'a str,
@@ -409,8 +613,11 @@ struct PageTemplate<'a> {
     logo_height: u32,
     social_links: &'a str,
     is_entry_page: bool,
+    show_sidebar: bool,
     embed_sidebar_before: &'a str,
     navigation: &'a str,
+    outline: &'a str,
+    outline_left: bool,
     embed_sidebar_after: &'a str,
     embed_content_before: &'a str,
     main_content: &'a str,
@@ -427,6 +634,9 @@ const SSG_CSS: &str = include_str!("ssg.css");
 /// CSS styles for Entry pages (hero, features).
 const ENTRY_CSS: &str = include_str!("entry.css");
 
+/// CSS styles for custom containers (`:::tip`, `:::details`, ...).
+const CONTAINERS_CSS: &str = include_str!("containers.css");
+
 /// CSS styles for Tabs plugin.
 const TABS_CSS: &str = include_str!("plugins/tabs.css");
 
@@ -445,9 +655,15 @@ const MERMAID_CSS: &str = include_str!("plugins/mermaid.css");
 /// CSS styles for Island plugin.
 const ISLAND_CSS: &str = include_str!("plugins/island.css");
 
+/// CSS styles for printing.
+const PRINT_CSS: &str = include_str!("print.css");
+
 /// JavaScript for SSG pages.
 const SSG_JS: &str = include_str!("ssg.js");
 
+/// Scroll-spy highlighting the outline link of the current section.
+const SCROLL_SPY_JS: &str = include_str!("scroll-spy.js");
+
 /// Generates CSS variable overrides for theme colors.
 fn generate_theme_css(theme: &ThemeConfig) -> String {
     let mut css = String::new();
@@ -586,6 +802,23 @@ fn generate_footer_html(theme: &ThemeConfig) -> String {
     template.render().unwrap_or_default()
 }
 
+/// Generates the page outline HTML, keeping only headings within the
+/// configured depth range.
+fn generate_outline_html(
+    toc: &[TocEntry],
+    (min_depth, max_depth): (u8, u8),
+    title: &str,
+) -> String {
+    let entries: Vec<&TocEntry> =
+        toc.iter().filter(|entry| (min_depth..=max_depth).contains(&entry.depth)).collect();
+    if entries.is_empty() {
+        return String::new();
+    }
+
+    let template = OutlineTemplate { title, entries: &entries };
+    template.render().unwrap_or_default()
+}
+
 /// Converts a `.md` link to an HTML path for entry page frontmatter links.
 /// Entry pages are always `index.md`, so relative links like `getting-started.md`
 /// become `{base}getting-started/index.html`.
@@ -749,7 +982,7 @@ fn page_content_contains_any(content: &str, needles: &[&str]) -> bool {
 /// This function creates a full HTML document with navigation sidebar,
 /// content area, table of contents, search functionality, and theme toggle.
 pub fn generate_html(page_data: &PageData, nav_groups: &[NavGroup], config: &SsgConfig) -> String {
-    let nav_html = generate_nav_html(nav_groups, &page_data.path);
+    let nav_html = generate_nav_html(nav_groups, &page_data.path, &config.base);
 
     // Theme configuration
     let theme = config.theme.as_ref();
@@ -771,6 +1004,9 @@ pub fn generate_html(page_data: &PageData, nav_groups: &[NavGroup], config: &Ssg
     if is_entry_page {
         css_sections.push(wrap_css_section("entry", ENTRY_CSS));
     }
+    if page_content_contains_any(&page_data.content, &["custom-block"]) {
+        css_sections.push(wrap_css_section("containers", CONTAINERS_CSS));
+    }
     if page_content_contains_any(&page_data.content, &["ox-tabs", "ox-tab-panel"]) {
         css_sections.push(wrap_css_section("plugin-tabs", TABS_CSS));
     }
@@ -792,6 +1028,9 @@ pub fn generate_html(page_data: &PageData, nav_groups: &[NavGroup], config: &Ssg
     if has_footer {
         css_sections.push(wrap_css_section("footer", footer_css));
     }
+    if theme.and_then(|t| t.print).unwrap_or(true) {
+        css_sections.push(wrap_css_section("print", PRINT_CSS));
+    }
     if !theme_css.is_empty() {
         css_sections.push(wrap_css_section("theme", &theme_css));
     }
@@ -839,10 +1078,7 @@ pub fn generate_html(page_data: &PageData, nav_groups: &[NavGroup], config: &Ssg
     let logo_light_src =
         header_config.and_then(|h| h.logo_light.as_deref()).map(resolve_theme_asset);
     let logo_dark_src = header_config.and_then(|h| h.logo_dark.as_deref()).map(resolve_theme_asset);
-
-    // Custom JS
-    let custom_js = theme.and_then(|t| t.js.as_deref()).unwrap_or("");
-    let all_js = format!("{}\n{}", SSG_JS.replace("{{base}}", &config.base), custom_js);
+    let favicon = theme.and_then(|t| t.favicon.as_deref()).map(resolve_theme_asset);
 
     // Social links
     let social_links_html = theme
@@ -881,21 +1117,81 @@ pub fn generate_html(page_data: &PageData, nav_groups: &[NavGroup], config: &Ssg
     {
         body_classes.push("entry-page--subtle".to_string());
     }
+
+    // Page layout: "page" drops the sidebar, "full" also drops the outline
+    let layout = page_data.layout.unwrap_or_default();
+    let show_sidebar = layout == PageLayout::Doc;
+    let show_outline = layout != PageLayout::Full;
+    if !show_sidebar {
+        let class = if show_outline { "page-layout--page" } else { "page-layout--full" };
+        body_classes.push(class.to_string());
+    }
     let body_class = body_classes.join(" ");
 
+    // Page outline
+    let outline_position = theme.and_then(|t| t.outline_position.as_deref()).unwrap_or("none");
+    let outline_html = if is_entry_page || outline_position == "none" || !show_outline {
+        String::new()
+    } else {
+        let level = theme.and_then(|t| t.outline_level).unwrap_or((2, 3));
+        generate_outline_html(&page_data.toc, level, &config.ui_strings.on_this_page)
+    };
+    let outline_left = outline_position == "left" && show_sidebar;
+
+    // Custom JS (the scroll-spy only runs when there is an outline to highlight)
+    let custom_js = theme.and_then(|t| t.js.as_deref()).unwrap_or("");
+    let scroll_spy_js = if outline_html.is_empty() { "" } else { SCROLL_SPY_JS };
+    let all_js =
+        format!("{}\n{}\n{}", SSG_JS.replace("{{base}}", &config.base), scroll_spy_js, custom_js);
+
     let document_title = if page_data.title.trim() == config.site_name.trim() {
         config.site_name.clone()
     } else {
         format!("{} - {}", page_data.title, config.site_name)
     };
 
+    // Document language and direction
+    let lang = config.locale.as_deref().unwrap_or("en");
+    let dir = config
+        .available_locales
+        .as_ref()
+        .and_then(|locales| locales.iter().find(|locale| locale.code == lang))
+        .map_or("ltr", |locale| locale.dir.as_str());
+
+    let json_ld = match (theme.and_then(|t| t.json_ld), config.site_url.as_deref()) {
+        (Some(true), Some(site_url)) => breadcrumb_json_ld(
+            &breadcrumbs(nav_groups, &page_data.path, &config.site_name, &config.base),
+            site_url,
+        ),
+        _ => String::new(),
+    };
+
     let template = PageTemplate {
+        lang,
+        dir,
+        ui: &config.ui_strings,
         site_name: &config.site_name,
         document_title: &document_title,
         description: page_data.description.as_deref(),
         og_image: config.og_image.as_deref(),
+        author: page_data.author.as_deref(),
+        published: page_data.published.as_deref(),
+        modified: page_data.modified.as_deref(),
+        favicon: favicon.as_deref(),
+        theme_color_light: theme.and_then(|t| t.theme_color_light.as_deref()),
+        theme_color_dark: theme.and_then(|t| t.theme_color_dark.as_deref()),
         css: &all_css,
         embed_head,
+        alternates: &page_data.alternates,
+        x_default_href: config.default_locale.as_deref().and_then(|default| {
+            page_data
+                .alternates
+                .iter()
+                .find(|(locale, _)| locale == default)
+                .map(|(_, href)| href.as_str())
+        }),
+        page_head: page_data.head.as_deref().unwrap_or(""),
+        json_ld: &json_ld,
         body_class: &body_class,
         embed_header_before,
         embed_header_after,
@@ -908,8 +1204,11 @@ pub fn generate_html(page_data: &PageData, nav_groups: &[NavGroup], config: &Ssg
         logo_height,
         social_links: &social_links_html,
         is_entry_page,
+        show_sidebar,
         embed_sidebar_before,
         navigation: &nav_html,
+        outline: &outline_html,
+        outline_left,
         embed_sidebar_after,
         embed_content_before,
         main_content: &main_content,
@@ -923,6 +1222,29 @@ pub fn generate_html(page_data: &PageData, nav_groups: &[NavGroup], config: &Ssg
     template.render().unwrap_or_default()
 }
 
+/// Generates a themed not-found (404) page.
+///
+/// The page reuses the regular site chrome (header, navigation, search) and
+/// links back to `{base}index.html`. Static hosts serve it as `404.html`.
+pub fn generate_404(nav_groups: &[NavGroup], config: &SsgConfig) -> String {
+    let not_found = config.theme.as_ref().and_then(|t| t.not_found.as_ref());
+    let title = not_found.and_then(|n| n.title.as_deref()).unwrap_or("Page not found");
+    let message = not_found
+        .and_then(|n| n.message.as_deref())
+        .unwrap_or("The page you are looking for does not exist or has been moved.");
+    let home_text = not_found.and_then(|n| n.home_text.as_deref()).unwrap_or("Back to home");
+
+    let template = NotFoundTemplate { title, message, home_text, base: &config.base };
+    let page_data = PageData {
+        title: title.to_string(),
+        content: template.render().unwrap_or_default(),
+        path: "404".to_string(),
+        ..Default::default()
+    };
+
+    generate_html(&page_data, nav_groups, config)
+}
+
 /// Renders an icon based on its format.
 ///
 /// Supported formats:
@@ -932,13 +1254,17 @@ pub fn generate_html(page_data: &PageData, nav_groups: &[NavGroup], config: &Ssg
 /// - URL (http://, https://) - Direct image URL
 /// - Path ending with .svg, .png - Local image path
 /// - Other - Treated as emoji/text
+///
+/// The result is inserted unescaped into templates, so every value taken
+/// from `icon` is HTML-escaped here.
 fn render_icon(icon: &str, base: &str) -> String {
     // Check for Iconify format (prefix:name)
     if let Some((prefix, name)) = icon.split_once(':') {
         // Validate it looks like an icon reference (not a URL scheme)
         if !prefix.contains('/') && !name.starts_with("//") {
             // Convert to Iconify CDN URL
-            let iconify_url = format!("https://api.iconify.design/{prefix}/{name}.svg");
+            let iconify_url =
+                escape_html(&format!("https://api.iconify.design/{prefix}/{name}.svg"));
             // Use span with mask-image for color control
             return format!(
                 "<span class=\"iconify-icon\" style=\"-webkit-mask-image: url('{iconify_url}'); mask-image: url('{iconify_url}')\"></span>"
@@ -948,18 +1274,34 @@ fn render_icon(icon: &str, base: &str) -> String {
 
     // Check if it's an image URL
     if icon.starts_with("http://") || icon.starts_with("https://") {
-        return format!("<img src=\"{icon}\" alt=\"\" />");
+        return format!("<img src=\"{}\" alt=\"\" />", escape_html(icon));
     }
 
     // Check if it's a local image path
     if icon.ends_with(".svg") || icon.ends_with(".png") {
         let icon_src =
             if icon.starts_with('/') { icon.to_string() } else { format!("{base}{icon}") };
-        return format!("<img src=\"{icon_src}\" alt=\"\" />");
+        return format!("<img src=\"{}\" alt=\"\" />", escape_html(&icon_src));
     }
 
     // Treat as emoji/text
-    icon.to_string()
+    escape_html(icon)
+}
+
+/// Escapes text for use in HTML content or a quoted attribute value.
+fn escape_html(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for ch in text.chars() {
+        match ch {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            _ => escaped.push(ch),
+        }
+    }
+    escaped
 }
 
 fn generate_social_links_html(links: &SocialLinks) -> String {
@@ -980,11 +1322,86 @@ fn generate_mobile_social_links_html(links: &SocialLinks) -> String {
     template.render().unwrap_or_default()
 }
 
-fn generate_nav_html(nav_groups: &[NavGroup], current_path: &str) -> String {
-    let template = NavTemplate { nav_groups, current_path };
+fn generate_nav_html(nav_groups: &[NavGroup], current_path: &str, base: &str) -> String {
+    let groups: Vec<NavGroupView<'_>> = nav_groups
+        .iter()
+        .map(|group| NavGroupView {
+            title: &group.title,
+            items: group
+                .items
+                .iter()
+                .map(|item| NavItemView {
+                    title: &item.title,
+                    href: &item.href,
+                    active: item.path == current_path,
+                    external: item.href.starts_with("http://") || item.href.starts_with("https://"),
+                    icon_html: item.icon.as_ref().map(|icon| render_icon(icon, base)),
+                })
+                .collect(),
+        })
+        .collect();
+    let template = NavTemplate { nav_groups: &groups };
     template.render().unwrap_or_default()
 }
 
+/// Returns the breadcrumb trail of a page as `(name, url)` pairs: the home
+/// page, the page's navigation group (linking to its first item) and the page.
+///
+/// Empty when the page is not in the navigation.
+fn breadcrumbs<'a>(
+    nav_groups: &'a [NavGroup],
+    current_path: &str,
+    site_name: &'a str,
+    base: &str,
+) -> Vec<(&'a str, String)> {
+    nav_groups
+        .iter()
+        .find_map(|group| {
+            let page = group.items.iter().find(|item| item.path == current_path)?;
+            Some(vec![
+                (site_name, format!("{base}index.html")),
+                (group.title.as_str(), group.items[0].href.clone()),
+                (page.title.as_str(), page.href.clone()),
+            ])
+        })
+        .unwrap_or_default()
+}
+
+/// Renders a breadcrumb trail as a schema.org `BreadcrumbList` JSON-LD script,
+/// resolving root-relative URLs against `site_url`.
+fn breadcrumb_json_ld(trail: &[(&str, String)], site_url: &str) -> String {
+    if trail.is_empty() {
+        return String::new();
+    }
+    let items: Vec<serde_json::Value> = trail
+        .iter()
+        .enumerate()
+        .map(|(index, (name, url))| {
+            let url = if url.starts_with("http://") || url.starts_with("https://") {
+                url.clone()
+            } else {
+                format!("{}{url}", site_url.trim_end_matches('/'))
+            };
+            serde_json::json!({
+                "@type": "ListItem",
+                "position": index + 1,
+                "name": name,
+                "item": url,
+            })
+        })
+        .collect();
+    let json = serde_json::json!({
+        "@context": "https://schema.org",
+        "@type": "BreadcrumbList",
+        "itemListElement": items,
+    });
+    // `</` would close the script element early.
+    format!(
+        "<script type=\"application/ld+json\">{}</script>",
+        json.to_string().replace("</", "<\\/")
+    )
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -997,7 +1414,7 @@ mod tests {
             content: "<h1>Hello</h1>".to_string(),
             toc: vec![TocEntry { depth: 1, text: "Hello".to_string(), slug: "hello".to_string() }],
             path: "test".to_string(),
-            entry_page: None,
+            ..Default::default()
         };
 
         let nav_groups = vec![NavGroup {
@@ -1006,16 +1423,14 @@ mod tests {
                 title: "Test Page".to_string(),
                 path: "test".to_string(),
                 href: "/docs/test/index.html".to_string(),
+                icon: None,
             }],
         }];
 
         let config = SsgConfig {
             site_name: "Test Site".to_string(),
             base: "/docs/".to_string(),
-            og_image: None,
-            theme: None,
-            locale: None,
-            available_locales: None,
+            ..Default::default()
         };
 
         let html = generate_html(&page_data, &nav_groups, &config);
@@ -1029,11 +1444,9 @@ mod tests {
     fn test_generate_html_with_theme() {
         let page_data = PageData {
             title: "Themed Page".to_string(),
-            description: None,
             content: "<p>Content</p>".to_string(),
-            toc: vec![],
             path: "themed".to_string(),
-            entry_page: None,
+            ..Default::default()
         };
 
         let nav_groups = vec![];
@@ -1041,9 +1454,6 @@ mod tests {
         let config = SsgConfig {
             site_name: "Themed Site".to_string(),
             base: "/".to_string(),
-            og_image: None,
-            locale: None,
-            available_locales: None,
             theme: Some(ThemeConfig {
                 colors: Some(ThemeColors {
                     primary: Some("#3498db".to_string()),
@@ -1055,6 +1465,7 @@ mod tests {
                 }),
                 ..Default::default()
             }),
+            ..Default::default()
         };
 
         let html = generate_html(&page_data, &nav_groups, &config);
@@ -1109,4 +1520,629 @@ mod tests {
         assert!(html.contains("Footer message"));
         assert!(html.contains("Copyright info"));
     }
+
+    #[test]
+    fn test_generate_html_accessibility_landmarks() {
+        let page_data = PageData {
+            title: "A11y".to_string(),
+            content: "<p>Content</p>".to_string(),
+            path: "a11y".to_string(),
+            ..Default::default()
+        };
+
+        let nav_groups = vec![NavGroup {
+            title: "Guide".to_string(),
+            items: vec![NavItem {
+                title: "A11y".to_string(),
+                path: "a11y".to_string(),
+                href: "/a11y/index.html".to_string(),
+                icon: None,
+            }],
+        }];
+
+        let config = SsgConfig {
+            site_name: "Site".to_string(),
+            base: "/".to_string(),
+            ..Default::default()
+        };
+
+        let html = generate_html(&page_data, &nav_groups, &config);
+
+        // The skip link is the first element in <body>.
+        let body = &html[html.find("<body").unwrap()..];
+        let first_link = body.find("<a ").unwrap();
+        assert!(body[first_link..]
+            .starts_with("<a class=\"skip-link\" href=\"#main-content\">Skip to content</a>"));
+        assert!(first_link < body.find("<button").unwrap());
+
+        assert!(html.contains(
+            "<main class=\"main\" id=\"main-content\" role=\"main\" \
+             data-copy-code=\"Copy\" data-copied=\"Copied\">"
+        ));
+        assert!(html.contains("<nav aria-label=\"Sidebar\">"));
+    }
+
+    #[test]
+    fn test_generate_html_print_styles() {
+        let page_data = PageData {
+            title: "Print".to_string(),
+            content: "<p>Content</p>".to_string(),
+            path: "print".to_string(),
+            ..Default::default()
+        };
+
+        let mut config = SsgConfig {
+            site_name: "Site".to_string(),
+            base: "/".to_string(),
+            ..Default::default()
+        };
+
+        let html = generate_html(&page_data, &[], &config);
+        assert!(html.contains("/* ox-content:css:print:start */"));
+        assert!(html.contains("@media print"));
+        assert!(html.contains("content: \" (\" attr(href) \")\";"));
+
+        config.theme = Some(ThemeConfig { print: Some(false), ..Default::default() });
+        let html = generate_html(&page_data, &[], &config);
+        assert!(!html.contains("@media print"));
+    }
+
+    #[test]
+    fn test_generate_html_container_styles() {
+        let mut page_data = PageData {
+            title: "Containers".to_string(),
+            content: "<div class=\"custom-block tip\">\n<p class=\"custom-block-title\">TIP</p>\n</div>\n\
+                      <details class=\"custom-block details\">\n<summary>Details</summary>\n</details>\n"
+                .to_string(),
+            path: "containers".to_string(),
+            ..Default::default()
+        };
+
+        let config = SsgConfig {
+            site_name: "Site".to_string(),
+            base: "/".to_string(),
+            ..Default::default()
+        };
+
+        let html = generate_html(&page_data, &[], &config);
+        assert!(html.contains("/* ox-content:css:containers:start */"));
+        assert!(html.contains(".content .custom-block.warning"));
+        assert!(html.contains(".content .custom-block.details > summary"));
+
+        page_data.content = "<p>Plain</p>".to_string();
+        let html = generate_html(&page_data, &[], &config);
+        assert!(!html.contains("ox-content:css:containers"));
+    }
+
+    #[test]
+    fn test_generate_404() {
+        let nav_groups = vec![NavGroup {
+            title: "Guide".to_string(),
+            items: vec![NavItem {
+                title: "Intro".to_string(),
+                path: "intro".to_string(),
+                href: "/docs/intro/index.html".to_string(),
+                icon: None,
+            }],
+        }];
+
+        let config = SsgConfig {
+            site_name: "My Docs".to_string(),
+            base: "/docs/".to_string(),
+            theme: Some(ThemeConfig {
+                not_found: Some(ThemeNotFound {
+                    message: Some("Nothing to see here.".to_string()),
+                    ..Default::default()
+                }),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+
+        let html = generate_404(&nav_groups, &config);
+
+        assert!(html.contains("<title>Page not found - My Docs</title>"));
+        assert!(html.contains("Nothing to see here."));
+        assert!(html.contains("href=\"/docs/intro/index.html\""));
+        assert!(
+            html.contains("<a class=\"not-found-home\" href=\"/docs/index.html\">Back to home</a>")
+        );
+    }
+
+    #[test]
+    fn test_generate_html_page_head() {
+        let page_data = PageData {
+            title: "Head".to_string(),
+            content: "<p>Content</p>".to_string(),
+            path: "head".to_string(),
+            head: Some("<link rel=\"preload\" href=\"/hero.png\" as=\"image\">".to_string()),
+            ..Default::default()
+        };
+
+        let config = SsgConfig {
+            site_name: "Site".to_string(),
+            base: "/".to_string(),
+            theme: Some(ThemeConfig {
+                embed: Some(ThemeEmbed {
+                    head: Some("<meta name=\"site-wide\" content=\"1\">".to_string()),
+                    ..Default::default()
+                }),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+
+        let html = generate_html(&page_data, &[], &config);
+        let site_head = html.find("<meta name=\"site-wide\" content=\"1\">").unwrap();
+        let page_head =
+            html.find("<link rel=\"preload\" href=\"/hero.png\" as=\"image\">").unwrap();
+        assert!(site_head < page_head);
+        assert!(page_head < html.find("</head>").unwrap());
+    }
+
+    #[test]
+    fn test_generate_html_article_meta() {
+        let mut page_data = PageData {
+            title: "Release".to_string(),
+            content: "<p>Content</p>".to_string(),
+            path: "blog/release".to_string(),
+            author: Some("Jane & Co".to_string()),
+            published: Some("2026-01-02".to_string()),
+            modified: Some("2026-01-05".to_string()),
+            ..Default::default()
+        };
+        let config = SsgConfig {
+            site_name: "Site".to_string(),
+            base: "/".to_string(),
+            ..Default::default()
+        };
+
+        let html = generate_html(&page_data, &[], &config);
+        assert!(html.contains("<meta name=\"author\" content=\"Jane &amp; Co\">"));
+        assert!(html.contains("<meta property=\"article:author\" content=\"Jane &amp; Co\">"));
+        assert!(html.contains("<meta property=\"article:published_time\" content=\"2026-01-02\">"));
+        assert!(html.contains("<meta property=\"article:modified_time\" content=\"2026-01-05\">"));
+        assert!(html.contains("<meta property=\"og:type\" content=\"article\">"));
+
+        page_data.author = None;
+        page_data.published = None;
+        page_data.modified = None;
+        let html = generate_html(&page_data, &[], &config);
+        assert!(!html.contains("name=\"author\""));
+        assert!(!html.contains("article:"));
+        assert!(html.contains("<meta property=\"og:type\" content=\"website\">"));
+    }
+
+    #[test]
+    fn test_generate_html_favicon_and_theme_color() {
+        let page_data = PageData {
+            title: "Home".to_string(),
+            content: "<p>Content</p>".to_string(),
+            path: "index".to_string(),
+            ..Default::default()
+        };
+        let mut config = SsgConfig {
+            site_name: "Site".to_string(),
+            base: "/docs/".to_string(),
+            theme: Some(ThemeConfig {
+                favicon: Some("favicon.svg".to_string()),
+                theme_color_light: Some("#ffffff".to_string()),
+                theme_color_dark: Some("#1b1b1f".to_string()),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+
+        let html = generate_html(&page_data, &[], &config);
+        assert!(html.contains("<link rel=\"icon\" href=\"/docs/favicon.svg\">"));
+        assert!(html.contains(
+            "<meta name=\"theme-color\" content=\"#ffffff\" media=\"(prefers-color-scheme: light)\">"
+        ));
+        assert!(html.contains(
+            "<meta name=\"theme-color\" content=\"#1b1b1f\" media=\"(prefers-color-scheme: dark)\">"
+        ));
+
+        config.theme = None;
+        let html = generate_html(&page_data, &[], &config);
+        assert!(!html.contains("rel=\"icon\""));
+        assert!(!html.contains("theme-color"));
+    }
+
+    fn outline_page_data() -> PageData {
+        let toc = [(1, "Title"), (2, "Install"), (3, "Usage"), (4, "Details")]
+            .into_iter()
+            .map(|(depth, text)| TocEntry {
+                depth,
+                text: text.to_string(),
+                slug: text.to_lowercase(),
+            })
+            .collect();
+        PageData {
+            title: "Outline".to_string(),
+            content: "<h1>Title</h1>".to_string(),
+            toc,
+            path: "outline".to_string(),
+            ..Default::default()
+        }
+    }
+
+    fn outline_theme() -> Option<ThemeConfig> {
+        Some(ThemeConfig { outline_position: Some("right".to_string()), ..Default::default() })
+    }
+
+    #[test]
+    fn test_generate_html_outline_levels() {
+        let mut config = SsgConfig {
+            site_name: "Site".to_string(),
+            base: "/".to_string(),
+            theme: outline_theme(),
+            ..Default::default()
+        };
+
+        let html = generate_html(&outline_page_data(), &[], &config);
+        assert!(html.contains("<aside class=\"outline-aside\">"));
+        assert!(!html.contains("href=\"#title\""));
+        assert!(html.contains("href=\"#install\""));
+        assert!(html.contains("href=\"#usage\""));
+        assert!(!html.contains("href=\"#details\""));
+
+        config.theme = Some(ThemeConfig {
+            outline_position: Some("right".to_string()),
+            outline_level: Some((3, 4)),
+            ..Default::default()
+        });
+        let html = generate_html(&outline_page_data(), &[], &config);
+        assert!(!html.contains("href=\"#install\""));
+        assert!(html.contains("href=\"#usage\""));
+        assert!(html.contains("href=\"#details\""));
+    }
+
+    #[test]
+    fn test_generate_html_scroll_spy_with_outline() {
+        let mut config = SsgConfig {
+            site_name: "Site".to_string(),
+            base: "/".to_string(),
+            theme: outline_theme(),
+            ..Default::default()
+        };
+
+        let html = generate_html(&outline_page_data(), &[], &config);
+        assert!(html.contains("new IntersectionObserver("));
+        assert!(html.contains("link.classList.toggle(\"active\""));
+
+        config.theme =
+            Some(ThemeConfig { outline_position: Some("none".to_string()), ..Default::default() });
+        let html = generate_html(&outline_page_data(), &[], &config);
+        assert!(!html.contains("new IntersectionObserver("));
+    }
+
+    #[test]
+    fn test_generate_html_outline_links_target_rendered_headings() {
+        use ox_content_allocator::Allocator;
+        use ox_content_parser::Parser;
+        use ox_content_renderer::{HtmlRenderer, HtmlRendererOptions, SlugStrategy, Slugger};
+
+        let allocator = Allocator::new();
+        let doc = Parser::new(&allocator, "# Title\n\n## Install\n\n## Usage\n\n### Usage\n")
+            .parse()
+            .unwrap();
+        let content = HtmlRenderer::with_options(HtmlRendererOptions {
+            heading_anchors: true,
+            ..Default::default()
+        })
+        .render(&doc);
+        let mut slugger = Slugger::new(SlugStrategy::default());
+        let toc = [(1, "Title"), (2, "Install"), (2, "Usage"), (3, "Usage")]
+            .into_iter()
+            .map(|(depth, text)| TocEntry {
+                depth,
+                text: text.to_string(),
+                slug: slugger.slug(text),
+            })
+            .collect();
+        let page_data = PageData { content, toc, ..outline_page_data() };
+        let config = SsgConfig {
+            site_name: "Site".to_string(),
+            base: "/".to_string(),
+            theme: outline_theme(),
+            ..Default::default()
+        };
+
+        let html = generate_html(&page_data, &[], &config);
+        let targets: Vec<&str> = html
+            .split("<a href=\"#")
+            .skip(1)
+            .filter_map(|rest| rest.split_once("\" class=\"outline-link\""))
+            .map(|(slug, _)| slug)
+            .collect();
+        assert_eq!(targets, ["install", "usage", "usage-1"]);
+        for slug in targets {
+            assert!(html.contains(&format!(" id=\"{slug}\">")), "no heading with id {slug}");
+        }
+        assert!(html.contains("new IntersectionObserver("));
+    }
+
+    #[test]
+    fn test_generate_nav_html_icons_and_external_links() {
+        let item = |title: &str, href: &str, icon: Option<&str>| NavItem {
+            title: title.to_string(),
+            path: title.to_lowercase(),
+            href: href.to_string(),
+            icon: icon.map(str::to_string),
+        };
+        let nav_groups = vec![NavGroup {
+            title: "Guide".to_string(),
+            items: vec![
+                item("Intro", "/docs/intro/index.html", Some("mdi:book")),
+                item("GitHub", "https://github.com/example/repo", Some("logo.svg")),
+            ],
+        }];
+
+        let html = generate_nav_html(&nav_groups, "intro", "/docs/");
+        let items: Vec<&str> = html.split("<li class=\"nav-item\">").skip(1).collect();
+        assert_eq!(items.len(), 2);
+
+        assert!(items[0].contains("class=\"nav-link active\""));
+        assert!(items[0].contains("https://api.iconify.design/mdi/book.svg"));
+        assert!(!items[0].contains("nav-external"));
+        assert!(!items[0].contains("target=\"_blank\""));
+
+        assert!(items[1].contains(
+            "<span class=\"nav-icon\"><img src=\"/docs/logo.svg\" alt=\"\" /></span>GitHub"
+        ));
+        assert!(
+            items[1].contains("class=\"nav-link\" target=\"_blank\" rel=\"noopener noreferrer\"")
+        );
+        assert!(items[1].contains("GitHub<span class=\"nav-external\" aria-hidden=\"true\">"));
+    }
+
+    #[test]
+    fn test_render_icon_escapes_text_and_urls() {
+        assert_eq!(render_icon("🚀", "/"), "🚀");
+        assert_eq!(render_icon("<b>&</b>", "/"), "&lt;b&gt;&amp;&lt;/b&gt;");
+        assert_eq!(
+            render_icon("https://example.com/a.svg?x=\"><script>", "/"),
+            "<img src=\"https://example.com/a.svg?x=&quot;&gt;&lt;script&gt;\" alt=\"\" />"
+        );
+    }
+
+    #[test]
+    fn test_generate_html_hreflang_alternates() {
+        let config = SsgConfig {
+            site_name: "My Docs".to_string(),
+            base: "/".to_string(),
+            locale: Some("ja".to_string()),
+            default_locale: Some("en".to_string()),
+            ..Default::default()
+        };
+
+        let html = generate_html(&outline_page_data(), &[], &config);
+        assert!(!html.contains("hreflang"));
+
+        let page_data = PageData {
+            alternates: vec![
+                ("ja".to_string(), "https://example.com/ja/guide/".to_string()),
+                ("en".to_string(), "https://example.com/guide/".to_string()),
+                ("zh-Hans".to_string(), "https://example.com/zh/guide/".to_string()),
+            ],
+            ..outline_page_data()
+        };
+        let html = generate_html(&page_data, &[], &config);
+        let links: Vec<&str> = html
+            .lines()
+            .map(str::trim)
+            .filter(|line| line.starts_with("<link rel=\"alternate\""))
+            .collect();
+        assert_eq!(
+            links,
+            [
+                "<link rel=\"alternate\" hreflang=\"ja\" href=\"https://example.com/ja/guide/\">",
+                "<link rel=\"alternate\" hreflang=\"en\" href=\"https://example.com/guide/\">",
+                "<link rel=\"alternate\" hreflang=\"zh-Hans\" href=\"https://example.com/zh/guide/\">",
+                "<link rel=\"alternate\" hreflang=\"x-default\" href=\"https://example.com/guide/\">",
+            ]
+        );
+        assert!(html.find("hreflang=\"x-default\"").unwrap() < html.find("</head>").unwrap());
+
+        // Without a default locale (or its translation) there is no `x-default`.
+        let config = SsgConfig { default_locale: None, ..config };
+        assert!(!generate_html(&page_data, &[], &config).contains("x-default"));
+    }
+
+    #[test]
+    fn test_generate_html_breadcrumb_json_ld() {
+        let item = |title: &str, path: &str| NavItem {
+            title: title.to_string(),
+            path: path.to_string(),
+            href: format!("/docs/{path}/index.html"),
+            icon: None,
+        };
+        let nav_groups = vec![NavGroup {
+            title: "Guide".to_string(),
+            items: vec![item("Intro", "intro"), item("Outline", "outline")],
+        }];
+        let mut config = SsgConfig {
+            site_name: "My Docs".to_string(),
+            base: "/docs/".to_string(),
+            ..Default::default()
+        };
+
+        let html = generate_html(&outline_page_data(), &nav_groups, &config);
+        assert!(!html.contains("application/ld+json"));
+
+        config.theme = Some(ThemeConfig { json_ld: Some(true), ..Default::default() });
+        // Without a site URL the absolute `item` URLs can't be built.
+        let html = generate_html(&outline_page_data(), &nav_groups, &config);
+        assert!(!html.contains("application/ld+json"));
+
+        config.site_url = Some("https://example.com/".to_string());
+        let html = generate_html(&outline_page_data(), &nav_groups, &config);
+        let start = html.find("<script type=\"application/ld+json\">").unwrap();
+        assert!(start < html.find("</head>").unwrap());
+        let json = &html[start + "<script type=\"application/ld+json\">".len()..];
+        let json: serde_json::Value =
+            serde_json::from_str(&json[..json.find("</script>").unwrap()]).unwrap();
+
+        assert_eq!(json["@type"], "BreadcrumbList");
+        let items = json["itemListElement"].as_array().unwrap();
+        let crumbs: Vec<(u64, &str, &str)> = items
+            .iter()
+            .map(|item| {
+                assert_eq!(item["@type"], "ListItem");
+                (
+                    item["position"].as_u64().unwrap(),
+                    item["name"].as_str().unwrap(),
+                    item["item"].as_str().unwrap(),
+                )
+            })
+            .collect();
+        assert_eq!(
+            crumbs,
+            [
+                (1, "My Docs", "https://example.com/docs/index.html"),
+                (2, "Guide", "https://example.com/docs/intro/index.html"),
+                (3, "Outline", "https://example.com/docs/outline/index.html"),
+            ]
+        );
+
+        // Pages outside the navigation get no breadcrumbs.
+        let html = generate_html(&outline_page_data(), &[], &config);
+        assert!(!html.contains("application/ld+json"));
+    }
+
+    #[test]
+    fn test_generate_html_outline_position() {
+        let mut config = SsgConfig {
+            site_name: "Site".to_string(),
+            base: "/".to_string(),
+            theme: Some(ThemeConfig {
+                outline_position: Some("none".to_string()),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+
+        let html = generate_html(&outline_page_data(), &[], &config);
+        assert!(!html.contains("class=\"outline\""));
+        assert!(!html.contains("<aside class=\"outline-aside\">"));
+
+        // The outline is opt-in.
+        config.theme = None;
+        let html = generate_html(&outline_page_data(), &[], &config);
+        assert!(!html.contains("class=\"outline\""));
+
+        config.theme =
+            Some(ThemeConfig { outline_position: Some("left".to_string()), ..Default::default() });
+        let html = generate_html(&outline_page_data(), &[], &config);
+        let sidebar = html.find("<aside class=\"sidebar\">").unwrap();
+        let outline = html.find("<nav class=\"outline\"").unwrap();
+        assert!(sidebar < outline);
+        assert!(outline < html.find("<main").unwrap());
+        assert!(!html.contains("<aside class=\"outline-aside\">"));
+    }
+
+    #[test]
+    fn test_generate_html_page_layouts() {
+        let config = SsgConfig {
+            site_name: "Site".to_string(),
+            base: "/".to_string(),
+            theme: outline_theme(),
+            ..Default::default()
+        };
+        let nav_groups = vec![NavGroup {
+            title: "Guide".to_string(),
+            items: vec![NavItem {
+                title: "Outline".to_string(),
+                path: "outline".to_string(),
+                href: "/outline/".to_string(),
+                icon: None,
+            }],
+        }];
+        let render = |layout: Option<&str>| {
+            let layout = layout.map(|name| PageLayout::parse(name).unwrap());
+            let page_data = PageData { layout, ..outline_page_data() };
+            generate_html(&page_data, &nav_groups, &config)
+        };
+
+        for html in [render(None), render(Some("doc"))] {
+            assert!(html.contains("<aside class=\"sidebar\">"));
+            assert!(html.contains("<aside class=\"outline-aside\">"));
+        }
+
+        let html = render(Some("page"));
+        assert!(!html.contains("<aside class=\"sidebar\">"));
+        assert!(html.contains("<aside class=\"outline-aside\">"));
+        assert!(html.contains("<body class=\"page-layout--page\">"));
+
+        let html = render(Some("full"));
+        assert!(!html.contains("<aside class=\"sidebar\">"));
+        assert!(!html.contains("<aside class=\"outline-aside\">"));
+        assert!(html.contains("<body class=\"page-layout--full\">"));
+
+        assert_eq!(PageLayout::parse("home"), None);
+        assert_eq!(PageLayout::parse("Doc"), None);
+
+        // Unknown layouts fall back to the default instead of failing.
+        let page: PageData = serde_json::from_value(serde_json::json!({
+            "title": "Home",
+            "description": null,
+            "content": "",
+            "toc": [],
+            "path": "index",
+            "entry_page": null,
+            "layout": "home",
+        }))
+        .unwrap();
+        assert_eq!(page.layout, None);
+        let page: PageData = serde_json::from_value(serde_json::json!({
+            "title": "Full",
+            "description": null,
+            "content": "",
+            "toc": [],
+            "path": "full",
+            "entry_page": null,
+            "layout": "full",
+        }))
+        .unwrap();
+        assert_eq!(page.layout, Some(PageLayout::Full));
+    }
+
+    #[test]
+    fn test_generate_html_ui_strings() {
+        let config = SsgConfig {
+            site_name: "サイト".to_string(),
+            base: "/".to_string(),
+            theme: outline_theme(),
+            locale: Some("ja".to_string()),
+            available_locales: Some(vec![LocaleInfo {
+                code: "ja".to_string(),
+                name: "日本語".to_string(),
+                dir: "ltr".to_string(),
+            }]),
+            ui_strings: UiStrings {
+                skip_to_content: "本文へスキップ".to_string(),
+                search: "検索".to_string(),
+                search_placeholder: "ドキュメントを検索...".to_string(),
+                menu: "メニュー".to_string(),
+                on_this_page: "このページの内容".to_string(),
+                copy_code: "コピー".to_string(),
+                copied: "コピーしました".to_string(),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        let html = generate_html(&outline_page_data(), &[], &config);
+
+        assert!(html.contains("<html lang=\"ja\" dir=\"ltr\">"));
+        assert!(html.contains("<a class=\"skip-link\" href=\"#main-content\">本文へスキップ</a>"));
+        assert!(html.contains("<span>検索</span>"));
+        assert!(html.contains("placeholder=\"ドキュメントを検索...\""));
+        assert!(html.contains("<span class=\"mobile-footer-label\">メニュー</span>"));
+        assert!(html.contains("<div class=\"outline-title\">このページの内容</div>"));
+        assert!(html.contains("data-copy-code=\"コピー\" data-copied=\"コピーしました\""));
+        assert!(!html.contains("Skip to content"));
+        assert!(!html.contains("<span>Search</span>"));
+        assert!(!html.contains("On this page"));
+    }
 }
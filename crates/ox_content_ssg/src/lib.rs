@@ -13,6 +13,8 @@
 //! - Dark/light theme support
 //! - Mobile-friendly responsive design
 //! - Customizable theme configuration
+//! - Themed 404 page generation
+//! - `llms.txt` / `llms-full.txt` plaintext exports
 //!
 //! # Example
 //!
@@ -25,7 +27,7 @@
 //!     content: "<h1>Getting Started</h1><p>Welcome!</p>".to_string(),
 //!     toc: vec![TocEntry { depth: 1, text: "Getting Started".to_string(), slug: "getting-started".to_string() }],
 //!     path: "getting-started".to_string(),
-//!     entry_page: None,
+//!     ..Default::default()
 //! };
 //!
 //! let nav_groups = vec![NavGroup {
@@ -34,24 +36,26 @@
 //!         title: "Getting Started".to_string(),
 //!         path: "getting-started".to_string(),
 //!         href: "/docs/getting-started/index.html".to_string(),
+//!         icon: None,
 //!     }],
 //! }];
 //!
 //! let config = SsgConfig {
 //!     site_name: "My Docs".to_string(),
 //!     base: "/docs/".to_string(),
-//!     og_image: None,
-//!     theme: None,
+//!     ..Default::default()
 //! };
 //!
 //! let html = generate_html(&page_data, &nav_groups, &config);
 //! ```
 
 mod html;
+mod llms;
 
 pub use html::{
-    generate_html, EntryPageConfig, FeatureConfig, HeroAction, HeroConfig, HeroImage,
-    HeroNoticeConfig, LocaleInfo, NavGroup, NavItem, PageData, SocialLinks, SsgConfig, ThemeColors,
-    ThemeConfig, ThemeEmbed, ThemeEntryPage, ThemeFonts, ThemeFooter, ThemeHeader, ThemeLayout,
-    TocEntry,
+    generate_404, generate_html, EntryPageConfig, FeatureConfig, HeroAction, HeroConfig, HeroImage,
+    HeroNoticeConfig, LocaleInfo, NavGroup, NavItem, PageData, PageLayout, SocialLinks, SsgConfig,
+    ThemeColors, ThemeConfig, ThemeEmbed, ThemeEntryPage, ThemeFonts, ThemeFooter, ThemeHeader,
+    ThemeLayout, ThemeNotFound, TocEntry, UiStrings,
 };
+pub use llms::{generate_llms_full_txt, generate_llms_txt, PageMeta};
@@ -0,0 +1,161 @@
+//! `llms.txt` generation: a plaintext site index for language models.
+//!
+//! Follows the `llms.txt` convention: an `# H1` with the site name, then a
+//! Markdown list of `[title](url): description` links. `llms-full.txt` adds
+//! each page's plain-text content.
+
+use serde::{Deserialize, Serialize};
+
+use crate::html::SsgConfig;
+
+/// Page summary listed in `llms.txt`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PageMeta {
+    /// Page title.
+    pub title: String,
+    /// URL path relative to `base` (e.g. `"guide/install"`; `""` or `"index"` for the home page).
+    pub path: String,
+    /// Page description.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// Plain-text page content, only used by [`generate_llms_full_txt`].
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub content: Option<String>,
+}
+
+/// Generates an `llms.txt` index listing every page with its title, URL and description.
+#[must_use]
+pub fn generate_llms_txt(pages: &[PageMeta], config: &SsgConfig) -> String {
+    let mut out = format!("# {}\n\n## Pages\n\n", config.site_name.trim());
+    for page in pages {
+        out.push_str(&format!(
+            "- [{}]({})",
+            escape_link_text(page.title.trim()),
+            page_url(page, config)
+        ));
+        if let Some(description) = page.description.as_deref().map(str::trim) {
+            if !description.is_empty() {
+                out.push_str(": ");
+                out.push_str(description);
+            }
+        }
+        out.push('\n');
+    }
+    out
+}
+
+/// Generates an `llms-full.txt` export: every page's title, URL and content in one file.
+#[must_use]
+pub fn generate_llms_full_txt(pages: &[PageMeta], config: &SsgConfig) -> String {
+    let mut out = format!("# {}\n", config.site_name.trim());
+    for page in pages {
+        out.push_str(&format!(
+            "\n---\n\n## {}\n\nURL: {}\n",
+            page.title.trim(),
+            page_url(page, config)
+        ));
+        if let Some(description) = page.description.as_deref().map(str::trim) {
+            if !description.is_empty() {
+                out.push_str(&format!("\n> {description}\n"));
+            }
+        }
+        if let Some(content) = page.content.as_deref().map(str::trim) {
+            if !content.is_empty() {
+                out.push('\n');
+                out.push_str(content);
+                out.push('\n');
+            }
+        }
+    }
+    out
+}
+
+/// Returns the page's absolute URL (`site_url` followed by `base`), matching
+/// the generated `index.html` layout. Without a `site_url` the URL is root-relative.
+fn page_url(page: &PageMeta, config: &SsgConfig) -> String {
+    let origin = config.site_url.as_deref().unwrap_or_default().trim_end_matches('/');
+    let base = &config.base;
+    let path = page.path.trim_matches('/');
+    if path.is_empty() || path == "index" {
+        format!("{origin}{base}index.html")
+    } else {
+        format!("{origin}{base}{path}/index.html")
+    }
+}
+
+/// Escapes characters that would end or nest Markdown link text.
+fn escape_link_text(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    for c in text.chars() {
+        if matches!(c, '\\' | '[' | ']') {
+            out.push('\\');
+        }
+        out.push(c);
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config(base: &str, site_url: Option<&str>) -> SsgConfig {
+        SsgConfig {
+            site_name: "My Docs".to_string(),
+            base: base.to_string(),
+            site_url: site_url.map(ToString::to_string),
+            ..Default::default()
+        }
+    }
+
+    fn pages() -> Vec<PageMeta> {
+        vec![
+            PageMeta {
+                title: "Home".to_string(),
+                path: "index".to_string(),
+                description: None,
+                content: Some("Welcome.".to_string()),
+            },
+            PageMeta {
+                title: "Install".to_string(),
+                path: "guide/install".to_string(),
+                description: Some("How to install.".to_string()),
+                content: Some("Run the installer.".to_string()),
+            },
+        ]
+    }
+
+    #[test]
+    fn test_llms_txt_lists_pages_under_base() {
+        let txt = generate_llms_txt(&pages(), &config("/docs/", Some("https://example.com/")));
+        assert_eq!(
+            txt,
+            "# My Docs\n\n## Pages\n\n\
+             - [Home](https://example.com/docs/index.html)\n\
+             - [Install](https://example.com/docs/guide/install/index.html): How to install.\n"
+        );
+    }
+
+    #[test]
+    fn test_llms_txt_escapes_link_titles() {
+        let pages = vec![PageMeta {
+            title: "Arrays [] and \\ paths".to_string(),
+            path: "arrays".to_string(),
+            description: None,
+            content: None,
+        }];
+        let txt = generate_llms_txt(&pages, &config("/", None));
+        assert!(txt.contains("- [Arrays \\[\\] and \\\\ paths](/arrays/index.html)\n"), "{txt}");
+    }
+
+    #[test]
+    fn test_llms_full_txt_includes_content() {
+        let txt = generate_llms_full_txt(&pages(), &config("/", None));
+        assert!(txt.starts_with("# My Docs\n"));
+        assert!(txt.contains(
+            "## Install\n\nURL: /guide/install/index.html\n\n> How to install.\n\n\
+             Run the installer.\n"
+        ));
+        assert!(txt.contains("## Home\n\nURL: /index.html\n\nWelcome.\n"));
+    }
+}
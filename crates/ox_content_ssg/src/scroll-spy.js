@@ -0,0 +1,36 @@
+const outlineLinks = Array.from(document.querySelectorAll(".outline-link")),
+  outlineHeadings = outlineLinks
+    .map((link) => findAnchorTarget(link.getAttribute("href")))
+    .filter(Boolean);
+
+if (outlineHeadings.length && "IntersectionObserver" in window) {
+  const visibleHeadings = new Set();
+
+  const setActiveHeading = (id) => {
+    for (const link of outlineLinks) {
+      link.classList.toggle("active", findAnchorTarget(link.getAttribute("href"))?.id === id);
+    }
+  };
+
+  const observer = new IntersectionObserver(
+    (entries) => {
+      for (const entry of entries) {
+        if (entry.isIntersecting) visibleHeadings.add(entry.target);
+        else visibleHeadings.delete(entry.target);
+      }
+      const current = outlineHeadings.find((heading) => visibleHeadings.has(heading));
+      if (current) setActiveHeading(current.id);
+    },
+    { rootMargin: "0px 0px -70% 0px" },
+  );
+
+  outlineHeadings.forEach((heading) => observer.observe(heading));
+  outlineLinks.forEach((link) =>
+    link.addEventListener("click", () =>
+      setActiveHeading(findAnchorTarget(link.getAttribute("href"))?.id),
+    ),
+  );
+
+  const initial = findAnchorTarget(location.hash);
+  if (initial && outlineHeadings.includes(initial)) setActiveHeading(initial.id);
+}
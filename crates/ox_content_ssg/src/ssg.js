@@ -90,11 +90,17 @@ document.addEventListener("keydown", (e) => {
   }
 });
 
-const scrollToHash = () => {
-  const hash = location.hash;
-  if (!hash) return;
+const findAnchorTarget = (hash) => {
+  if (!hash || hash.length < 2) return null;
+  try {
+    return document.getElementById(decodeURIComponent(hash.slice(1)));
+  } catch {
+    return document.getElementById(hash.slice(1));
+  }
+};
 
-  const target = document.querySelector(hash);
+const scrollToHash = () => {
+  const target = findAnchorTarget(location.hash);
   if (!target) return;
 
   setTimeout(() => target.scrollIntoView({ behavior: "smooth", block: "start" }), 100);
@@ -105,7 +111,7 @@ window.addEventListener("hashchange", scrollToHash);
 document.querySelectorAll('a[href^="#"]').forEach((a) =>
   a.addEventListener("click", (e) => {
     const hash = a.getAttribute("href");
-    const target = hash ? document.querySelector(hash) : null;
+    const target = findAnchorTarget(hash);
     if (target) {
       e.preventDefault();
       target.scrollIntoView({ behavior: "smooth", block: "start" });
@@ -131,6 +137,29 @@ mobileSearchBtn?.addEventListener("click", () => {
 
 mobileThemeBtn?.addEventListener("click", () => setTheme(getTheme() === "dark" ? "light" : "dark"));
 
+const copyLabels = document.getElementById("main-content")?.dataset ?? {};
+
+document.querySelectorAll(".code-block-wrapper .copy-code").forEach((button) => {
+  const copyLabel = copyLabels.copyCode ?? button.textContent;
+  button.textContent = copyLabel;
+  button.setAttribute("aria-label", copyLabel);
+  button.addEventListener("click", async () => {
+    const code = button.parentElement?.querySelector("pre code");
+    if (!code || !navigator.clipboard) return;
+    try {
+      await navigator.clipboard.writeText(code.textContent ?? "");
+    } catch {
+      return;
+    }
+    button.classList.add("copied");
+    button.textContent = copyLabels.copied ?? copyLabel;
+    setTimeout(() => {
+      button.classList.remove("copied");
+      button.textContent = copyLabel;
+    }, 2000);
+  });
+});
+
 document.querySelectorAll(".ox-api-controls").forEach((controls) => {
   const targetSelector = controls.getAttribute("data-ox-api-target");
   if (!targetSelector) return;
@@ -225,6 +254,11 @@ window.__oxContentInitSearch = (() => {
       return scopes.some((scope) => docScopes.has(scope));
     };
 
+    const foldText = (text) =>
+      searchIndex?.fold_diacritics
+        ? text.normalize("NFKD").replace(/[\u0300-\u036F]/g, "").normalize("NFC")
+        : text;
+
     const tokenize = (text) => {
       const tokens = [];
       let current = "";
@@ -289,7 +323,7 @@ window.__oxContentInitSearch = (() => {
         return;
       }
 
-      const tokens = tokenize(parsedQuery.text);
+      const tokens = tokenize(foldText(parsedQuery.text));
       const k1 = 1.2,
         b = 0.75,
         scores = new Map();
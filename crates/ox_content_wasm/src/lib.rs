@@ -9,7 +9,7 @@ use wasm_bindgen::prelude::*;
 use ox_content_allocator::Allocator;
 use ox_content_ast::{Document, Heading, Node};
 use ox_content_parser::{Parser, ParserOptions};
-use ox_content_renderer::HtmlRenderer;
+use ox_content_renderer::{HtmlRenderer, SlugStrategy, Slugger};
 
 /// Table of contents entry.
 #[derive(serde::Serialize)]
@@ -230,14 +230,17 @@ fn parse_frontmatter(source: &str) -> (String, HashMap<String, serde_json::Value
 }
 
 /// Extracts table of contents from document headings.
+///
+/// Deeper headings still claim their slugs so duplicate suffixes match the rendered ids.
 fn extract_toc(doc: &Document, max_depth: u8) -> Vec<TocEntry> {
     let mut entries = Vec::new();
+    let mut slugger = Slugger::new(SlugStrategy::default());
 
     for node in &doc.children {
         if let Node::Heading(heading) = node {
+            let text = extract_heading_text(heading);
+            let slug = slugger.slug(&text);
             if heading.depth <= max_depth {
-                let text = extract_heading_text(heading);
-                let slug = slugify(&text);
                 entries.push(TocEntry { depth: heading.depth, text, slug });
             }
         }
@@ -259,6 +262,7 @@ fn extract_heading_text(heading: &Heading) -> String {
 fn collect_text(node: &Node, text: &mut String) {
     match node {
         Node::Text(t) => text.push_str(t.value),
+        Node::Break(_) => text.push(' '),
         Node::Emphasis(e) => {
             for child in &e.children {
                 collect_text(child, text);
@@ -275,6 +279,21 @@ fn collect_text(node: &Node, text: &mut String) {
                 collect_text(child, text);
             }
         }
+        Node::Mark(d) => {
+            for child in &d.children {
+                collect_text(child, text);
+            }
+        }
+        Node::Subscript(d) => {
+            for child in &d.children {
+                collect_text(child, text);
+            }
+        }
+        Node::Superscript(d) => {
+            for child in &d.children {
+                collect_text(child, text);
+            }
+        }
         Node::Link(l) => {
             for child in &l.children {
                 collect_text(child, text);
@@ -283,14 +302,3 @@ fn collect_text(node: &Node, text: &mut String) {
         _ => {}
     }
 }
-
-/// Converts text to URL-friendly slug.
-fn slugify(text: &str) -> String {
-    text.to_lowercase()
-        .chars()
-        .map(|c| if c.is_alphanumeric() || c == ' ' || c == '-' { c } else { ' ' })
-        .collect::<String>()
-        .split_whitespace()
-        .collect::<Vec<_>>()
-        .join("-")
-}
@@ -99,6 +99,36 @@ describe("writeDocs", () => {
 });
 
 describe("generateMarkdown", () => {
+  it("renders every overload signature as a list", () => {
+    const docs: ExtractedDocs[] = [
+      {
+        file: "/repo/src/parser.ts",
+        entries: [
+          {
+            name: "parse",
+            kind: "function",
+            description: "Parses a document.",
+            file: "/repo/src/parser.ts",
+            line: 1,
+            endLine: 2,
+            signature: "export declare function parse(source: string): Document",
+            overloads: [
+              "export declare function parse(source: string): Document",
+              "export declare function parse(source: string, options: ParseOptions): Document",
+            ],
+          },
+        ],
+      },
+    ];
+
+    const markdown = generateMarkdown(docs, resolveDocsOptions({})!);
+
+    expect(markdown["parser.md"]).toContain('<ul class="ox-api-entry__overloads">');
+    const signatures = markdown["parser.md"]?.match(/<li><code class="ox-api-entry__signature/g);
+    expect(signatures).toHaveLength(2);
+    expect(markdown["parser.md"]).toContain("<h4>Overloads</h4>");
+  });
+
   it("emits overview lines and accordion-style details for file docs", () => {
     const docs: ExtractedDocs[] = [
       {
@@ -128,6 +158,43 @@ describe("generateMarkdown", () => {
     expect(markdown["index.md"]).toContain("`@api transform`");
   });
 
+  it("renders generic type parameters with constraints and defaults", () => {
+    const docs: ExtractedDocs[] = [
+      {
+        file: "/repo/src/pick.ts",
+        entries: [
+          {
+            name: "pick",
+            kind: "function",
+            description: "Reads a property from an object.",
+            file: "/repo/src/pick.ts",
+            line: 1,
+            endLine: 3,
+            signature:
+              "export function pick<T extends object, K extends keyof T = keyof T>(obj: T, key: K): T[K]",
+            typeParams: [
+              { name: "T", constraint: "object", description: "Source object type" },
+              {
+                name: "K",
+                constraint: "keyof T",
+                default: "keyof T",
+                description: "Property key",
+              },
+            ],
+          },
+        ],
+      },
+    ];
+
+    const markdown = generateMarkdown(docs, resolveDocsOptions({})!)["pick.md"];
+
+    expect(markdown).toContain("<h4>Type Parameters</h4>");
+    expect(markdown).toContain('<code class="ox-api-entry__param-name">K</code>');
+    expect(markdown).toContain("extends keyof T");
+    expect(markdown).toContain("Source object type");
+    expect(markdown).toContain("default: keyof T");
+  });
+
   it("extracts declaration line ranges for source links", async () => {
     const srcDir = await fs.mkdtemp(path.join(os.tmpdir(), "ox-content-docs-src-"));
     tempDirs.push(srcDir);
@@ -56,6 +56,7 @@ import type {
   ExtractedDocs,
   DocEntry,
   ParamDoc,
+  TypeParamDoc,
   GeneratedDocsData,
 } from "./types";
 import { generateNavMetadata, generateNavCode } from "./nav-generator";
@@ -337,6 +338,13 @@ interface NapiDocParam {
   description?: string;
 }
 
+interface NapiDocTypeParam {
+  name: string;
+  constraint?: string;
+  default?: string;
+  description?: string;
+}
+
 interface NapiDocItem {
   name: string;
   kind: string;
@@ -347,7 +355,9 @@ interface NapiDocItem {
   endLine: number;
   exported: boolean;
   signature?: string;
+  overloads?: string[];
   params: NapiDocParam[];
+  typeParams?: NapiDocTypeParam[];
   returnType?: string;
   tags: NapiDocTag[];
 }
@@ -513,6 +523,7 @@ function parseNapiDocItem(item: NapiDocItem): DocEntry | null {
   }
 
   const params: ParamDoc[] = [];
+  const typeParams: TypeParamDoc[] = [];
   const examples: string[] = [];
   const tags: Record<string, string> = {};
   let description = "";
@@ -560,6 +571,18 @@ function parseNapiDocItem(item: NapiDocItem): DocEntry | null {
               });
             }
             break;
+          case "typeParam":
+          case "template": {
+            const typeParamMatch = /(\w+)\s*-?\s*(.*)/.exec(tagRest.trim());
+            if (typeParamMatch) {
+              typeParams.push({
+                name: typeParamMatch[1],
+                constraint: tagType || undefined,
+                description: typeParamMatch[2],
+              });
+            }
+            break;
+          }
           case "returns":
           case "return":
             returns = {
@@ -622,6 +645,19 @@ function parseNapiDocItem(item: NapiDocItem): DocEntry | null {
     }
   }
 
+  // Prefer the declared type parameters (in source order, with constraints
+  // and defaults), filling descriptions from `@typeParam` tags.
+  const jsdocTypeParams = new Map(typeParams.map((param) => [param.name, param]));
+  const resolvedTypeParams =
+    item.typeParams && item.typeParams.length > 0
+      ? item.typeParams.map((param) => ({
+          name: param.name,
+          constraint: param.constraint ?? jsdocTypeParams.get(param.name)?.constraint,
+          default: param.default,
+          description: param.description ?? jsdocTypeParams.get(param.name)?.description ?? "",
+        }))
+      : typeParams;
+
   if (!returns && item.returnType) {
     returns = {
       type: item.returnType,
@@ -638,6 +674,8 @@ function parseNapiDocItem(item: NapiDocItem): DocEntry | null {
   for (const tag of item.tags) {
     if (
       tag.tag === "param" ||
+      tag.tag === "typeParam" ||
+      tag.tag === "template" ||
       tag.tag === "returns" ||
       tag.tag === "return" ||
       tag.tag === "example"
@@ -658,6 +696,7 @@ function parseNapiDocItem(item: NapiDocItem): DocEntry | null {
     kind,
     description,
     params: params.length > 0 ? params : undefined,
+    typeParams: resolvedTypeParams.length > 0 ? resolvedTypeParams : undefined,
     returns,
     examples: examples.length > 0 ? examples : undefined,
     tags: Object.keys(tags).length > 0 ? tags : undefined,
@@ -666,6 +705,7 @@ function parseNapiDocItem(item: NapiDocItem): DocEntry | null {
     line: item.line,
     endLine: item.endLine,
     signature: item.signature,
+    overloads: item.overloads && item.overloads.length > 1 ? item.overloads : undefined,
   };
 }
 
@@ -878,6 +918,47 @@ ${rows}
 </div>`;
 }
 
+function renderTypeParamsListHtml(typeParams: TypeParamDoc[]): string {
+  const rows = typeParams
+    .map((param) => {
+      const description = [param.description, param.default ? `default: ${param.default}` : ""]
+        .filter(Boolean)
+        .join(" — ");
+
+      return `<li class="ox-api-entry__param">
+  <div class="ox-api-entry__param-heading">
+    <code class="ox-api-entry__param-name">${escapeHtml(param.name)}</code>
+    ${param.constraint ? `<code class="ox-api-entry__param-type">extends ${escapeHtml(param.constraint)}</code>` : ""}
+  </div>
+  ${description ? `<p class="ox-api-entry__param-description">${renderInlineHtml(description)}</p>` : ""}
+</li>`;
+    })
+    .join("\n");
+
+  return `<div class="ox-api-entry__section ox-api-entry__section--type-params">
+<h4>Type Parameters</h4>
+<ul class="ox-api-entry__params">
+${rows}
+</ul>
+</div>`;
+}
+
+function renderOverloadsHtml(overloads: string[]): string {
+  const items = overloads
+    .map(
+      (overload) =>
+        `<li>${renderHighlightedInlineCodeHtml(normalizeSignature(overload) ?? overload, "ox-api-entry__signature ox-api-entry__signature--highlighted")}</li>`,
+    )
+    .join("\n");
+
+  return `<div class="ox-api-entry__section ox-api-entry__section--overloads">
+<h4>Overloads</h4>
+<ul class="ox-api-entry__overloads">
+${items}
+</ul>
+</div>`;
+}
+
 function renderTagListHtml(tags: Record<string, string>): string {
   const items = Object.entries(tags)
     .map(
@@ -917,6 +998,14 @@ function generateEntryMarkdown(
     body += `<p class="ox-api-entry__source"><a href="${escapeHtml(sourceHref)}">View source</a></p>\n`;
   }
 
+  if (entry.overloads && entry.overloads.length > 1) {
+    body += renderOverloadsHtml(entry.overloads) + "\n";
+  }
+
+  if (entry.typeParams && entry.typeParams.length > 0) {
+    body += renderTypeParamsListHtml(entry.typeParams) + "\n";
+  }
+
   if (entry.params && entry.params.length > 0) {
     body += renderParamsListHtml(entry.params) + "\n";
   }
@@ -15,6 +15,7 @@ import { protectMermaidSvgs, restoreMermaidSvgs } from "./plugins/mermaid-protec
 import { transformIslands, hasIslands } from "./island";
 import { importNapiModule } from "./napi";
 import type {
+  ResolvedI18nOptions,
   ResolvedOptions,
   ResolvedSsgOptions,
   SsgOptions,
@@ -32,6 +33,8 @@ export interface SsgNavItem {
   title: string;
   path: string;
   href: string;
+  /** Icon from the page's `icon` frontmatter (Iconify name, image URL/path, or emoji). */
+  icon?: string;
   children?: SsgNavItem[];
 }
 
@@ -56,6 +59,8 @@ export interface SsgPageData {
   href: string;
   /** Entry page configuration (if layout: entry) */
   entryPage?: SsgEntryPageConfig;
+  /** Translations of this page, including itself, for `hreflang` links */
+  alternates?: { locale: string; href: string }[];
 }
 
 /**
@@ -823,6 +828,13 @@ export const DEFAULT_HTML_TEMPLATE = `<!DOCTYPE html>
       border: 1px solid var(--color-code-frame-border);
       border-radius: 4px;
     }
+    .content .ox-api-entry__overloads {
+      list-style: none;
+      padding: 0;
+      margin: 0;
+      display: grid;
+      gap: 0.4rem;
+    }
     .content .ox-api-entry__params {
       list-style: none;
       padding: 0;
@@ -1402,7 +1414,9 @@ export function extractTitle(content: string, frontmatter: Record<string, unknow
     return frontmatter.title;
   }
 
-  const h1Match = content.match(/<h1[^>]*>([^<]+)<\/h1>/i);
+  const h1Match = content.match(
+    /<h1[^>]*>([^<]+)(?:<a class="header-anchor"[^>]*>[^<]*<\/a>)?<\/h1>/i,
+  );
   if (h1Match) {
     return h1Match[1].trim();
   }
@@ -1463,6 +1477,9 @@ export function generateBareHtmlPage(content: string, title: string): string {
   });
 }
 
+/** Frontmatter `layout` values the built-in page template understands. */
+const PAGE_LAYOUTS = new Set(["doc", "page", "full"]);
+
 /**
  * Generates HTML page with navigation using Rust NAPI bindings.
  */
@@ -1473,6 +1490,8 @@ export async function generateHtmlPage(
   base: string,
   ogImage?: string,
   theme?: ResolvedThemeConfig,
+  siteUrl?: string,
+  defaultLocale?: string,
 ): Promise<string> {
   const mod = await importNapiModule();
 
@@ -1490,6 +1509,7 @@ export async function generateHtmlPage(
       title: item.title,
       path: item.path,
       href: item.href,
+      icon: item.icon,
     })),
   }));
 
@@ -1537,6 +1557,16 @@ export async function generateHtmlPage(
       }
     : undefined;
 
+  // `layout: entry` is handled by `entryPage`; other values pick the page chrome
+  const layout = pageData.frontmatter.layout;
+  const pageLayout =
+    typeof layout === "string" && PAGE_LAYOUTS.has(layout) ? layout : undefined;
+  if (layout !== undefined && layout !== "entry" && pageLayout === undefined) {
+    console.warn(
+      `[ox-content:ssg] Unknown layout ${JSON.stringify(layout)} in ${pageData.path}; using "doc".`,
+    );
+  }
+
   return mod.generateSsgHtml(
     {
       title: pageData.title,
@@ -1545,13 +1575,17 @@ export async function generateHtmlPage(
       toc: tocForRust,
       path: pageData.path,
       entryPage: entryPageForRust,
+      layout: pageLayout,
+      alternates: pageData.alternates,
     },
     navGroupsForRust,
     {
       siteName,
       base,
+      siteUrl,
       ogImage,
       theme: themeForRust,
+      defaultLocale,
     },
   );
 }
@@ -1944,6 +1978,22 @@ function getOgImageUrl(inputPath: string, srcDir: string, base: string, siteUrl?
   return relativePath;
 }
 
+/**
+ * Splits a page's URL path into its locale and the path its translations share.
+ *
+ * Pages without a known locale prefix belong to the default locale.
+ */
+export function splitLocalePath(
+  urlPath: string,
+  i18n: ResolvedI18nOptions,
+): { locale: string; key: string } {
+  const [first, ...rest] = urlPath.split("/");
+  if (i18n.locales.some((l) => l.code === first)) {
+    return { locale: first, key: rest.join("/") || "/" };
+  }
+  return { locale: i18n.defaultLocale, key: urlPath };
+}
+
 /**
  * Gets display title from file path.
  */
@@ -2155,6 +2205,9 @@ export async function buildSsg(
         convertMdLinks: true,
         baseUrl: base,
         sourcePath: inputPath,
+        copyButton: true,
+        containers: true,
+        headingAnchors: true,
       });
 
       // Apply built-in plugin transformations (No-JS First)
@@ -2251,6 +2304,32 @@ export async function buildSsg(
     }
   }
 
+  // Nav icons come from each page's `icon` frontmatter
+  const navIcons = new Map<string, string>();
+  for (const { inputPath, frontmatter } of pageResults) {
+    if (typeof frontmatter.icon === "string") {
+      navIcons.set(getUrlPath(inputPath, srcDir), frontmatter.icon);
+    }
+  }
+  for (const group of navItems) {
+    for (const item of group.items) {
+      item.icon = navIcons.get(item.path) ?? item.icon;
+    }
+  }
+
+  // Translations of each page, keyed by its path without the locale prefix
+  const translations = new Map<string, { locale: string; href: string }[]>();
+  if (options.i18n) {
+    const siteUrl = ssgOptions.siteUrl?.replace(/\/$/, "") ?? "";
+    for (const { inputPath } of pageResults) {
+      const { locale, key } = splitLocalePath(getUrlPath(inputPath, srcDir), options.i18n);
+      const href = siteUrl + getHref(inputPath, srcDir, base, ssgOptions.extension);
+      const links = translations.get(key) ?? [];
+      links.push({ locale, href });
+      translations.set(key, links);
+    }
+  }
+
   // Generate HTML pages
   for (const pageResult of pageResults) {
     try {
@@ -2286,6 +2365,11 @@ export async function buildSsg(
           href: getHref(inputPath, srcDir, base, ssgOptions.extension),
           entryPage,
         };
+        if (options.i18n) {
+          const { key } = splitLocalePath(pageData.path, options.i18n);
+          const links = translations.get(key) ?? [];
+          pageData.alternates = links.length > 1 ? links : undefined;
+        }
         html = await generateHtmlPage(
           pageData,
           navItems,
@@ -2293,6 +2377,8 @@ export async function buildSsg(
           base,
           pageOgImage,
           ssgOptions.theme,
+          ssgOptions.siteUrl,
+          options.i18n ? options.i18n.defaultLocale : undefined,
         );
       }
 
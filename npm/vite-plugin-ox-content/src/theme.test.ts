@@ -126,6 +126,18 @@ describe("theme", () => {
       expect(napi.footer?.copyright).toBe("2025");
     });
 
+    it("should pass jsonLd through", () => {
+      expect(themeToNapi(resolveTheme({ jsonLd: true })).jsonLd).toBe(true);
+      expect(themeToNapi(resolveTheme(defaultTheme)).jsonLd).toBeUndefined();
+    });
+
+    it("should leave the outline off unless a position is set", () => {
+      expect(themeToNapi(resolveTheme(defaultTheme)).outlinePosition).toBeUndefined();
+      const napi = themeToNapi(resolveTheme({ outlinePosition: "left", outlineLevel: [2, 4] }));
+      expect(napi.outlinePosition).toBe("left");
+      expect(napi.outlineLevel).toEqual([2, 4]);
+    });
+
     it("should omit empty sections", () => {
       const resolved = resolveTheme(defaultTheme);
       const napi = themeToNapi(resolved);
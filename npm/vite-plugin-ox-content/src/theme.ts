@@ -152,6 +152,22 @@ export interface ThemeConfig {
   css?: string;
   /** Additional custom JavaScript */
   js?: string;
+  /**
+   * Emit schema.org JSON-LD (a `BreadcrumbList`) on each page.
+   * Requires `ssg.siteUrl`, since its URLs must be absolute.
+   * @default false
+   */
+  jsonLd?: boolean;
+  /**
+   * Where the page outline renders.
+   * @default "none"
+   */
+  outlinePosition?: "right" | "left" | "none";
+  /**
+   * Heading depth range `[min, max]` shown in the outline.
+   * @default [2, 3]
+   */
+  outlineLevel?: [number, number];
 }
 
 /**
@@ -170,6 +186,9 @@ export interface ResolvedThemeConfig {
   embed: ThemeEmbed;
   css: string;
   js: string;
+  jsonLd: boolean;
+  outlinePosition: "right" | "left" | "none";
+  outlineLevel?: [number, number];
 }
 
 /**
@@ -346,6 +365,9 @@ export function resolveTheme(config?: ThemeConfig): ResolvedThemeConfig {
     embed: merged.embed ?? {},
     css: merged.css ?? "",
     js: merged.js ?? "",
+    jsonLd: merged.jsonLd ?? false,
+    outlinePosition: merged.outlinePosition ?? "none",
+    outlineLevel: merged.outlineLevel,
   };
 }
 
@@ -427,6 +449,9 @@ export function themeToNapi(theme: ResolvedThemeConfig): NapiThemeConfig {
     embed: Object.keys(theme.embed).length > 0 ? theme.embed : undefined,
     css: theme.css || undefined,
     js: theme.js || undefined,
+    jsonLd: theme.jsonLd || undefined,
+    outlinePosition: theme.outlinePosition === "none" ? undefined : theme.outlinePosition,
+    outlineLevel: theme.outlineLevel,
   };
 }
 
@@ -528,4 +553,7 @@ export interface NapiThemeConfig {
   embed?: NapiThemeEmbed;
   css?: string;
   js?: string;
+  jsonLd?: boolean;
+  outlinePosition?: string;
+  outlineLevel?: number[];
 }
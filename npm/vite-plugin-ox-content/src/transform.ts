@@ -223,6 +223,24 @@ interface JsTransformOptions {
    * @default false
    */
   codeAnnotationDefaultLineNumbers?: boolean;
+
+  /**
+   * Wrap code blocks with a copy button hook for client-side scripts.
+   * @default false
+   */
+  copyButton?: boolean;
+
+  /**
+   * Enable `:::kind title` ... `:::` custom containers.
+   * @default false
+   */
+  containers?: boolean;
+
+  /**
+   * Add an `id` and a `#` permalink anchor to headings.
+   * @default false
+   */
+  headingAnchors?: boolean;
 }
 
 /**
@@ -392,6 +410,12 @@ export interface SsgTransformOptions {
   baseUrl?: string;
   /** Source file path for relative link resolution */
   sourcePath?: string;
+  /** Wrap code blocks with a copy button (wired up by the SSG script) */
+  copyButton?: boolean;
+  /** Parse `:::kind title` ... `:::` custom containers */
+  containers?: boolean;
+  /** Give headings ids (targets for the outline) and permalink anchors */
+  headingAnchors?: boolean;
 }
 
 export async function transformMarkdown(
@@ -422,6 +446,9 @@ export async function transformMarkdown(
     convertMdLinks: ssgOptions?.convertMdLinks,
     baseUrl: ssgOptions?.baseUrl,
     sourcePath: ssgOptions?.sourcePath ?? filePath,
+    copyButton: ssgOptions?.copyButton,
+    containers: ssgOptions?.containers,
+    headingAnchors: ssgOptions?.headingAnchors,
     codeAnnotations: options.codeAnnotations.enabled,
     codeAnnotationMetaKey: options.codeAnnotations.metaKey,
     codeAnnotationSyntax: options.codeAnnotations.notation,
@@ -678,6 +678,7 @@ export interface DocEntry {
   kind: "function" | "class" | "interface" | "type" | "variable" | "module";
   description: string;
   params?: ParamDoc[];
+  typeParams?: TypeParamDoc[];
   returns?: ReturnDoc;
   examples?: string[];
   tags?: Record<string, string>;
@@ -686,6 +687,7 @@ export interface DocEntry {
   line: number;
   endLine: number;
   signature?: string; // Full function/type signature (for functions and type aliases)
+  overloads?: string[]; // Every signature of an overloaded function
 }
 
 /**
@@ -699,6 +701,16 @@ export interface ParamDoc {
   default?: string;
 }
 
+/**
+ * Generic type parameter documentation.
+ */
+export interface TypeParamDoc {
+  name: string;
+  constraint?: string;
+  default?: string;
+  description: string;
+}
+
 /**
  * Return type documentation.
  */